@@ -0,0 +1,70 @@
+use crate::color::{
+    hsl_to_rgb, linear_to_srgb, oklab_to_rgb, relative_luminance, rgb_to_hsl, rgb_to_oklab, Color,
+};
+
+impl Color {
+    /// Increase HSL lightness by `amount` percentage points.
+    ///
+    /// Lightness is clamped to 0-100, so `lighten(100.0)` always yields white.
+    pub fn lighten(self, amount: f32) -> Color {
+        self.map_hsl(|h, s, l| (h, s, l + amount))
+    }
+
+    /// Decrease HSL lightness by `amount` percentage points.
+    pub fn darken(self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Increase HSL saturation by `amount` percentage points.
+    pub fn saturate(self, amount: f32) -> Color {
+        self.map_hsl(|h, s, l| (h, s + amount, l))
+    }
+
+    /// Decrease HSL saturation by `amount` percentage points.
+    pub fn desaturate(self, amount: f32) -> Color {
+        self.saturate(-amount)
+    }
+
+    /// Rotate the HSL hue by `degrees`, wrapping around the color wheel.
+    pub fn rotate_hue(self, degrees: f32) -> Color {
+        self.map_hsl(|h, s, l| (h + degrees, s, l))
+    }
+
+    /// Mix with another color in Oklab space.
+    ///
+    /// `t` is clamped to 0.0-1.0, where 0.0 returns this color and 1.0 returns
+    /// `other`. Mixing in a perceptual space avoids the dark, muddy midpoints
+    /// of naive sRGB averaging.
+    pub fn mix(self, other: Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let (l1, a1, b1) = rgb_to_oklab(self.r, self.g, self.b);
+        let (l2, a2, b2) = rgb_to_oklab(other.r, other.g, other.b);
+
+        Color::from(oklab_to_rgb(
+            l1 + (l2 - l1) * t,
+            a1 + (a2 - a1) * t,
+            b1 + (b2 - b1) * t,
+        ))
+    }
+
+    /// Return the RGB complement of this color.
+    pub fn invert(self) -> Color {
+        Color::rgb(255 - self.r, 255 - self.g, 255 - self.b)
+    }
+
+    /// Return the gray with the same relative luminance as this color.
+    pub fn grayscale(self) -> Color {
+        let gray = linear_to_srgb(relative_luminance(self.r, self.g, self.b));
+        Color::rgb(gray, gray, gray)
+    }
+
+    fn map_hsl(self, update: impl FnOnce(f32, f32, f32) -> (f32, f32, f32)) -> Color {
+        let (h, s, l) = rgb_to_hsl(self.r, self.g, self.b);
+        let (h, s, l) = update(h, s, l);
+        Color::from(hsl_to_rgb(
+            h.rem_euclid(360.0),
+            s.clamp(0.0, 100.0),
+            l.clamp(0.0, 100.0),
+        ))
+    }
+}
@@ -0,0 +1,131 @@
+use std::hash::{Hash, Hasher};
+use std::sync::OnceLock;
+
+use crate::color::{oklch_to_rgb_in_gamut, rgb_to_ansi256, rgb_to_named_color, NamedColor};
+use crate::palettes::Palette;
+use crate::Color;
+
+/// Oklch lightness of generated colors, light enough to read on dark
+/// backgrounds while keeping hues apart.
+const LIGHTNESS: f32 = 0.72;
+/// Oklch chroma of generated colors, high enough that they keep their hue
+/// when quantized to named colors. Hues that cannot reach it within the sRGB
+/// gamut get the highest chroma that fits, at the same lightness and hue.
+const CHROMA: f32 = 0.22;
+/// Hue of the first generated color, an orange-red in Oklch.
+const FIRST_HUE: f32 = 30.0;
+/// Lightness steps tried, after the base lightness, when a hue collides with
+/// an earlier color once quantized.
+const LIGHTNESS_NUDGES: [f32; 6] = [0.06, -0.06, 0.12, -0.12, 0.18, -0.18];
+/// Fractions of the hue spacing tried when a hue collides once quantized.
+/// They stay within half the spacing so neighbouring hues never swap.
+const HUE_NUDGES: [f32; 4] = [0.25, -0.25, 0.45, -0.45];
+
+/// Number of colors [`Color::from_hash`] chooses from. Small enough that each
+/// keeps its own named color on ANSI 16 terminals.
+const HASH_COLORS: usize = 8;
+
+impl Palette {
+    /// Generate `n` colors that are as distinct as possible.
+    ///
+    /// Hues are spaced evenly around the Oklch hue circle at a fixed lightness
+    /// and chroma, with chroma lowered only as far as each hue needs to stay
+    /// inside the sRGB gamut. When a color would map to the same ANSI 256
+    /// index or named color as an earlier one, its hue and lightness are
+    /// nudged within its slot until both are unique. ANSI 256 indexes stay
+    /// unique for at least 48 colors, while the named colors run out beyond 10.
+    ///
+    /// The colors are generated, so they come back as a `Vec` rather than a
+    /// built-in [`Palette`].
+    pub fn categorical_colors(n: usize) -> Vec<Color> {
+        let spacing = 360.0 / n.max(1) as f32;
+        let mut colors = Vec::with_capacity(n);
+        let mut used_ansi256 = Vec::with_capacity(n);
+        let mut used_named = Vec::with_capacity(n);
+
+        for index in 0..n {
+            let hue = FIRST_HUE + spacing * index as f32;
+            let candidates = candidates(hue, spacing);
+            let is_free = |color: &Color, check_named: bool| {
+                let (ansi256, named) = quantized(*color);
+                !used_ansi256.contains(&ansi256) && (!check_named || !used_named.contains(&named))
+            };
+            let color = candidates
+                .iter()
+                .find(|color| is_free(color, true))
+                .or_else(|| candidates.iter().find(|color| is_free(color, false)))
+                .copied()
+                .unwrap_or(candidates[0]);
+
+            let (ansi256, named) = quantized(color);
+            used_ansi256.push(ansi256);
+            used_named.push(named);
+            colors.push(color);
+        }
+
+        colors
+    }
+}
+
+impl Color {
+    /// Pick a color for `key` that stays the same across runs.
+    ///
+    /// Colors come from a small [`Palette::categorical_colors`] set, so each
+    /// stays readable on dark backgrounds and distinct down to ANSI 16; ANSI 8
+    /// has too few colors to keep all of them apart. Keys are hashed with
+    /// FNV-1a rather than the standard library's randomly seeded hasher, so a
+    /// key keeps its color between runs and builds on the same platform.
+    pub fn from_hash(key: impl Hash) -> Self {
+        static COLORS: OnceLock<Vec<Color>> = OnceLock::new();
+        let colors = COLORS.get_or_init(|| Palette::categorical_colors(HASH_COLORS));
+
+        let mut hasher = Fnv1a::default();
+        key.hash(&mut hasher);
+        colors[(hasher.finish() % colors.len() as u64) as usize]
+    }
+}
+
+/// Return the colors to try for a hue, best first.
+fn candidates(hue: f32, spacing: f32) -> Vec<Color> {
+    let mut candidates = vec![oklch_to_rgb_in_gamut(LIGHTNESS, CHROMA, hue)];
+    for nudge in LIGHTNESS_NUDGES {
+        candidates.push(oklch_to_rgb_in_gamut(LIGHTNESS + nudge, CHROMA, hue));
+    }
+    for nudge in HUE_NUDGES {
+        let hue = hue + spacing * nudge;
+        candidates.push(oklch_to_rgb_in_gamut(LIGHTNESS, CHROMA, hue));
+        for lightness in LIGHTNESS_NUDGES {
+            candidates.push(oklch_to_rgb_in_gamut(LIGHTNESS + lightness, CHROMA, hue));
+        }
+    }
+    candidates
+}
+
+fn quantized(color: Color) -> (u8, NamedColor) {
+    (
+        rgb_to_ansi256(color.r, color.g, color.b),
+        rgb_to_named_color(color.r, color.g, color.b),
+    )
+}
+
+/// The 64-bit FNV-1a hash, whose output depends only on the bytes written.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
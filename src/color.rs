@@ -1,4 +1,10 @@
-use crate::terminal::ColorLevel;
+use std::sync::OnceLock;
+
+use crate::config::ExtendedColorSyntax;
+use crate::gradient::Gradient;
+use crate::hsl::Hsl;
+use crate::terminal::{Background, ColorLevel};
+use crate::vision::ColorTransform;
 
 const ANSI256_STEPS: [u8; 6] = [0, 95, 135, 175, 215, 255];
 
@@ -21,7 +27,7 @@ const ANSI16_RGB: [(u8, u8, u8); 16] = [
     (255, 255, 255),
 ];
 
-const NAMED_COLORS: [NamedColor; 16] = [
+pub(crate) const NAMED_COLORS: [NamedColor; 16] = [
     NamedColor::Black,
     NamedColor::Red,
     NamedColor::Green,
@@ -42,36 +48,158 @@ const NAMED_COLORS: [NamedColor; 16] = [
 
 /// Convert HSL color values to RGB.
 ///
-/// - `h`: Hue in degrees
-/// - `s`: Saturation percentage
-/// - `l`: Lightness percentage
+/// - `h`: Hue in degrees, wrapped into 0-360
+/// - `s`: Saturation percentage, clamped to 0-100
+/// - `l`: Lightness percentage, clamped to 0-100
 pub(crate) fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (u8, u8, u8) {
-    let h = h / 360.0;
-    let s = s / 100.0;
-    let l = l / 100.0;
-
-    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
-    let x = c * (1.0 - ((h * 6.0) % 2.0 - 1.0).abs());
-    let m = l - c / 2.0;
-
-    let (r, g, b) = match (h * 6.0) as i32 {
-        0 => (c, x, 0.0),
-        1 => (x, c, 0.0),
-        2 => (0.0, c, x),
-        3 => (0.0, x, c),
-        4 => (x, 0.0, c),
-        _ => (c, 0.0, x),
+    Hsl::new(h, s, l).to_color().to_rgb()
+}
+
+/// Convert RGB color values to HSL.
+///
+/// Returns hue in degrees and saturation and lightness as percentages, matching
+/// the units accepted by [`hsl_to_rgb`].
+pub(crate) fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let r = f32::from(r) / 255.0;
+    let g = f32::from(g) / 255.0;
+    let b = f32::from(b) / 255.0;
+
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let delta = max - min;
+
+    if delta == 0.0 {
+        return (0.0, 0.0, l * 100.0);
+    }
+
+    let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
     };
 
+    (h, s * 100.0, l * 100.0)
+}
+
+/// Return the WCAG relative luminance of an sRGB color, from 0.0 to 1.0.
+pub(crate) fn relative_luminance(r: u8, g: u8, b: u8) -> f32 {
+    0.2126 * srgb_to_linear(r) + 0.7152 * srgb_to_linear(g) + 0.0722 * srgb_to_linear(b)
+}
+
+/// Convert an 8-bit sRGB component to linear light, from 0.0 to 1.0.
+pub(crate) fn srgb_to_linear(component: u8) -> f32 {
+    let component = f32::from(component) / 255.0;
+    if component <= 0.04045 {
+        component / 12.92
+    } else {
+        ((component + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear-light component to 8-bit sRGB, clamping out-of-gamut
+/// values.
+pub(crate) fn linear_to_srgb(component: f32) -> u8 {
+    let component = component.clamp(0.0, 1.0);
+    let encoded = if component <= 0.003_130_8 {
+        component * 12.92
+    } else {
+        1.055 * component.powf(1.0 / 2.4) - 0.055
+    };
+
+    (encoded * 255.0).round() as u8
+}
+
+/// Convert sRGB to Oklab `(L, a, b)`.
+pub(crate) fn rgb_to_oklab(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let r = srgb_to_linear(r);
+    let g = srgb_to_linear(g);
+    let b = srgb_to_linear(b);
+
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
     (
-        ((r + m) * 255.0) as u8,
-        ((g + m) * 255.0) as u8,
-        ((b + m) * 255.0) as u8,
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
     )
 }
 
+/// Convert Oklab `(L, a, b)` to sRGB, clamping out-of-gamut values.
+pub(crate) fn oklab_to_rgb(lightness: f32, a: f32, b: f32) -> (u8, u8, u8) {
+    let (r, g, b) = oklab_to_linear(lightness, a, b);
+    (linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b))
+}
+
+/// Convert Oklab to linear sRGB components, which fall outside 0.0-1.0 for
+/// colors outside the sRGB gamut.
+fn oklab_to_linear(lightness: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+    (
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    )
+}
+
+/// Convert sRGB to Oklch `(L, C, h)` with hue in degrees.
+pub(crate) fn rgb_to_oklch(color: Color) -> (f32, f32, f32) {
+    let (l, a, b) = rgb_to_oklab(color.r, color.g, color.b);
+    let chroma = a.hypot(b);
+    let hue = b.atan2(a).to_degrees().rem_euclid(360.0);
+    (l, chroma, hue)
+}
+
+/// Convert Oklch `(L, C, h)` with hue in degrees to sRGB, clamping
+/// out-of-gamut values.
+pub(crate) fn oklch_to_rgb(lightness: f32, chroma: f32, hue: f32) -> Color {
+    let (sin, cos) = hue.to_radians().sin_cos();
+    Color::from(oklab_to_rgb(lightness, chroma * cos, chroma * sin))
+}
+
+/// Convert Oklch `(L, C, h)` with hue in degrees to sRGB, lowering chroma at
+/// the same lightness and hue until the color fits in the sRGB gamut.
+pub(crate) fn oklch_to_rgb_in_gamut(lightness: f32, chroma: f32, hue: f32) -> Color {
+    const TOLERANCE: f32 = 1e-4;
+    let (sin, cos) = hue.to_radians().sin_cos();
+    let fits = |chroma: f32| {
+        let (r, g, b) = oklab_to_linear(lightness, chroma * cos, chroma * sin);
+        [r, g, b]
+            .into_iter()
+            .all(|component| (-TOLERANCE..=1.0 + TOLERANCE).contains(&component))
+    };
+
+    let mut fitting = chroma;
+    if !fits(chroma) {
+        let mut outside = chroma;
+        fitting = 0.0;
+        for _ in 0..24 {
+            let middle = (fitting + outside) / 2.0;
+            if fits(middle) {
+                fitting = middle;
+            } else {
+                outside = middle;
+            }
+        }
+    }
+    oklch_to_rgb(lightness, fitting, hue)
+}
+
 pub(crate) fn hex_to_rgb(hex: &str) -> Option<(u8, u8, u8)> {
     let hex = hex.trim_start_matches('#');
+    // Checking the digits first keeps the byte slicing below on char
+    // boundaries for untrusted input.
+    if !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
+    }
     let expanded = match hex.len() {
         3 => {
             let mut expanded = String::with_capacity(6);
@@ -92,23 +220,344 @@ pub(crate) fn hex_to_rgb(hex: &str) -> Option<(u8, u8, u8)> {
     Some((r, g, b))
 }
 
+/// Parse a 4-digit or 8-digit hex color with alpha, falling back to an opaque
+/// color for 3-digit and 6-digit input.
+pub(crate) fn hex_to_rgba(hex: &str) -> Option<(u8, u8, u8, u8)> {
+    let trimmed = hex.trim_start_matches('#');
+    if !trimmed.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
+    }
+    let (color, alpha) = match trimmed.len() {
+        4 => trimmed.split_at(3),
+        8 => trimmed.split_at(6),
+        _ => return hex_to_rgb(hex).map(|(r, g, b)| (r, g, b, u8::MAX)),
+    };
+
+    let (r, g, b) = hex_to_rgb(color)?;
+    let alpha = if alpha.len() == 1 {
+        alpha.repeat(2)
+    } else {
+        alpha.to_string()
+    };
+
+    Some((r, g, b, u8::from_str_radix(&alpha, 16).ok()?))
+}
+
+/// An sRGB color with 8-bit components.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Color {
+    /// Red component.
+    pub r: u8,
+    /// Green component.
+    pub g: u8,
+    /// Blue component.
+    pub b: u8,
+}
+
+impl Color {
+    /// Create a color from RGB components.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Parse a 3-digit or 6-digit hex color, with or without the `#` prefix.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        hex_to_rgb(hex).map(Self::from)
+    }
+
+    /// Return the color as an `(r, g, b)` tuple.
+    pub const fn to_rgb(self) -> (u8, u8, u8) {
+        (self.r, self.g, self.b)
+    }
+
+    /// Use an ANSI 256 index in place of this color on ANSI 256 terminals.
+    pub const fn or_ansi256(self, index: u8) -> FallbackColor {
+        FallbackColor::new(self).or_ansi256(index)
+    }
+
+    /// Use a named color in place of this color on ANSI 16 and ANSI 8
+    /// terminals.
+    pub const fn or_named(self, color: NamedColor) -> FallbackColor {
+        FallbackColor::new(self).or_named(color)
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::rgb(r, g, b)
+    }
+}
+
+impl From<Color> for (u8, u8, u8) {
+    fn from(color: Color) -> Self {
+        color.to_rgb()
+    }
+}
+
+/// An sRGB color with an 8-bit alpha channel.
+///
+/// Terminals cannot draw translucent text, so alpha colors are composited at
+/// render time. A foreground blends against the span's own background when it
+/// has one, and otherwise against the terminal background. The terminal
+/// background comes from [`crate::ColorizeConfig::set_background_color`], the
+/// capabilities' `background_color` (for example from an OSC 11 query), the
+/// `COLORFGBG` palette index, or finally black or white depending on the
+/// [`Background`]. The composited color then degrades like any RGB color.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rgba {
+    /// Red component.
+    pub r: u8,
+    /// Green component.
+    pub g: u8,
+    /// Blue component.
+    pub b: u8,
+    /// Alpha component, from 0 (transparent) to 255 (opaque).
+    pub a: u8,
+}
+
+impl Rgba {
+    /// Create a color from RGBA components.
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Parse a hex color with optional alpha, such as `#00000080` or `#0008`.
+    ///
+    /// 3-digit and 6-digit input is accepted as an opaque color.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        hex_to_rgba(hex).map(|(r, g, b, a)| Self::new(r, g, b, a))
+    }
+
+    /// Return the color without its alpha channel.
+    pub const fn color(self) -> Color {
+        Color::rgb(self.r, self.g, self.b)
+    }
+
+    /// Composite this color over an opaque background.
+    ///
+    /// Blending happens on the sRGB-encoded values, matching how browsers and
+    /// design tools composite alpha.
+    pub fn over(self, background: Color) -> Color {
+        let alpha = f32::from(self.a) / 255.0;
+        let channel = |top: u8, bottom: u8| {
+            (f32::from(bottom) + (f32::from(top) - f32::from(bottom)) * alpha).round() as u8
+        };
+
+        Color::rgb(
+            channel(self.r, background.r),
+            channel(self.g, background.g),
+            channel(self.b, background.b),
+        )
+    }
+}
+
+impl From<Color> for Rgba {
+    fn from(color: Color) -> Self {
+        Self::new(color.r, color.g, color.b, u8::MAX)
+    }
+}
+
+/// A color that picks a variant based on the terminal background.
+///
+/// The variant is chosen at render time from the detected or configured
+/// [`Background`]. When the background is unknown,
+/// [`crate::ColorizeConfig::default_background`] decides. The chosen color then
+/// degrades to the resolved [`ColorLevel`] like any RGB color.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct AdaptiveColor {
+    /// The color used on light backgrounds.
+    pub light: Color,
+    /// The color used on dark backgrounds.
+    pub dark: Color,
+}
+
+impl AdaptiveColor {
+    /// Create an adaptive color from its light and dark background variants.
+    pub const fn new(light: Color, dark: Color) -> Self {
+        Self { light, dark }
+    }
+
+    /// Create an adaptive color from two hex codes.
+    ///
+    /// Returns `None` if either hex code is invalid.
+    pub fn from_hex(light: &str, dark: &str) -> Option<Self> {
+        Some(Self::new(Color::from_hex(light)?, Color::from_hex(dark)?))
+    }
+
+    /// Return the variant for a background.
+    pub const fn resolve(self, background: Background) -> Color {
+        match background {
+            Background::Light => self.light,
+            Background::Dark => self.dark,
+        }
+    }
+}
+
+/// An RGB color with hand-picked stand-ins for terminals that cannot show it.
+///
+/// Build one from a [`Color`] with [`Color::or_ansi256`] or
+/// [`Color::or_named`]. At render time the explicit fallback for the resolved
+/// [`ColorLevel`] is used: the ANSI 256 index for ANSI 256 output and the named
+/// color for ANSI 16 and ANSI 8 output, or the ANSI 256 index mapped to a named
+/// color when no named fallback is set. Levels without an explicit fallback
+/// quantize the RGB color automatically, as do active color transforms and
+/// grayscale output.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FallbackColor {
+    color: Color,
+    ansi256: Option<u8>,
+    named: Option<NamedColor>,
+}
+
+impl FallbackColor {
+    /// Create a fallback chain with no explicit fallbacks.
+    pub const fn new(color: Color) -> Self {
+        Self {
+            color,
+            ansi256: None,
+            named: None,
+        }
+    }
+
+    /// Use an ANSI 256 index in place of the color on ANSI 256 terminals.
+    pub const fn or_ansi256(mut self, index: u8) -> Self {
+        self.ansi256 = Some(index);
+        self
+    }
+
+    /// Use a named color in place of the color on ANSI 16 and ANSI 8
+    /// terminals.
+    pub const fn or_named(mut self, color: NamedColor) -> Self {
+        self.named = Some(color);
+        self
+    }
+
+    /// Return the true-color value.
+    pub const fn color(self) -> Color {
+        self.color
+    }
+
+    /// Return the explicit ANSI 256 fallback, if any.
+    pub const fn ansi256(self) -> Option<u8> {
+        self.ansi256
+    }
+
+    /// Return the explicit named fallback, if any.
+    pub const fn named(self) -> Option<NamedColor> {
+        self.named
+    }
+
+    /// Return the color to render at `level`.
+    pub(crate) fn spec_for(self, level: ColorLevel) -> ColorSpec {
+        let explicit = match level {
+            ColorLevel::Ansi256 => self.ansi256.map(ColorSpec::Ansi256),
+            // The ANSI 256 index is nearer the intended color than a
+            // quantized RGB value, so it stands in when no named color is set.
+            ColorLevel::Ansi16 | ColorLevel::Ansi8 => self
+                .named
+                .map(ColorSpec::Named)
+                .or(self.ansi256.map(ColorSpec::Ansi256)),
+            ColorLevel::NoColor | ColorLevel::TrueColor => None,
+        };
+        let Color { r, g, b } = self.color;
+        explicit.unwrap_or(ColorSpec::Rgb(r, g, b))
+    }
+}
+
+impl From<Color> for FallbackColor {
+    fn from(color: Color) -> Self {
+        Self::new(color)
+    }
+}
+
+/// Render-time information needed to turn a [`ColorSpec`] into SGR codes.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub(crate) enum NamedColor {
+pub(crate) struct RenderContext {
+    pub(crate) level: ColorLevel,
+    pub(crate) background: Background,
+    /// The terminal background that alpha colors are composited against.
+    pub(crate) background_color: Color,
+    pub(crate) transform: ColorTransform,
+    pub(crate) grayscale: bool,
+    pub(crate) extended_color_syntax: ExtendedColorSyntax,
+}
+
+impl RenderContext {
+    /// Create a context whose background color is black or white, following
+    /// the background brightness.
+    pub(crate) fn new(level: ColorLevel, background: Background) -> Self {
+        let background_color = match background {
+            Background::Light => Color::rgb(255, 255, 255),
+            Background::Dark => Color::rgb(0, 0, 0),
+        };
+
+        Self {
+            level,
+            background,
+            background_color,
+            transform: ColorTransform::None,
+            grayscale: false,
+            extended_color_syntax: ExtendedColorSyntax::Semicolon,
+        }
+    }
+
+    pub(crate) fn with_background_color(mut self, color: Color) -> Self {
+        self.background_color = color;
+        self
+    }
+
+    pub(crate) fn with_transform(mut self, transform: ColorTransform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    pub(crate) fn with_grayscale(mut self, grayscale: bool) -> Self {
+        self.grayscale = grayscale;
+        self
+    }
+
+    pub(crate) fn with_extended_color_syntax(mut self, syntax: ExtendedColorSyntax) -> Self {
+        self.extended_color_syntax = syntax;
+        self
+    }
+}
+
+/// One of the 16 standard ANSI colors.
+///
+/// The exact shade of each color is chosen by the terminal's palette.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NamedColor {
+    /// ANSI black (30/40).
     Black,
+    /// ANSI red (31/41).
     Red,
+    /// ANSI green (32/42).
     Green,
+    /// ANSI yellow (33/43).
     Yellow,
+    /// ANSI blue (34/44).
     Blue,
+    /// ANSI magenta (35/45).
     Magenta,
+    /// ANSI cyan (36/46).
     Cyan,
+    /// ANSI white (37/47).
     White,
+    /// ANSI bright black (90/100).
     BrightBlack,
+    /// ANSI bright red (91/101).
     BrightRed,
+    /// ANSI bright green (92/102).
     BrightGreen,
+    /// ANSI bright yellow (93/103).
     BrightYellow,
+    /// ANSI bright blue (94/104).
     BrightBlue,
+    /// ANSI bright magenta (95/105).
     BrightMagenta,
+    /// ANSI bright cyan (96/106).
     BrightCyan,
+    /// ANSI bright white (97/107).
     BrightWhite,
 }
 
@@ -121,6 +570,28 @@ impl NamedColor {
         (self.foreground_code_value() + 10).to_string()
     }
 
+    /// Return the non-bright variant of this color.
+    pub(crate) fn base(self) -> Self {
+        match self {
+            Self::BrightBlack => Self::Black,
+            Self::BrightRed => Self::Red,
+            Self::BrightGreen => Self::Green,
+            Self::BrightYellow => Self::Yellow,
+            Self::BrightBlue => Self::Blue,
+            Self::BrightMagenta => Self::Magenta,
+            Self::BrightCyan => Self::Cyan,
+            Self::BrightWhite => Self::White,
+            color => color,
+        }
+    }
+
+    /// Return the standard palette RGB value for this color.
+    pub(crate) fn rgb(self) -> (u8, u8, u8) {
+        named_color_candidates()
+            .find(|(color, _)| *color == self)
+            .map_or((0, 0, 0), |(_, rgb)| rgb)
+    }
+
     fn foreground_code_value(self) -> u8 {
         match self {
             Self::Black => 30,
@@ -148,42 +619,206 @@ pub(crate) enum ColorSpec {
     Named(NamedColor),
     Ansi256(u8),
     Rgb(u8, u8, u8),
+    Adaptive(AdaptiveColor),
+    Rgba(Rgba),
+    Gradient(Gradient),
+    Fallback(FallbackColor),
 }
 
 impl ColorSpec {
-    pub(crate) fn foreground_code(&self, level: ColorLevel) -> Option<String> {
-        self.code(level, ColorPosition::Foreground)
+    pub(crate) fn is_gradient(&self) -> bool {
+        matches!(self, Self::Gradient(_))
+    }
+
+    /// Return the color at position `t` of a span, from 0.0 to 1.0.
+    ///
+    /// Gradients are sampled; every other color is the same along the span.
+    pub(crate) fn at(&self, t: f32) -> Self {
+        match self {
+            Self::Gradient(gradient) => {
+                let Color { r, g, b } = gradient.sample(t);
+                Self::Rgb(r, g, b)
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Resolve adaptive and alpha colors against the terminal background,
+    /// leaving others as-is.
+    pub(crate) fn resolve(&self, context: RenderContext) -> Self {
+        match self {
+            Self::Adaptive(color) => {
+                let Color { r, g, b } = color.resolve(context.background);
+                Self::Rgb(r, g, b)
+            }
+            Self::Rgba(color) => {
+                let Color { r, g, b } = color.over(context.background_color);
+                Self::Rgb(r, g, b)
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Return the RGB value this color stands for, using the standard palette
+    /// for named and ANSI 256 colors.
+    pub(crate) fn to_color(&self, context: RenderContext) -> Color {
+        match self {
+            Self::Named(color) => Color::from(color.rgb()),
+            Self::Ansi256(index) => Color::from(ansi256_to_rgb(*index)),
+            Self::Rgb(r, g, b) => Color::rgb(*r, *g, *b),
+            Self::Adaptive(color) => color.resolve(context.background),
+            Self::Rgba(color) => color.over(context.background_color),
+            Self::Gradient(gradient) => gradient.sample(0.0),
+            Self::Fallback(color) => color.color(),
+        }
+    }
+
+    /// Apply the context's color transform, turning the color into RGB when
+    /// the transform is active.
+    pub(crate) fn transformed(&self, context: RenderContext) -> Self {
+        if context.transform == ColorTransform::None {
+            return self.clone();
+        }
+
+        let Color { r, g, b } = context.transform.apply(self.to_color(context));
+        Self::Rgb(r, g, b)
+    }
+
+    /// Return the foreground code and whether the color also needs bold, as
+    /// bright colors do in 8-color output.
+    pub(crate) fn foreground_code(&self, context: RenderContext) -> Option<(String, bool)> {
+        self.code(context, ColorPosition::Foreground)
     }
 
-    pub(crate) fn background_code(&self, level: ColorLevel) -> Option<String> {
-        self.code(level, ColorPosition::Background)
+    pub(crate) fn background_code(&self, context: RenderContext) -> Option<String> {
+        self.code(context, ColorPosition::Background)
+            .map(|(code, _)| code)
     }
 
-    fn code(&self, level: ColorLevel, position: ColorPosition) -> Option<String> {
+    /// Return the color as it is rendered at the context's level: a named
+    /// color below ANSI 256, an ANSI 256 index at ANSI 256, and RGB only at
+    /// truecolor. Grayscale output reduces the color to a gray first.
+    pub(crate) fn quantized(&self, context: RenderContext) -> Self {
+        let level = context.level;
+        if context.grayscale {
+            let gray = self.to_color(context).grayscale().r;
+            return match level {
+                ColorLevel::NoColor | ColorLevel::Ansi8 | ColorLevel::Ansi16 => {
+                    Self::Named(gray_to_named_color(gray))
+                }
+                ColorLevel::Ansi256 => Self::Ansi256(rgb_to_ansi256_gray(gray, gray, gray)),
+                ColorLevel::TrueColor => Self::Rgb(gray, gray, gray),
+            };
+        }
+
         match (level, self) {
-            (ColorLevel::NoColor, _) => None,
-            (_, Self::Named(color)) => Some(position.named_code(*color)),
+            (_, Self::Adaptive(_) | Self::Rgba(_)) => self.resolve(context).quantized(context),
+            // A gradient rendered as a single code uses its starting color.
+            (_, Self::Gradient(_)) => self.at(0.0).quantized(context),
+            (level, Self::Fallback(color)) => color.spec_for(level).quantized(context),
+            (_, Self::Named(color)) => Self::Named(*color),
+            (ColorLevel::NoColor | ColorLevel::Ansi8, Self::Ansi256(index)) => {
+                let (r, g, b) = ansi256_to_rgb(*index);
+                Self::Named(rgb_to_basic_color(r, g, b))
+            }
+            (ColorLevel::NoColor | ColorLevel::Ansi8, Self::Rgb(r, g, b)) => {
+                Self::Named(rgb_to_basic_color(*r, *g, *b))
+            }
             (ColorLevel::Ansi16, Self::Ansi256(index)) => {
-                Some(position.named_code(ansi256_to_named_color(*index)))
+                Self::Named(ansi256_to_named_color(*index))
+            }
+            (ColorLevel::Ansi16, Self::Rgb(r, g, b)) => Self::Named(rgb_to_named_color(*r, *g, *b)),
+            (_, Self::Ansi256(index)) => Self::Ansi256(*index),
+            (ColorLevel::Ansi256, Self::Rgb(r, g, b)) => Self::Ansi256(rgb_to_ansi256(*r, *g, *b)),
+            (ColorLevel::TrueColor, Self::Rgb(r, g, b)) => Self::Rgb(*r, *g, *b),
+        }
+    }
+
+    /// Return the SGR code for the color and whether it also needs bold.
+    fn code(&self, context: RenderContext, position: ColorPosition) -> Option<(String, bool)> {
+        if context.level == ColorLevel::NoColor {
+            return None;
+        }
+
+        match (context.level, self.quantized(context)) {
+            (ColorLevel::Ansi8, Self::Named(color)) => Some(position.basic_code(color)),
+            (_, Self::Named(color)) => Some((position.named_code(color), false)),
+            (_, Self::Ansi256(index)) => {
+                let prefix = position.extended_prefix();
+                let code = match context.extended_color_syntax {
+                    ExtendedColorSyntax::Semicolon => format!("{prefix};5;{index}"),
+                    ExtendedColorSyntax::ColonWithColorSpace | ExtendedColorSyntax::Colon => {
+                        format!("{prefix}:5:{index}")
+                    }
+                };
+                Some((code, false))
             }
-            (ColorLevel::Ansi16, Self::Rgb(r, g, b)) => {
-                Some(position.named_code(rgb_to_named_color(*r, *g, *b)))
+            (_, Self::Rgb(r, g, b)) => {
+                let prefix = position.extended_prefix();
+                let code = match context.extended_color_syntax {
+                    ExtendedColorSyntax::Semicolon => format!("{prefix};2;{r};{g};{b}"),
+                    ExtendedColorSyntax::ColonWithColorSpace => format!("{prefix}:2::{r}:{g}:{b}"),
+                    ExtendedColorSyntax::Colon => format!("{prefix}:2:{r}:{g}:{b}"),
+                };
+                Some((code, false))
             }
-            (ColorLevel::Ansi256 | ColorLevel::TrueColor, Self::Ansi256(index)) => {
-                Some(format!("{};5;{index}", position.extended_prefix()))
+            // `quantized` only returns named, ANSI 256, and RGB colors.
+            _ => None,
+        }
+    }
+}
+
+/// A palette entry: the color to emit and its RGB value.
+pub(crate) type PaletteEntry = (ColorSpec, (u8, u8, u8));
+
+/// Return the palette RGB output is quantized to at the context's level, or
+/// an empty palette when RGB is shown as-is or not at all.
+///
+/// Each palette is built once per process and shared.
+pub(crate) fn quantization_palette(context: RenderContext) -> &'static [PaletteEntry] {
+    static PALETTES: [OnceLock<Vec<PaletteEntry>>; 6] = [const { OnceLock::new() }; 6];
+    let slot = match (context.level, context.grayscale) {
+        (ColorLevel::NoColor | ColorLevel::TrueColor, _) => return &[],
+        (ColorLevel::Ansi8, grayscale) => usize::from(grayscale),
+        (ColorLevel::Ansi16, grayscale) => 2 + usize::from(grayscale),
+        (ColorLevel::Ansi256, grayscale) => 4 + usize::from(grayscale),
+    };
+    PALETTES[slot].get_or_init(|| build_quantization_palette(context.level, context.grayscale))
+}
+
+fn build_quantization_palette(
+    level: ColorLevel,
+    grayscale: bool,
+) -> Vec<(ColorSpec, (u8, u8, u8))> {
+    match level {
+        ColorLevel::NoColor | ColorLevel::TrueColor => Vec::new(),
+        // Plain grayscale quantization uses the same four grays at 8 colors,
+        // drawing the bright ones as bold.
+        ColorLevel::Ansi8 | ColorLevel::Ansi16 if grayscale => GRAY_NAMED_COLORS
+            .into_iter()
+            .map(|color| (ColorSpec::Named(color), color.rgb()))
+            .collect(),
+        ColorLevel::Ansi8 => named_color_candidates()
+            .take(8)
+            .map(|(color, rgb)| (ColorSpec::Named(color), rgb))
+            .collect(),
+        ColorLevel::Ansi16 => named_color_candidates()
+            .map(|(color, rgb)| (ColorSpec::Named(color), rgb))
+            .collect(),
+        ColorLevel::Ansi256 if grayscale => (232..=255)
+            .map(|index| (ColorSpec::Ansi256(index), ansi256_to_rgb(index)))
+            .collect(),
+        ColorLevel::Ansi256 => {
+            // Skip repeated colors, such as black at both 0 and 16, so every
+            // entry is a distinct color.
+            let mut palette: Vec<PaletteEntry> = Vec::new();
+            for index in 0..=255 {
+                let rgb = ansi256_to_rgb(index);
+                if palette.iter().all(|(_, existing)| *existing != rgb) {
+                    palette.push((ColorSpec::Ansi256(index), rgb));
+                }
             }
-            (ColorLevel::Ansi256, Self::Rgb(r, g, b)) => Some(format!(
-                "{};5;{}",
-                position.extended_prefix(),
-                rgb_to_ansi256(*r, *g, *b)
-            )),
-            (ColorLevel::TrueColor, Self::Rgb(r, g, b)) => Some(format!(
-                "{};2;{};{};{}",
-                position.extended_prefix(),
-                r,
-                g,
-                b
-            )),
+            palette
         }
     }
 }
@@ -202,6 +837,16 @@ impl ColorPosition {
         }
     }
 
+    /// Return the code for a named color using only the eight base colors,
+    /// and whether it needs bold.
+    ///
+    /// Bright foregrounds become their base color in bold, which most 8-color
+    /// terminals draw brighter. Bright backgrounds become their base color.
+    fn basic_code(self, color: NamedColor) -> (String, bool) {
+        let bold = matches!(self, Self::Foreground) && color.base() != color;
+        (self.named_code(color.base()), bold)
+    }
+
     fn extended_prefix(self) -> &'static str {
         match self {
             Self::Foreground => "38",
@@ -268,7 +913,7 @@ fn nearest_ansi256_cube_component(value: u8) -> u8 {
     index as u8
 }
 
-fn rgb_to_ansi256_gray(r: u8, g: u8, b: u8) -> u8 {
+pub(crate) fn rgb_to_ansi256_gray(r: u8, g: u8, b: u8) -> u8 {
     let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
     let ramp_index = if average <= 8 {
         0
@@ -289,16 +934,43 @@ pub(crate) fn rgb_to_named_color(r: u8, g: u8, b: u8) -> NamedColor {
     best
 }
 
+/// Return the nearest of the eight base colors.
+pub(crate) fn rgb_to_basic_color(r: u8, g: u8, b: u8) -> NamedColor {
+    let target = (r, g, b);
+    let (best, _) = nearest_by_distance(named_color_candidates().take(8), |candidate| {
+        distance_squared(target, candidate.1)
+    });
+    best
+}
+
+/// The named colors that are shades of gray, from darkest to lightest.
+pub(crate) const GRAY_NAMED_COLORS: [NamedColor; 4] = [
+    NamedColor::Black,
+    NamedColor::BrightBlack,
+    NamedColor::White,
+    NamedColor::BrightWhite,
+];
+
+/// Return the nearest of black, bright black, white, and bright white.
+pub(crate) fn gray_to_named_color(gray: u8) -> NamedColor {
+    nearest_by_distance(GRAY_NAMED_COLORS, |color| {
+        component_distance_squared(gray, color.rgb().0)
+    })
+}
+
 pub(crate) fn ansi256_to_named_color(index: u8) -> NamedColor {
     let (r, g, b) = ansi256_to_rgb(index);
     rgb_to_named_color(r, g, b)
 }
 
-fn named_color_candidates() -> impl Iterator<Item = (NamedColor, (u8, u8, u8))> {
+pub(crate) fn named_color_candidates() -> impl Iterator<Item = (NamedColor, (u8, u8, u8))> {
     NAMED_COLORS.into_iter().zip(ANSI16_RGB)
 }
 
-fn nearest_by_distance<T>(candidates: impl IntoIterator<Item = T>, distance: impl Fn(T) -> u32) -> T
+pub(crate) fn nearest_by_distance<T>(
+    candidates: impl IntoIterator<Item = T>,
+    distance: impl Fn(T) -> u32,
+) -> T
 where
     T: Copy,
 {
@@ -321,7 +993,7 @@ where
     best
 }
 
-fn distance_squared(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+pub(crate) fn distance_squared(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
     component_distance_squared(a.0, b.0)
         + component_distance_squared(a.1, b.1)
         + component_distance_squared(a.2, b.2)
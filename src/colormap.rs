@@ -0,0 +1,239 @@
+use crate::gradient::{interpolate, InterpolationSpace};
+use crate::Color;
+
+/// Polynomial coefficients, lowest degree first, for one colormap's red,
+/// green, and blue channels.
+type Coefficients = [[f32; 3]; 7];
+
+// Degree-6 polynomial fits of the matplotlib colormaps by Matt Zucker
+// (https://www.shadertoy.com/view/WlfXRN), accurate to within a few 8-bit
+// levels.
+const VIRIDIS: Coefficients = [
+    [0.277_727_33, 0.005_407_344_5, 0.334_099_8],
+    [0.105_093_04, 1.404_613_5, 1.384_590_2],
+    [-0.330_861_83, 0.214_847_56, 0.095_095_16],
+    [-4.634_230_6, -5.799_101, -19.332_441],
+    [6.228_27, 14.179_933, 56.690_55],
+    [4.776_385, -13.745_146, -65.353_03],
+    [-5.435_456, 4.645_852_6, 26.312_435],
+];
+const PLASMA: Coefficients = [
+    [0.058_732_344, 0.023_336_709, 0.543_340_2],
+    [2.176_514_6, 0.238_383_42, 0.753_960_46],
+    [-2.689_460_5, -7.455_851, 3.110_8],
+    [6.130_348, 42.346_19, -28.518_855],
+    [-11.107_436, -82.666_31, 60.139_847],
+    [10.023_066, 71.413_62, -54.072_186],
+    [-3.658_713_8, -22.931_535, 18.191_908],
+];
+const MAGMA: Coefficients = [
+    [-0.002_136_485, -0.000_749_655_05, -0.005_386_128],
+    [0.251_660_54, 0.677_523_24, 2.494_026_6],
+    [8.353_717, -3.577_719_4, 0.314_467_9],
+    [-27.668_733, 14.264_731, -13.649_213],
+    [52.176_14, -27.943_607, 12.944_169],
+    [-50.768_524, 29.046_583, 4.234_153],
+    [18.655_705, -11.489_773, -5.601_961_5],
+];
+const INFERNO: Coefficients = [
+    [0.000_218_940_37, 0.001_651_004_6, -0.019_480_899],
+    [0.106_513_42, 0.563_956_4, 3.932_712_3],
+    [11.602_493, -3.972_854, -15.942_394],
+    [-41.703_995, 17.436_4, 44.354_145],
+    [77.162_93, -33.402_36, -81.807_31],
+    [-71.319_43, 32.626_064, 73.209_52],
+    [25.131_126, -12.242_669, -23.070_326],
+];
+// Anton Mikhailov's polynomial approximation of Google's Turbo colormap.
+const TURBO: Coefficients = [
+    [0.135_721_38, 0.091_402_61, 0.106_673_3],
+    [4.615_392_6, 2.194_188_4, 12.641_946],
+    [-42.660_324, 4.842_966_6, -60.582_047],
+    [132.131_09, -14.185_033, 110.362_77],
+    [-152.942_4, 4.277_298_5, -89.903_11],
+    [59.286_38, 2.829_566, 27.348_25],
+    [0.0, 0.0, 0.0],
+];
+
+const CIVIDIS: [Color; 5] = [
+    Color::rgb(0x00, 0x20, 0x4c),
+    Color::rgb(0x41, 0x4d, 0x6b),
+    Color::rgb(0x7c, 0x7b, 0x78),
+    Color::rgb(0xbc, 0xaf, 0x6f),
+    Color::rgb(0xff, 0xe9, 0x45),
+];
+// ColorBrewer RdBu, from red at 0.0 to blue at 1.0.
+const RED_BLUE: [Color; 11] = [
+    Color::rgb(0x67, 0x00, 0x1f),
+    Color::rgb(0xb2, 0x18, 0x2b),
+    Color::rgb(0xd6, 0x60, 0x4d),
+    Color::rgb(0xf4, 0xa5, 0x82),
+    Color::rgb(0xfd, 0xdb, 0xc7),
+    Color::rgb(0xf7, 0xf7, 0xf7),
+    Color::rgb(0xd1, 0xe5, 0xf0),
+    Color::rgb(0x92, 0xc5, 0xde),
+    Color::rgb(0x43, 0x93, 0xc3),
+    Color::rgb(0x21, 0x66, 0xac),
+    Color::rgb(0x05, 0x30, 0x61),
+];
+
+/// A continuous map from numbers to colors, for heatmaps and bars.
+///
+/// Samples outside 0.0-1.0 are clamped, and NaN samples as 0.0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Colormap {
+    kind: ColormapKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ColormapKind {
+    Polynomial(&'static Coefficients),
+    Stops {
+        stops: Vec<(f32, Color)>,
+        space: InterpolationSpace,
+    },
+}
+
+impl Colormap {
+    /// Create a colormap through `(position, color)` stops, interpolated in
+    /// sRGB.
+    ///
+    /// Positions are clamped to 0.0-1.0 and sorted; stops with a NaN position
+    /// are ignored. Before the first stop and after the last, the map holds
+    /// the end color. A map without stops samples as black.
+    pub fn new(stops: impl IntoIterator<Item = (f32, Color)>) -> Self {
+        let mut stops: Vec<(f32, Color)> = stops
+            .into_iter()
+            .filter(|(position, _)| !position.is_nan())
+            .map(|(position, color)| (position.clamp(0.0, 1.0), color))
+            .collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Self {
+            kind: ColormapKind::Stops {
+                stops,
+                space: InterpolationSpace::default(),
+            },
+        }
+    }
+
+    /// Create a colormap through evenly spaced colors, interpolated in sRGB.
+    pub fn evenly_spaced(colors: impl IntoIterator<Item = Color>) -> Self {
+        let colors: Vec<Color> = colors.into_iter().collect();
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        Self::new(
+            colors
+                .into_iter()
+                .enumerate()
+                .map(|(index, color)| (index as f32 / last, color)),
+        )
+    }
+
+    /// Set the space custom stops are interpolated in. Built-in polynomial
+    /// maps are unaffected.
+    pub fn space(mut self, space: InterpolationSpace) -> Self {
+        if let ColormapKind::Stops { space: current, .. } = &mut self.kind {
+            *current = space;
+        }
+        self
+    }
+
+    /// Matplotlib's perceptually uniform viridis map, from dark purple to
+    /// yellow.
+    pub fn viridis() -> Self {
+        Self::polynomial(&VIRIDIS)
+    }
+
+    /// Matplotlib's magma map, from black through purple to pale yellow.
+    pub fn magma() -> Self {
+        Self::polynomial(&MAGMA)
+    }
+
+    /// Matplotlib's plasma map, from blue through magenta to yellow.
+    pub fn plasma() -> Self {
+        Self::polynomial(&PLASMA)
+    }
+
+    /// Matplotlib's inferno map, from black through red to pale yellow.
+    pub fn inferno() -> Self {
+        Self::polynomial(&INFERNO)
+    }
+
+    /// The cividis map, from blue to yellow, designed to read the same with
+    /// red-green color vision deficiencies.
+    pub fn cividis() -> Self {
+        Self::evenly_spaced(CIVIDIS)
+    }
+
+    /// Google's Turbo rainbow map, from dark blue through green to dark red.
+    pub fn turbo() -> Self {
+        Self::polynomial(&TURBO)
+    }
+
+    /// A diverging map from dark red through near-white to dark blue, for
+    /// values on either side of a midpoint.
+    pub fn red_blue() -> Self {
+        Self::evenly_spaced(RED_BLUE)
+    }
+
+    fn polynomial(coefficients: &'static Coefficients) -> Self {
+        Self {
+            kind: ColormapKind::Polynomial(coefficients),
+        }
+    }
+
+    /// Sample the map at `t`, clamped to 0.0-1.0.
+    pub fn sample(&self, t: f32) -> Color {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        match &self.kind {
+            ColormapKind::Polynomial(coefficients) => {
+                let channel = |index: usize| {
+                    let value = coefficients
+                        .iter()
+                        .rev()
+                        .fold(0.0, |sum, row| sum * t + row[index]);
+                    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+                };
+                Color::rgb(channel(0), channel(1), channel(2))
+            }
+            ColormapKind::Stops { stops, space } => sample_stops(stops, t, *space),
+        }
+    }
+
+    /// Sample the map at `value`'s position from `min` to `max`, clamped to
+    /// the range.
+    ///
+    /// A `min` greater than `max` reverses the map. An empty range samples
+    /// the start of the map.
+    pub fn sample_range(&self, value: f32, min: f32, max: f32) -> Color {
+        let span = max - min;
+        let t = if span == 0.0 || !span.is_finite() {
+            0.0
+        } else {
+            (value - min) / span
+        };
+        self.sample(t)
+    }
+}
+
+fn sample_stops(stops: &[(f32, Color)], t: f32, space: InterpolationSpace) -> Color {
+    let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+        return Color::rgb(0, 0, 0);
+    };
+    if t <= first.0 {
+        return first.1;
+    }
+    if t >= last.0 {
+        return last.1;
+    }
+
+    let index = stops.partition_point(|(position, _)| *position <= t);
+    let (from_position, from) = stops[index - 1];
+    let (to_position, to) = stops[index];
+    let span = to_position - from_position;
+    if span <= 0.0 {
+        return to;
+    }
+
+    interpolate(from, to, (t - from_position) / span, space)
+}
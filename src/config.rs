@@ -1,9 +1,16 @@
 use std::cell::RefCell;
 use std::io::IsTerminal;
+use std::sync::{Arc, RwLock};
 
+use crate::color::{Color, RenderContext};
 use crate::terminal::{
-    color_level_for_capabilities, terminal_capabilities, ColorLevel, TerminalCapabilities,
+    color_level_for_capabilities, terminal_capabilities, Background, ColorLevel,
+    TerminalCapabilities,
 };
+use crate::theme::{default_theme, Theme};
+#[cfg(feature = "serde")]
+use crate::user_config::user_config;
+use crate::vision::ColorTransform;
 
 /// Runtime color policy for rendered output.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -45,12 +52,46 @@ pub enum ColorDepthMode {
     Auto,
     /// Disable all ANSI SGR output.
     NoColor,
+    /// Select the eight base ANSI colors when color output is enabled.
+    Ansi8,
     /// Select named ANSI color output when color output is enabled.
     Ansi16,
     /// Select ANSI 256-color output when color output is enabled.
     Ansi256,
     /// Select 24-bit RGB truecolor output when color output is enabled.
     TrueColor,
+    /// Detect color depth like [`ColorDepthMode::Auto`], but render every
+    /// color as a gray of the same luminance.
+    ///
+    /// Truecolor output uses gray RGB values, ANSI 256 output uses the
+    /// 232-255 grayscale ramp, and ANSI 16 output uses black, bright black,
+    /// white, and bright white. Text styles are kept. This suits monochrome
+    /// and e-ink terminals or grayscale printing.
+    Grayscale,
+}
+
+/// Parameter syntax for ANSI 256 and RGB color codes.
+///
+/// ITU T.416 separates the parts of an extended color with colons, which
+/// keeps them from being read as separate SGR parameters. Most terminals also
+/// accept the older semicolon form, which remains the default because every
+/// terminal that supports extended colors understands it.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ExtendedColorSyntax {
+    /// Separate parts with semicolons: `38;5;208` and `38;2;255;128;0`.
+    #[default]
+    Semicolon,
+    /// Separate parts with colons and include the empty color space ID that
+    /// T.416 defines for RGB colors: `38:5:208` and `38:2::255:128:0`.
+    ///
+    /// This is the exact T.416 form, understood by xterm, kitty, mintty, and
+    /// other terminals that parse colon sub-parameters.
+    ColonWithColorSpace,
+    /// Separate parts with colons and omit the color space ID:
+    /// `38:5:208` and `38:2:255:128:0`.
+    ///
+    /// Some terminals that accept colons only understand this shorter form.
+    Colon,
 }
 
 /// Configuration for controlling runtime color behavior.
@@ -62,8 +103,18 @@ pub enum ColorDepthMode {
 pub struct ColorizeConfig {
     color_mode: ColorMode,
     color_depth_mode: ColorDepthMode,
+    background: Option<Background>,
+    background_color: Option<Color>,
+    default_background: Background,
+    color_transform: ColorTransform,
+    extended_color_syntax: ExtendedColorSyntax,
+    theme: Option<Arc<Theme>>,
+    warn_unknown_roles: bool,
 }
 
+/// Theme used by threads that have not set their own.
+static PROCESS_THEME: RwLock<Option<Arc<Theme>>> = RwLock::new(None);
+
 thread_local! {
     static CONFIG: RefCell<ColorizeConfig> = RefCell::new(ColorizeConfig::default());
     #[cfg(test)]
@@ -79,6 +130,13 @@ impl Default for ColorizeConfig {
         Self {
             color_mode: ColorMode::Auto,
             color_depth_mode: ColorDepthMode::Auto,
+            background: None,
+            background_color: None,
+            default_background: Background::Dark,
+            color_transform: ColorTransform::None,
+            extended_color_syntax: ExtendedColorSyntax::Semicolon,
+            theme: None,
+            warn_unknown_roles: false,
         }
     }
 }
@@ -117,6 +175,130 @@ impl ColorizeConfig {
         CONFIG.with(|config| config.borrow().color_depth_mode)
     }
 
+    /// Set the background brightness override for the current thread.
+    ///
+    /// `Some` replaces environment-based background detection for normal
+    /// targets. `None` restores detection from `COLORFGBG` and
+    /// terminal-specific variables. [`RenderTarget::Capabilities`] values keep
+    /// their supplied background.
+    pub fn set_background(background: Option<Background>) {
+        CONFIG.with(|config| config.borrow_mut().background = background);
+    }
+
+    /// Get the background brightness override for the current thread.
+    pub fn background() -> Option<Background> {
+        CONFIG.with(|config| config.borrow().background)
+    }
+
+    /// Set the exact terminal background color for the current thread.
+    ///
+    /// Alpha colors are composited against this color. `Some` replaces the
+    /// palette color detected from `COLORFGBG` for normal targets and, unless
+    /// [`ColorizeConfig::set_background`] is also set, decides the background
+    /// brightness. `None` restores detection. [`RenderTarget::Capabilities`]
+    /// values keep their supplied background color.
+    pub fn set_background_color(color: Option<Color>) {
+        CONFIG.with(|config| config.borrow_mut().background_color = color);
+    }
+
+    /// Get the exact terminal background color override for the current
+    /// thread.
+    pub fn background_color() -> Option<Color> {
+        CONFIG.with(|config| config.borrow().background_color)
+    }
+
+    /// Set the background assumed for the current thread when it is unknown.
+    ///
+    /// Adaptive colors use this value when neither the configured override,
+    /// environment detection, nor supplied capabilities provide a background.
+    /// The default is [`Background::Dark`].
+    pub fn set_default_background(background: Background) {
+        CONFIG.with(|config| config.borrow_mut().default_background = background);
+    }
+
+    /// Get the background assumed for the current thread when it is unknown.
+    pub fn default_background() -> Background {
+        CONFIG.with(|config| config.borrow().default_background)
+    }
+
+    /// Set the color transform applied at render time for the current thread.
+    ///
+    /// The transform is applied to every color before it is quantized to the
+    /// resolved color level, which makes it possible to preview output as a
+    /// viewer with a color vision deficiency sees it, or to correct for one.
+    /// The default is [`ColorTransform::None`].
+    pub fn set_color_transform(transform: ColorTransform) {
+        CONFIG.with(|config| config.borrow_mut().color_transform = transform);
+    }
+
+    /// Get the color transform applied at render time for the current thread.
+    pub fn color_transform() -> ColorTransform {
+        CONFIG.with(|config| config.borrow().color_transform)
+    }
+
+    /// Set the syntax of ANSI 256 and RGB color codes for the current thread.
+    ///
+    /// The default is [`ExtendedColorSyntax::Semicolon`].
+    /// [`RenderTarget::Capabilities`] values keep their supplied syntax.
+    pub fn set_extended_color_syntax(syntax: ExtendedColorSyntax) {
+        CONFIG.with(|config| config.borrow_mut().extended_color_syntax = syntax);
+    }
+
+    /// Get the syntax of ANSI 256 and RGB color codes for the current thread.
+    pub fn extended_color_syntax() -> ExtendedColorSyntax {
+        CONFIG.with(|config| config.borrow().extended_color_syntax)
+    }
+
+    /// Set the theme used to style roles on the current thread.
+    ///
+    /// `Some` overrides the process theme for this thread. `None` restores the
+    /// process theme, or [`Theme::default_theme`] when none is set.
+    pub fn set_theme(theme: Option<Theme>) {
+        CONFIG.with(|config| config.borrow_mut().theme = theme.map(Arc::new));
+    }
+
+    /// Get the theme set for the current thread, if any.
+    pub fn theme() -> Option<Theme> {
+        CONFIG.with(|config| config.borrow().theme.as_deref().cloned())
+    }
+
+    /// Set the theme used to style roles on threads without their own theme.
+    ///
+    /// `None` restores [`Theme::default_theme`].
+    pub fn set_process_theme(theme: Option<Theme>) {
+        *PROCESS_THEME
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = theme.map(Arc::new);
+    }
+
+    /// Get the theme set for the process, if any.
+    pub fn process_theme() -> Option<Theme> {
+        process_theme().as_deref().cloned()
+    }
+
+    /// Get the theme roles are styled with on the current thread: the thread
+    /// theme, then the process theme, then [`Theme::default_theme`].
+    ///
+    /// With the `serde` feature, the theme from an installed
+    /// [`UserConfig`](crate::UserConfig) overrides the roles it defines.
+    pub fn active_theme() -> Theme {
+        with_active_theme(Theme::clone)
+    }
+
+    /// Set whether roles missing from the active theme print a warning to
+    /// stderr on the current thread.
+    ///
+    /// Each unknown role is reported once per process. Unknown roles render
+    /// plain either way. The default is `false`.
+    pub fn set_warn_unknown_roles(warn: bool) {
+        CONFIG.with(|config| config.borrow_mut().warn_unknown_roles = warn);
+    }
+
+    /// Get whether unknown roles print a warning on the current thread.
+    pub fn warn_unknown_roles() -> bool {
+        CONFIG.with(|config| config.borrow().warn_unknown_roles)
+    }
+
     /// Resolve terminal capabilities for a render target using current config.
     pub fn terminal_capabilities(target: RenderTarget) -> TerminalCapabilities {
         capabilities_for(target)
@@ -142,31 +324,112 @@ impl ColorizeConfig {
     }
 }
 
-pub(crate) fn color_level() -> ColorLevel {
-    color_level_for(RenderTarget::Stdout)
+/// Call `f` with the theme roles are styled with on the current thread.
+pub(crate) fn with_active_theme<T>(f: impl FnOnce(&Theme) -> T) -> T {
+    let theme = CONFIG
+        .with(|config| config.borrow().theme.clone())
+        .or_else(process_theme);
+    let theme = theme.as_deref().unwrap_or_else(|| default_theme());
+
+    #[cfg(feature = "serde")]
+    if let Some(config) = user_config() {
+        if let Some(user_theme) = config.theme() {
+            return f(&theme.overlaid_with(user_theme));
+        }
+    }
+
+    f(theme)
 }
 
-pub(crate) fn color_level_for(target: RenderTarget) -> ColorLevel {
-    capabilities_for(target).color_level
+fn process_theme() -> Option<Arc<Theme>> {
+    PROCESS_THEME
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+pub(crate) fn render_context() -> RenderContext {
+    render_context_for(RenderTarget::Stdout)
+}
+
+pub(crate) fn render_context_for(target: RenderTarget) -> RenderContext {
+    let capabilities = capabilities_for(target);
+    let context = RenderContext::new(
+        capabilities.color_level,
+        capabilities
+            .background
+            .unwrap_or_else(ColorizeConfig::default_background),
+    )
+    .with_transform(ColorizeConfig::color_transform())
+    .with_grayscale(capabilities.grayscale)
+    .with_extended_color_syntax(capabilities.extended_color_syntax);
+
+    match capabilities.background_color {
+        Some(color) => context.with_background_color(color),
+        None => context,
+    }
 }
 
 fn capabilities_for(target: RenderTarget) -> TerminalCapabilities {
-    let color_mode = ColorizeConfig::color_mode();
-    let depth_mode = ColorizeConfig::color_depth_mode();
+    let color_mode = effective_color_mode();
+    let depth_mode = effective_color_depth_mode();
+    let background = ColorizeConfig::background();
+    let background_color = ColorizeConfig::background_color();
+    let syntax = ColorizeConfig::extended_color_syntax();
 
     match target {
         RenderTarget::Capabilities(capabilities) => TerminalCapabilities {
             color_level: color_level_for_capabilities(capabilities, color_mode, depth_mode),
+            grayscale: capabilities.grayscale || depth_mode == ColorDepthMode::Grayscale,
             ..capabilities
         },
-        RenderTarget::Stdout => terminal_capabilities(stdout_is_terminal(), color_mode, depth_mode),
-        RenderTarget::Stderr => terminal_capabilities(stderr_is_terminal(), color_mode, depth_mode),
-        RenderTarget::Terminal(is_terminal) => {
-            terminal_capabilities(is_terminal, color_mode, depth_mode)
-        }
+        RenderTarget::Stdout => terminal_capabilities(
+            stdout_is_terminal(),
+            color_mode,
+            depth_mode,
+            background,
+            background_color,
+            syntax,
+        ),
+        RenderTarget::Stderr => terminal_capabilities(
+            stderr_is_terminal(),
+            color_mode,
+            depth_mode,
+            background,
+            background_color,
+            syntax,
+        ),
+        RenderTarget::Terminal(is_terminal) => terminal_capabilities(
+            is_terminal,
+            color_mode,
+            depth_mode,
+            background,
+            background_color,
+            syntax,
+        ),
     }
 }
 
+/// Return the user config file's color mode, or the one set in-process.
+fn effective_color_mode() -> ColorMode {
+    #[cfg(feature = "serde")]
+    if let Some(mode) = user_config().and_then(|config| config.color_mode()) {
+        return mode;
+    }
+
+    ColorizeConfig::color_mode()
+}
+
+/// Return the user config file's color depth mode, or the one set in-process.
+pub(crate) fn effective_color_depth_mode() -> ColorDepthMode {
+    #[cfg(feature = "serde")]
+    if let Some(mode) = user_config().and_then(|config| config.color_depth_mode()) {
+        return mode;
+    }
+
+    ColorizeConfig::color_depth_mode()
+}
+
 fn stdout_is_terminal() -> bool {
     #[cfg(test)]
     if let Some(value) = STDOUT_TERMINAL_OVERRIDE.with(|override_value| *override_value.borrow()) {
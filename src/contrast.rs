@@ -0,0 +1,196 @@
+use crate::color::{hsl_to_rgb, relative_luminance, rgb_to_hsl, Color};
+
+const BLACK: Color = Color::rgb(0, 0, 0);
+const WHITE: Color = Color::rgb(255, 255, 255);
+const LIGHTNESS_SEARCH_STEPS: usize = 16;
+
+// APCA 0.0.98G-4g constants.
+const APCA_BLACK_THRESHOLD: f32 = 0.022;
+const APCA_BLACK_CLAMP: f32 = 1.414;
+const APCA_DELTA_Y_MIN: f32 = 0.0005;
+const APCA_NORMAL_BACKGROUND: f32 = 0.56;
+const APCA_NORMAL_TEXT: f32 = 0.57;
+const APCA_REVERSE_TEXT: f32 = 0.62;
+const APCA_REVERSE_BACKGROUND: f32 = 0.65;
+const APCA_SCALE: f32 = 1.14;
+const APCA_OFFSET: f32 = 0.027;
+const APCA_LOW_CLIP: f32 = 0.1;
+
+/// A minimum contrast between a foreground and background color.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ContrastTarget {
+    /// WCAG 2.x level AA for normal text, a ratio of 4.5:1.
+    #[default]
+    Aa,
+    /// WCAG 2.x level AAA for normal text, a ratio of 7:1.
+    Aaa,
+    /// A custom WCAG 2.x contrast ratio, from 1.0 to 21.0.
+    Ratio(f32),
+    /// A minimum APCA lightness contrast, compared against the absolute `Lc`
+    /// value (for example 60 for body text or 75 for fluent reading).
+    Apca(f32),
+}
+
+impl ContrastTarget {
+    fn score(self, foreground: Color, background: Color) -> f32 {
+        match self {
+            Self::Aa | Self::Aaa | Self::Ratio(_) => foreground.contrast_ratio(background),
+            Self::Apca(_) => foreground.apca_contrast(background).abs(),
+        }
+    }
+
+    fn threshold(self) -> f32 {
+        match self {
+            Self::Aa => 4.5,
+            Self::Aaa => 7.0,
+            Self::Ratio(ratio) => ratio,
+            Self::Apca(lc) => lc.abs(),
+        }
+    }
+}
+
+impl Color {
+    /// Return the WCAG 2.x relative luminance, from 0.0 for black to 1.0 for
+    /// white.
+    pub fn relative_luminance(self) -> f32 {
+        relative_luminance(self.r, self.g, self.b)
+    }
+
+    /// Return the WCAG 2.x contrast ratio against another color.
+    ///
+    /// The ratio is symmetric and ranges from 1.0 for identical luminance to
+    /// 21.0 for black against white.
+    pub fn contrast_ratio(self, other: Color) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Return the APCA lightness contrast (`Lc`) of this text color on a
+    /// background.
+    ///
+    /// Unlike the WCAG ratio, APCA depends on polarity: dark text on a light
+    /// background gives a positive value and light text on a dark background
+    /// gives a negative value. Values range from roughly -108 to 106.
+    pub fn apca_contrast(self, background: Color) -> f32 {
+        let text = apca_luminance(self);
+        let background = apca_luminance(background);
+
+        if (background - text).abs() < APCA_DELTA_Y_MIN {
+            return 0.0;
+        }
+
+        let contrast = if background > text {
+            let sapc = (background.powf(APCA_NORMAL_BACKGROUND) - text.powf(APCA_NORMAL_TEXT))
+                * APCA_SCALE;
+            if sapc < APCA_LOW_CLIP {
+                0.0
+            } else {
+                sapc - APCA_OFFSET
+            }
+        } else {
+            let sapc = (background.powf(APCA_REVERSE_BACKGROUND) - text.powf(APCA_REVERSE_TEXT))
+                * APCA_SCALE;
+            if sapc > -APCA_LOW_CLIP {
+                0.0
+            } else {
+                sapc + APCA_OFFSET
+            }
+        };
+
+        contrast * 100.0
+    }
+
+    /// Return whether this text color meets a contrast target on a background.
+    pub fn meets_contrast(self, background: Color, target: ContrastTarget) -> bool {
+        target.score(self, background) >= target.threshold()
+    }
+
+    /// Return black or white, whichever has the higher WCAG contrast against a
+    /// background.
+    pub fn black_or_white_on(background: Color) -> Color {
+        contrasting(background, ContrastTarget::Aa)
+    }
+
+    /// Return a version of this color that is readable on a background.
+    ///
+    /// If the color already meets the target it is returned unchanged.
+    /// Otherwise its HSL lightness is adjusted by the smallest amount that
+    /// meets the target, keeping hue and saturation. If neither darkening nor
+    /// lightening can reach the target, black or white is returned, whichever
+    /// contrasts more.
+    pub fn readable_on(self, background: Color, target: ContrastTarget) -> Color {
+        if self.meets_contrast(background, target) {
+            return self;
+        }
+
+        let (h, s, l) = rgb_to_hsl(self.r, self.g, self.b);
+        let darker = search_lightness(h, s, l, 0.0, background, target);
+        let lighter = search_lightness(h, s, l, 100.0, background, target);
+
+        match (darker, lighter) {
+            (Some((dark_l, dark)), Some((light_l, light))) => {
+                if l - dark_l <= light_l - l {
+                    dark
+                } else {
+                    light
+                }
+            }
+            (Some((_, color)), None) | (None, Some((_, color))) => color,
+            (None, None) => contrasting(background, target),
+        }
+    }
+}
+
+/// Return black or white, whichever scores higher against a background for
+/// `target`'s contrast measure.
+pub(crate) fn contrasting(background: Color, target: ContrastTarget) -> Color {
+    if target.score(BLACK, background) >= target.score(WHITE, background) {
+        BLACK
+    } else {
+        WHITE
+    }
+}
+
+/// Binary search HSL lightness between `start`, which fails the target, and
+/// `end` for the value closest to `start` that meets it.
+fn search_lightness(
+    h: f32,
+    s: f32,
+    start: f32,
+    end: f32,
+    background: Color,
+    target: ContrastTarget,
+) -> Option<(f32, Color)> {
+    let color_at = |l: f32| Color::from(hsl_to_rgb(h, s, l));
+    let mut passing = end;
+    let mut failing = start;
+
+    if !color_at(passing).meets_contrast(background, target) {
+        return None;
+    }
+
+    for _ in 0..LIGHTNESS_SEARCH_STEPS {
+        let middle = (passing + failing) / 2.0;
+        if color_at(middle).meets_contrast(background, target) {
+            passing = middle;
+        } else {
+            failing = middle;
+        }
+    }
+
+    Some((passing, color_at(passing)))
+}
+
+fn apca_luminance(color: Color) -> f32 {
+    let channel = |value: u8| (f32::from(value) / 255.0).powf(2.4);
+    let luminance = 0.212_672_9 * channel(color.r)
+        + 0.715_152_2 * channel(color.g)
+        + 0.072_175 * channel(color.b);
+
+    if luminance < APCA_BLACK_THRESHOLD {
+        luminance + (APCA_BLACK_THRESHOLD - luminance).powf(APCA_BLACK_CLAMP)
+    } else {
+        luminance
+    }
+}
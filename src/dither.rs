@@ -0,0 +1,201 @@
+use crate::color::{quantization_palette, Color, ColorSpec, PaletteEntry, RenderContext};
+use crate::terminal::ColorLevel;
+
+/// One-dimensional Bayer ordering, spreading thresholds evenly over any run of
+/// eight neighbouring positions.
+const BAYER: [u8; 8] = [0, 4, 2, 6, 1, 5, 3, 7];
+
+/// Largest per-channel error carried forward, so a color the palette cannot
+/// bracket does not build up error without bound.
+const MAX_ERROR: f32 = 64.0;
+
+/// How RGB colors are quantized to a limited terminal palette.
+///
+/// Without dithering every position maps to its nearest palette entry, so
+/// smooth ramps collapse into visible bands. With dithering each position picks
+/// between the two nearest entries so that the run as a whole approximates the
+/// original colors. Dithering only affects ANSI 256, ANSI 16, and ANSI 8
+/// output.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Dither {
+    /// Quantize each position to its nearest palette entry.
+    #[default]
+    None,
+    /// Ordered dithering against a repeating Bayer threshold pattern.
+    ///
+    /// The result at a position depends only on its color and index, so
+    /// identical inputs always produce identical output.
+    Ordered,
+    /// Error diffusion, carrying each position's quantization error forward
+    /// to the next position along the run.
+    ErrorDiffusion,
+}
+
+impl Dither {
+    /// Return whether this mode changes colors rendered at `level`.
+    pub(crate) fn applies_to(self, level: ColorLevel) -> bool {
+        self != Self::None
+            && matches!(
+                level,
+                ColorLevel::Ansi8 | ColorLevel::Ansi16 | ColorLevel::Ansi256
+            )
+    }
+}
+
+/// Quantizes the colors of one run of positions according to a [`Dither`]
+/// mode.
+#[derive(Clone, Debug)]
+pub(crate) struct Ditherer {
+    dither: Dither,
+    palette: &'static [PaletteEntry],
+    grayscale: bool,
+    error: [f32; 3],
+}
+
+impl Ditherer {
+    pub(crate) fn new(dither: Dither, context: RenderContext) -> Self {
+        let palette = if dither.applies_to(context.level) {
+            quantization_palette(context)
+        } else {
+            &[]
+        };
+
+        Self {
+            dither,
+            palette,
+            grayscale: context.grayscale,
+            error: [0.0; 3],
+        }
+    }
+
+    /// Quantize `color` at `position` along the run.
+    ///
+    /// Colors that are not RGB, such as named colors and ANSI 256 indexes, are
+    /// returned unchanged. In grayscale output, colors are reduced to their
+    /// luminance and dithered between grays.
+    pub(crate) fn apply(&mut self, color: ColorSpec, position: usize) -> ColorSpec {
+        match color {
+            ColorSpec::Rgb(r, g, b) if !self.palette.is_empty() => {
+                let color = Color::rgb(r, g, b);
+                let color = if self.grayscale {
+                    color.grayscale()
+                } else {
+                    color
+                };
+                self.quantize(color, position)
+            }
+            color => color,
+        }
+    }
+
+    fn quantize(&mut self, color: Color, position: usize) -> ColorSpec {
+        let target = [f32::from(color.r), f32::from(color.g), f32::from(color.b)];
+        let (nearest, second) = self.two_nearest(target);
+        let first_rgb = rgb_components(self.palette[nearest].1);
+        let second_rgb = rgb_components(self.palette[second].1);
+
+        let chosen = match self.dither {
+            Dither::Ordered => {
+                let threshold =
+                    (f32::from(BAYER[position % BAYER.len()]) + 0.5) / BAYER.len() as f32;
+                if projection(target, first_rgb, second_rgb) > threshold {
+                    second
+                } else {
+                    nearest
+                }
+            }
+            Dither::ErrorDiffusion | Dither::None => {
+                let wanted = [
+                    target[0] + self.error[0],
+                    target[1] + self.error[1],
+                    target[2] + self.error[2],
+                ];
+                let chosen = if distance(wanted, second_rgb) < distance(wanted, first_rgb) {
+                    second
+                } else {
+                    nearest
+                };
+                let chosen_rgb = rgb_components(self.palette[chosen].1);
+                for channel in 0..3 {
+                    self.error[channel] =
+                        (wanted[channel] - chosen_rgb[channel]).clamp(-MAX_ERROR, MAX_ERROR);
+                }
+                chosen
+            }
+        };
+
+        self.palette[chosen].0.clone()
+    }
+
+    /// Return the palette indexes of the nearest entry and the entry to dither
+    /// it with.
+    ///
+    /// The partner is the nearest entry on the other side of `target`, so that
+    /// mixing the pair can approximate it. When no entry lies on the other side
+    /// the second-nearest entry is used. Palette order breaks ties.
+    fn two_nearest(&self, target: [f32; 3]) -> (usize, usize) {
+        let nearest = self.nearest_where(target, |_| true);
+        let nearest_rgb = rgb_components(self.palette[nearest].1);
+        let opposite = |rgb: [f32; 3]| {
+            (0..3)
+                .map(|channel| {
+                    (rgb[channel] - target[channel]) * (nearest_rgb[channel] - target[channel])
+                })
+                .sum::<f32>()
+                < 0.0
+        };
+
+        let partner = if self
+            .palette
+            .iter()
+            .any(|(_, rgb)| opposite(rgb_components(*rgb)))
+        {
+            self.nearest_where(target, opposite)
+        } else {
+            self.nearest_where(target, |rgb| rgb != nearest_rgb)
+        };
+
+        (nearest, partner)
+    }
+
+    fn nearest_where(&self, target: [f32; 3], accept: impl Fn([f32; 3]) -> bool) -> usize {
+        let mut best = (0, f32::INFINITY);
+        for (index, (_, rgb)) in self.palette.iter().enumerate() {
+            let rgb = rgb_components(*rgb);
+            let candidate = distance(target, rgb);
+            if accept(rgb) && candidate < best.1 {
+                best = (index, candidate);
+            }
+        }
+
+        best.0
+    }
+}
+
+/// Return how far `target` lies along the segment from `from` to `to`, clamped
+/// to 0.0-1.0.
+fn projection(target: [f32; 3], from: [f32; 3], to: [f32; 3]) -> f32 {
+    let mut dot = 0.0;
+    let mut length = 0.0;
+    for channel in 0..3 {
+        let segment = to[channel] - from[channel];
+        dot += (target[channel] - from[channel]) * segment;
+        length += segment * segment;
+    }
+
+    if length == 0.0 {
+        0.0
+    } else {
+        (dot / length).clamp(0.0, 1.0)
+    }
+}
+
+fn distance(a: [f32; 3], b: [f32; 3]) -> f32 {
+    (0..3)
+        .map(|channel| (a[channel] - b[channel]).powi(2))
+        .sum()
+}
+
+fn rgb_components((r, g, b): (u8, u8, u8)) -> [f32; 3] {
+    [f32::from(r), f32::from(g), f32::from(b)]
+}
@@ -0,0 +1,172 @@
+use crate::color::{linear_to_srgb, oklch_to_rgb, rgb_to_oklch, srgb_to_linear, Color};
+
+const RAINBOW: [Color; 6] = [
+    Color::rgb(255, 0, 0),
+    Color::rgb(255, 255, 0),
+    Color::rgb(0, 255, 0),
+    Color::rgb(0, 255, 255),
+    Color::rgb(0, 0, 255),
+    Color::rgb(255, 0, 255),
+];
+
+const RAINBOW_CYCLE: [Color; 7] = [
+    Color::rgb(255, 0, 0),
+    Color::rgb(255, 255, 0),
+    Color::rgb(0, 255, 0),
+    Color::rgb(0, 255, 255),
+    Color::rgb(0, 0, 255),
+    Color::rgb(255, 0, 255),
+    Color::rgb(255, 0, 0),
+];
+
+const PASTEL_RAINBOW: [Color; 6] = [
+    Color::rgb(255, 179, 186),
+    Color::rgb(255, 223, 186),
+    Color::rgb(255, 255, 186),
+    Color::rgb(186, 255, 201),
+    Color::rgb(186, 225, 255),
+    Color::rgb(218, 186, 255),
+];
+
+/// Chroma below which an Oklch color is treated as gray and has no usable hue.
+const ACHROMATIC_CHROMA: f32 = 1e-4;
+
+/// Color space used to interpolate between gradient stops.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum InterpolationSpace {
+    /// Interpolate gamma-encoded sRGB components directly.
+    #[default]
+    Srgb,
+    /// Interpolate in linear light, which avoids dark midpoints.
+    Linear,
+    /// Interpolate lightness, chroma, and hue in Oklch, taking the shorter way
+    /// around the hue circle.
+    Oklch,
+}
+
+/// A multi-stop color gradient.
+///
+/// Stops are spaced evenly from 0.0 to 1.0. A gradient with a single stop is a
+/// solid color, and a gradient without stops samples as black.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Gradient {
+    stops: Vec<Color>,
+    space: InterpolationSpace,
+}
+
+impl Gradient {
+    /// Create a gradient through the given stops, interpolated in sRGB.
+    pub fn new(stops: impl IntoIterator<Item = Color>) -> Self {
+        Self {
+            stops: stops.into_iter().collect(),
+            space: InterpolationSpace::default(),
+        }
+    }
+
+    /// A rainbow from red through yellow, green, cyan, and blue to magenta.
+    pub fn rainbow() -> Self {
+        Self::new(RAINBOW)
+    }
+
+    /// A rainbow that returns to red at the end, so repeated spans join
+    /// without a jump.
+    pub fn rainbow_cycle() -> Self {
+        Self::new(RAINBOW_CYCLE)
+    }
+
+    /// A light rainbow from pink to lavender that stays readable on dark
+    /// backgrounds, as text or behind dark text.
+    pub fn pastel_rainbow() -> Self {
+        Self::new(PASTEL_RAINBOW)
+    }
+
+    /// Set the interpolation space.
+    pub fn space(mut self, space: InterpolationSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Return the gradient stops.
+    pub fn stops(&self) -> &[Color] {
+        &self.stops
+    }
+
+    /// Return the interpolation space.
+    pub fn interpolation_space(&self) -> InterpolationSpace {
+        self.space
+    }
+
+    /// Sample the gradient at `t`, clamped to 0.0-1.0.
+    pub fn sample(&self, t: f32) -> Color {
+        match self.stops.as_slice() {
+            [] => Color::rgb(0, 0, 0),
+            [color] => *color,
+            stops => {
+                let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+                let segments = stops.len() - 1;
+                let position = t * segments as f32;
+                let index = (position.floor() as usize).min(segments - 1);
+                interpolate(
+                    stops[index],
+                    stops[index + 1],
+                    position - index as f32,
+                    self.space,
+                )
+            }
+        }
+    }
+}
+
+impl From<&[Color]> for Gradient {
+    fn from(stops: &[Color]) -> Self {
+        Self::new(stops.iter().copied())
+    }
+}
+
+impl<const N: usize> From<[Color; N]> for Gradient {
+    fn from(stops: [Color; N]) -> Self {
+        Self::new(stops)
+    }
+}
+
+pub(crate) fn interpolate(from: Color, to: Color, t: f32, space: InterpolationSpace) -> Color {
+    match space {
+        InterpolationSpace::Srgb => {
+            let channel =
+                |a: u8, b: u8| (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round() as u8;
+            Color::rgb(
+                channel(from.r, to.r),
+                channel(from.g, to.g),
+                channel(from.b, to.b),
+            )
+        }
+        InterpolationSpace::Linear => {
+            let channel = |a: u8, b: u8| {
+                let a = srgb_to_linear(a);
+                linear_to_srgb(a + (srgb_to_linear(b) - a) * t)
+            };
+            Color::rgb(
+                channel(from.r, to.r),
+                channel(from.g, to.g),
+                channel(from.b, to.b),
+            )
+        }
+        InterpolationSpace::Oklch => {
+            let (l1, c1, h1) = rgb_to_oklch(from);
+            let (l2, c2, h2) = rgb_to_oklch(to);
+            let (h1, h2) = match (c1 < ACHROMATIC_CHROMA, c2 < ACHROMATIC_CHROMA) {
+                (true, false) => (h2, h2),
+                (false, true) => (h1, h1),
+                _ => (h1, h2),
+            };
+            let mut delta = h2 - h1;
+            if delta > 180.0 {
+                delta -= 360.0;
+            } else if delta < -180.0 {
+                delta += 360.0;
+            }
+
+            oklch_to_rgb(l1 + (l2 - l1) * t, c1 + (c2 - c1) * t, h1 + delta * t)
+        }
+    }
+}
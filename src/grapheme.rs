@@ -0,0 +1,74 @@
+/// Split text into approximate extended grapheme clusters.
+///
+/// This covers the cases that matter for per-character coloring without a
+/// Unicode data dependency: CR LF pairs, combining marks, variation selectors,
+/// emoji modifiers and tags, zero-width joiner sequences, and regional
+/// indicator flag pairs. Scripts that need the full UAX #29 rules may split
+/// into more clusters than a complete implementation would.
+pub(crate) fn graphemes(text: &str) -> Vec<&str> {
+    let mut clusters = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((start, first)) = chars.next() {
+        let mut previous = first;
+        let mut regional_indicators = usize::from(is_regional_indicator(first));
+
+        while let Some(&(_, next)) = chars.peek() {
+            let joins = (previous == '\r' && next == '\n')
+                || is_extend(next)
+                || previous == '\u{200d}'
+                || (is_regional_indicator(next) && regional_indicators == 1);
+            if !joins {
+                break;
+            }
+
+            if is_regional_indicator(next) {
+                regional_indicators += 1;
+            }
+            previous = next;
+            chars.next();
+        }
+
+        let end = chars.peek().map_or(text.len(), |&(index, _)| index);
+        clusters.push(&text[start..end]);
+    }
+
+    clusters
+}
+
+fn is_extend(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{0300}'..='\u{036f}'
+            | '\u{0483}'..='\u{0489}'
+            | '\u{0591}'..='\u{05bd}'
+            | '\u{05bf}'
+            | '\u{05c1}'..='\u{05c2}'
+            | '\u{05c4}'..='\u{05c5}'
+            | '\u{05c7}'
+            | '\u{0610}'..='\u{061a}'
+            | '\u{064b}'..='\u{065f}'
+            | '\u{0670}'
+            | '\u{0900}'..='\u{0903}'
+            | '\u{093a}'..='\u{094f}'
+            | '\u{0951}'..='\u{0957}'
+            | '\u{0962}'..='\u{0963}'
+            | '\u{0e31}'
+            | '\u{0e34}'..='\u{0e3a}'
+            | '\u{0e47}'..='\u{0e4e}'
+            | '\u{1ab0}'..='\u{1aff}'
+            | '\u{1dc0}'..='\u{1dff}'
+            | '\u{200c}'..='\u{200d}'
+            | '\u{20d0}'..='\u{20ff}'
+            | '\u{3099}'..='\u{309a}'
+            | '\u{fe00}'..='\u{fe0f}'
+            | '\u{fe20}'..='\u{fe2f}'
+            | '\u{1f3fb}'..='\u{1f3ff}'
+            | '\u{e0020}'..='\u{e007f}'
+            | '\u{e0100}'..='\u{e01ef}'
+    )
+}
+
+fn is_regional_indicator(ch: char) -> bool {
+    matches!(ch, '\u{1f1e6}'..='\u{1f1ff}')
+}
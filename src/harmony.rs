@@ -0,0 +1,127 @@
+use crate::color::{hsl_to_rgb, oklch_to_rgb, rgb_to_hsl, rgb_to_oklch, Color};
+
+/// HSL lightness range, in percent, spanned by [`Color::monochromatic`].
+const HSL_LIGHTNESS_RANGE: (f32, f32) = (15.0, 90.0);
+/// Oklch lightness range spanned by [`Color::monochromatic`].
+const OKLCH_LIGHTNESS_RANGE: (f32, f32) = (0.3, 0.95);
+/// Hue offset, in degrees, between analogous colors.
+const ANALOGOUS_DEGREES: f32 = 30.0;
+/// Hue offset, in degrees, of split-complementary colors from the complement.
+const SPLIT_DEGREES: f32 = 30.0;
+
+/// Color space used to rotate hues and step lightness when generating color
+/// harmonies.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum HarmonySpace {
+    /// Rotate the HSL hue, as classic color wheels do.
+    #[default]
+    Hsl,
+    /// Rotate the Oklch hue, which keeps perceived lightness and colorfulness
+    /// steady across the set. Colors outside the sRGB gamut are clipped.
+    Oklch,
+}
+
+impl Color {
+    /// Return this color and its opposite on the hue circle.
+    pub fn complementary(self, space: HarmonySpace) -> [Color; 2] {
+        [self, self.hue_rotated(180.0, space)]
+    }
+
+    /// Return this color and the two colors on either side of its complement,
+    /// 150 degrees away in each direction.
+    pub fn split_complementary(self, space: HarmonySpace) -> [Color; 3] {
+        [
+            self,
+            self.hue_rotated(180.0 - SPLIT_DEGREES, space),
+            self.hue_rotated(180.0 + SPLIT_DEGREES, space),
+        ]
+    }
+
+    /// Return this color and two others spaced evenly around the hue circle,
+    /// 120 degrees apart.
+    pub fn triadic(self, space: HarmonySpace) -> [Color; 3] {
+        [
+            self,
+            self.hue_rotated(120.0, space),
+            self.hue_rotated(240.0, space),
+        ]
+    }
+
+    /// Return this color and three others spaced evenly around the hue
+    /// circle, 90 degrees apart.
+    pub fn tetradic(self, space: HarmonySpace) -> [Color; 4] {
+        [
+            self,
+            self.hue_rotated(90.0, space),
+            self.hue_rotated(180.0, space),
+            self.hue_rotated(270.0, space),
+        ]
+    }
+
+    /// Return this color and its neighbours 30 degrees away on either side.
+    pub fn analogous(self, space: HarmonySpace) -> [Color; 3] {
+        [
+            self,
+            self.hue_rotated(-ANALOGOUS_DEGREES, space),
+            self.hue_rotated(ANALOGOUS_DEGREES, space),
+        ]
+    }
+
+    /// Return `n` shades of this color's hue, from dark to light.
+    ///
+    /// Hue and saturation (or chroma) are kept while lightness steps evenly
+    /// across a readable range. The step nearest this color's lightness is
+    /// this color itself, so the set always contains it.
+    pub fn monochromatic(self, n: usize, space: HarmonySpace) -> Vec<Color> {
+        let ((min, max), lightness) = match space {
+            HarmonySpace::Hsl => (HSL_LIGHTNESS_RANGE, rgb_to_hsl(self.r, self.g, self.b).2),
+            HarmonySpace::Oklch => (OKLCH_LIGHTNESS_RANGE, rgb_to_oklch(self).0),
+        };
+        let step = if n > 1 {
+            (max - min) / (n - 1) as f32
+        } else {
+            0.0
+        };
+        let levels: Vec<f32> = (0..n).map(|index| min + step * index as f32).collect();
+        let nearest = levels
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - lightness).abs().total_cmp(&(*b - lightness).abs()))
+            .map(|(index, _)| index);
+
+        levels
+            .iter()
+            .enumerate()
+            .map(|(index, level)| {
+                if Some(index) == nearest {
+                    self
+                } else {
+                    self.with_lightness(*level, space)
+                }
+            })
+            .collect()
+    }
+
+    fn hue_rotated(self, degrees: f32, space: HarmonySpace) -> Color {
+        match space {
+            HarmonySpace::Hsl => self.rotate_hue(degrees),
+            HarmonySpace::Oklch => {
+                let (lightness, chroma, hue) = rgb_to_oklch(self);
+                oklch_to_rgb(lightness, chroma, hue + degrees)
+            }
+        }
+    }
+
+    fn with_lightness(self, lightness: f32, space: HarmonySpace) -> Color {
+        match space {
+            HarmonySpace::Hsl => {
+                let (hue, saturation, _) = rgb_to_hsl(self.r, self.g, self.b);
+                Color::from(hsl_to_rgb(hue, saturation, lightness))
+            }
+            HarmonySpace::Oklch => {
+                let (_, chroma, hue) = rgb_to_oklch(self);
+                oklch_to_rgb(lightness, chroma, hue)
+            }
+        }
+    }
+}
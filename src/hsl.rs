@@ -0,0 +1,167 @@
+use crate::color::{rgb_to_hsl, Color, Rgba};
+
+/// A color in the HSL (hue, saturation, lightness) model.
+///
+/// Components are normalized on construction: hue wraps into 0-360 degrees,
+/// so -90 and 270 are the same hue, while saturation and lightness are clamped
+/// to 0-100 percent. NaN components are treated as 0, and an infinite hue is
+/// treated as 0 because it has no position on the color wheel.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hsl {
+    hue: f32,
+    saturation: f32,
+    lightness: f32,
+}
+
+impl Hsl {
+    /// Create an HSL color from hue in degrees and saturation and lightness in
+    /// percent.
+    pub fn new(hue: f32, saturation: f32, lightness: f32) -> Self {
+        Self {
+            hue: wrap_hue(hue),
+            saturation: clamp_percent(saturation),
+            lightness: clamp_percent(lightness),
+        }
+    }
+
+    /// Return the hue in degrees, from 0.0 up to but excluding 360.0.
+    pub fn hue(self) -> f32 {
+        self.hue
+    }
+
+    /// Return the saturation in percent, from 0.0 to 100.0.
+    pub fn saturation(self) -> f32 {
+        self.saturation
+    }
+
+    /// Return the lightness in percent, from 0.0 to 100.0.
+    pub fn lightness(self) -> f32 {
+        self.lightness
+    }
+
+    /// Convert to RGB, rounding each component to the nearest integer.
+    pub fn to_color(self) -> Color {
+        let s = self.saturation / 100.0;
+        let l = self.lightness / 100.0;
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let sector = self.hue / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let m = l - chroma / 2.0;
+
+        let (r, g, b) = match sector as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let channel = |value: f32| ((value + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+
+        Color::rgb(channel(r), channel(g), channel(b))
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        let (h, s, l) = rgb_to_hsl(color.r, color.g, color.b);
+        Self::new(h, s, l)
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        hsl.to_color()
+    }
+}
+
+/// An HSL color with an alpha channel.
+///
+/// Alpha is clamped to 0.0-1.0. A NaN alpha is treated as fully opaque so that
+/// an invalid value never hides text.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hsla {
+    hsl: Hsl,
+    alpha: f32,
+}
+
+impl Hsla {
+    /// Create an HSLA color from hue in degrees, saturation and lightness in
+    /// percent, and alpha from 0.0 (transparent) to 1.0 (opaque).
+    pub fn new(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        Self {
+            hsl: Hsl::new(hue, saturation, lightness),
+            alpha: if alpha.is_nan() {
+                1.0
+            } else {
+                alpha.clamp(0.0, 1.0)
+            },
+        }
+    }
+
+    /// Return the color without its alpha channel.
+    pub fn hsl(self) -> Hsl {
+        self.hsl
+    }
+
+    /// Return the alpha, from 0.0 to 1.0.
+    pub fn alpha(self) -> f32 {
+        self.alpha
+    }
+
+    /// Convert to RGBA, rounding each component to the nearest integer.
+    pub fn to_rgba(self) -> Rgba {
+        let Color { r, g, b } = self.hsl.to_color();
+        Rgba::new(r, g, b, (self.alpha * 255.0).round() as u8)
+    }
+}
+
+impl From<Hsl> for Hsla {
+    fn from(hsl: Hsl) -> Self {
+        Self { hsl, alpha: 1.0 }
+    }
+}
+
+impl From<Rgba> for Hsla {
+    fn from(color: Rgba) -> Self {
+        Self {
+            hsl: Hsl::from(color.color()),
+            alpha: f32::from(color.a) / 255.0,
+        }
+    }
+}
+
+impl From<Hsla> for Rgba {
+    fn from(hsla: Hsla) -> Self {
+        hsla.to_rgba()
+    }
+}
+
+impl Color {
+    /// Convert to HSL.
+    pub fn to_hsl(self) -> Hsl {
+        Hsl::from(self)
+    }
+}
+
+fn wrap_hue(hue: f32) -> f32 {
+    if !hue.is_finite() {
+        return 0.0;
+    }
+
+    let wrapped = hue.rem_euclid(360.0);
+    // `rem_euclid` can round up to exactly 360.0 for tiny negative inputs.
+    if wrapped >= 360.0 {
+        0.0
+    } else {
+        wrapped
+    }
+}
+
+fn clamp_percent(value: f32) -> f32 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 100.0)
+    }
+}
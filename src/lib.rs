@@ -45,11 +45,39 @@
 //! - Text styles (bold, dim, italic, underline)
 //! - ANSI 256-color foreground and background support
 //! - RGB, HSL, and Hex color support
+//! - Validated `Hsl` and `Hsla` types with hue wrapping, clamping, and rounding
+//! - Nearest CSS name, ANSI 256 index, and named color lookups with distances
 //! - Terminal color capability detection
-//! - RGB, HSL, and Hex degradation when truecolor is unavailable
+//! - Light/dark background detection
+//! - Adaptive colors that resolve by terminal background
+//! - Hand-picked ANSI 256 and named fallbacks for RGB colors
+//! - Built-in Solarized, Nord, Dracula, Gruvbox, Catppuccin, Tokyo Night,
+//!   Tailwind, and Material palettes
+//! - Categorical palette generation and stable hash-based colors
+//! - Complementary, triadic, analogous, and other color harmonies in HSL or
+//!   Oklch
+//! - Reusable `Style` values and threshold or sign scales for numbers
+//! - Semantic role themes (default, high-contrast, monochrome) activated per
+//!   thread or process
+//! - `LS_COLORS` and `dircolors` parsing for coloring file paths like `ls`
+//! - Optional `serde` feature for loading themes from TOML or JSON files and an
+//!   opt-in per-user config file
+//! - WCAG 2.x and APCA contrast checks with automatic readable foregrounds
+//! - Color manipulation (lighten, darken, saturate, hue rotation, Oklab mixing)
+//! - RGBA colors composited against the span or terminal background
+//! - Color vision deficiency simulation and daltonization at render time
+//! - Per-grapheme foreground and background gradients
+//! - Continuous colormaps (viridis, magma, plasma, inferno, cividis, turbo,
+//!   red-blue, and custom stops) for numeric values
+//! - Optional ordered or error-diffusion dithering for ANSI 256, ANSI 16, and
+//!   ANSI 8 output
+//! - Opt-in active terminal queries (OSC 4/10/11, DA1, XTVERSION)
+//! - RGB, HSL, and Hex degradation when truecolor is unavailable, keeping
+//!   downgraded foregrounds distinct from their backgrounds
+//! - Semicolon or ITU T.416 colon syntax for ANSI 256 and RGB codes
 //! - Composed style chaining
 //! - Works with format! macro
-//! - Explicit runtime color and color-depth modes
+//! - Explicit runtime color and color-depth modes, including grayscale output
 //!
 //! # Input Handling
 //!
@@ -63,6 +91,9 @@
 //!   shorthand or 6-digit full form
 //! - Invalid hex codes (wrong length or invalid characters) return plain
 //!   unstyled text
+//! - HSL hue wraps around the color wheel, saturation and lightness are
+//!   clamped to 0-100, NaN components are treated as 0, and RGB components are
+//!   rounded to the nearest value
 //! - All color methods are guaranteed to return a valid string, never panicking
 //!
 //! ```rust
@@ -97,7 +128,8 @@
 //! to use; they do not by themselves bypass [`ColorMode::Auto`] non-terminal
 //! suppression. For [`RenderTarget::Capabilities`], the supplied
 //! [`TerminalCapabilities`] are used as-is except for the hard disables:
-//! `NO_COLOR`, [`ColorMode::Never`], and [`ColorDepthMode::NoColor`].
+//! `NO_COLOR`, [`ColorMode::Never`], and [`ColorDepthMode::NoColor`]. An
+//! explicit [`ColorDepthMode::Grayscale`] also turns on grayscale output.
 //!
 //! ```rust
 //! use colored_text::{ColorDepthMode, ColorMode, Colorize, ColorizeConfig};
@@ -112,6 +144,17 @@
 //! println!("{}", "Never colored".red());
 //! ```
 //!
+//! [`AdaptiveColor`] values pick a light or dark background variant at render
+//! time from [`TerminalCapabilities::background`], falling back to
+//! [`ColorizeConfig::default_background`] when the background is unknown.
+//!
+//! ```rust
+//! use colored_text::{AdaptiveColor, Colorize};
+//!
+//! let accent = AdaptiveColor::from_hex("#005f87", "#5fafff").unwrap();
+//! println!("{}", "Adaptive accent".adaptive(accent));
+//! ```
+//!
 //! When you need `Auto` mode to follow a destination other than stdout, use
 //! [`StyledText::render`] with a [`RenderTarget`].
 //!
@@ -128,24 +171,86 @@
 //! foreground and bright background colors. Most users rely on the blanket
 //! `impl<T: std::fmt::Display> Colorize for T` and are unaffected. Downstream
 //! crates with manual `impl Colorize for ...` blocks must implement the new
-//! methods.
+//! methods. Later additions, such as [`Colorize::adaptive`],
+//! [`Colorize::gradient`], and [`Colorize::style`], have default bodies built
+//! on [`Colorize::clear`], so manual implementations keep compiling.
+//!
+//! [`TerminalCapabilities`] has gained `background`, `background_color`,
+//! `grayscale`, and `extended_color_syntax` fields and is now
+//! `#[non_exhaustive]`. Struct literals outside this crate no longer compile;
+//! create values with [`TerminalCapabilities::new`] and assign the fields you
+//! need.
 //!
 //! # Note
 //!
 //! Colors and styles are implemented using ANSI escape codes, which are
 //! supported by most modern terminals. Capability detection is heuristic and
-//! environment-based; the crate does not use terminfo, termcap, active terminal
-//! queries, WinAPI console enablement, CLI parsing, or runtime dependencies. If
-//! color output is disabled by policy, the text is displayed without styling.
+//! environment-based; the crate does not use terminfo, termcap, WinAPI console
+//! enablement, CLI parsing, or runtime dependencies. If color output is disabled
+//! by policy, the text is displayed without styling.
+//!
+//! Active terminal queries are opt-in via [`TerminalQuery`], which writes OSC
+//! and device attribute requests to a caller-supplied terminal and folds the
+//! replies into [`TerminalCapabilities`].
+//!
+//! ```rust
+//! use std::io::Cursor;
+//!
+//! use colored_text::{ColorLevel, TerminalCapabilities, TerminalQuery};
+//!
+//! // A fake terminal that answers the query.
+//! let mut reader = Cursor::new(b"\x1bP>|kitty(0.35.2)\x1b\\\x1b[?62;22c".to_vec());
+//! let mut writer = Vec::new();
+//!
+//! let report = TerminalQuery::new().run(&mut reader, &mut writer).unwrap();
+//! let caps = report.apply_to(TerminalCapabilities::new(true, ColorLevel::Ansi16));
+//! assert_eq!(caps.color_level, ColorLevel::TrueColor);
+//! ```
 
+mod adjust;
+mod categorical;
 mod color;
+mod colormap;
 mod config;
+mod contrast;
+mod dither;
+mod gradient;
+mod grapheme;
+mod harmony;
+mod hsl;
+mod ls_colors;
+mod nearest;
+pub mod palettes;
+mod query;
+mod scale;
 mod style;
 mod terminal;
+mod theme;
+#[cfg(feature = "serde")]
+mod theme_file;
+#[cfg(feature = "serde")]
+mod user_config;
+mod vision;
 
 #[cfg(test)]
 mod tests;
 
-pub use config::{ColorDepthMode, ColorMode, ColorizeConfig, RenderTarget};
-pub use style::{Colorize, StyledText};
-pub use terminal::{ColorLevel, TerminalCapabilities};
+pub use color::{AdaptiveColor, Color, FallbackColor, NamedColor, Rgba};
+pub use colormap::Colormap;
+pub use config::{ColorDepthMode, ColorMode, ColorizeConfig, ExtendedColorSyntax, RenderTarget};
+pub use contrast::ContrastTarget;
+pub use dither::Dither;
+pub use gradient::{Gradient, InterpolationSpace};
+pub use harmony::HarmonySpace;
+pub use hsl::{Hsl, Hsla};
+pub use ls_colors::LsColors;
+pub use query::{TerminalQuery, TerminalReport};
+pub use scale::Scale;
+pub use style::{Colorize, Style, StyledText};
+pub use terminal::{Background, ColorLevel, TerminalCapabilities};
+pub use theme::Theme;
+#[cfg(feature = "serde")]
+pub use theme_file::ConfigError;
+#[cfg(feature = "serde")]
+pub use user_config::UserConfig;
+pub use vision::{ColorTransform, ColorVisionDeficiency};
@@ -0,0 +1,358 @@
+use std::collections::BTreeMap;
+use std::fs::{self, Metadata};
+use std::path::Path;
+
+use crate::color::{Color, NAMED_COLORS};
+use crate::style::Style;
+use crate::terminal::{EnvProvider, ProcessEnv};
+
+/// Styles `ls` uses when `LS_COLORS` is unset, from GNU coreutils.
+const GNU_DEFAULTS: &str = "di=01;34:ln=01;36:pi=33:so=01;35:do=01;35:bd=01;33:cd=01;33:\
+                            ex=01;32:su=37;41:sg=30;43:st=37;44:ow=34;42:tw=30;42";
+
+/// File path styles in the `LS_COLORS` format used by `ls` and `dircolors`.
+///
+/// Entries are keyed by two-letter file type codes, such as `di` for
+/// directories and `ex` for executables, or by `*` patterns that match the end
+/// of a file name, such as `*.rs`. Values are SGR code sequences. Codes without
+/// a typed equivalent, such as `5` for blink, are kept with [`Style::raw`].
+///
+/// ```rust
+/// use colored_text::LsColors;
+///
+/// let ls_colors = LsColors::from_env();
+/// for entry in std::fs::read_dir(".").unwrap().flatten() {
+///     let path = entry.path();
+///     if let Ok(metadata) = entry.metadata() {
+///         let style = ls_colors.style_path(&path, &metadata);
+///         println!("{}", style.paint(path.display()));
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LsColors {
+    types: BTreeMap<String, Style>,
+    suffixes: Vec<(String, Style)>,
+    link_target: bool,
+}
+
+impl LsColors {
+    /// Create an empty set of styles, which leaves every path plain.
+    pub fn new() -> Self {
+        Self {
+            types: BTreeMap::new(),
+            suffixes: Vec::new(),
+            link_target: false,
+        }
+    }
+
+    /// The styles GNU `ls` uses when `LS_COLORS` is unset: colored file types
+    /// and no extension rules.
+    pub fn gnu_defaults() -> Self {
+        Self::parse(GNU_DEFAULTS)
+    }
+
+    /// Read styles from the `LS_COLORS` environment variable, or return
+    /// [`LsColors::gnu_defaults`] when it is unset or empty.
+    pub fn from_env() -> Self {
+        from_env(&ProcessEnv)
+    }
+
+    /// Parse an `LS_COLORS` value such as `di=01;34:ln=01;36:*.rs=33`.
+    ///
+    /// Later entries replace earlier ones with the same key. Malformed
+    /// entries are skipped, and `ln=target` styles links like the file they
+    /// point to.
+    pub fn parse(ls_colors: &str) -> Self {
+        let mut colors = Self::new();
+        for entry in ls_colors.split(':') {
+            if let Some((key, value)) = entry.split_once('=') {
+                colors.insert(key, value);
+            }
+        }
+        colors
+    }
+
+    /// Parse a `dircolors` database, as printed by `dircolors --print-database`.
+    ///
+    /// Each line holds a keyword, such as `DIR` or `EXEC`, or an extension
+    /// such as `.rs`, followed by an SGR code sequence. `#` starts a comment.
+    /// `TERM` and `COLORTERM` lines are ignored, so every entry applies
+    /// whatever the terminal.
+    pub fn from_dircolors(database: &str) -> Self {
+        let mut colors = Self::new();
+        for line in database.lines() {
+            let line = strip_comment(line);
+            let mut words = line.split_whitespace();
+            let (Some(keyword), Some(value)) = (words.next(), words.next()) else {
+                continue;
+            };
+            if keyword.starts_with('.') {
+                colors.insert(&format!("*{keyword}"), value);
+            } else if keyword.starts_with('*') {
+                colors.insert(keyword, value);
+            } else if let Some(key) = dircolors_key(keyword) {
+                colors.insert(key, value);
+            }
+        }
+        colors
+    }
+
+    /// Return the style for a file type code such as `"di"`, or for a pattern
+    /// such as `"*.rs"`.
+    pub fn style(&self, key: &str) -> Option<&Style> {
+        match key.strip_prefix('*') {
+            Some(suffix) => self
+                .suffixes
+                .iter()
+                .rev()
+                .find(|(pattern, _)| pattern == suffix)
+                .map(|(_, style)| style),
+            None => self.types.get(key),
+        }
+    }
+
+    /// Return the style `ls` would draw `path` with.
+    ///
+    /// `metadata` should come from [`fs::symlink_metadata`] so links are
+    /// recognized; broken links are styled with `or` when it is set. Regular
+    /// files are matched by permissions first (`su`, `sg`, `ex`, `mh`), then
+    /// by name pattern, then fall back to `fi`. Patterns prefer an exact-case
+    /// match and otherwise ignore ASCII case. Paths without a matching entry
+    /// get a plain style.
+    pub fn style_path(&self, path: &Path, metadata: &Metadata) -> Style {
+        if metadata.file_type().is_symlink() {
+            return match fs::metadata(path) {
+                Ok(target) if self.link_target => self.style_path(path, &target),
+                Err(_) if self.has("or") => self.type_style("or"),
+                _ => self.type_style("ln"),
+            };
+        }
+
+        if let Some(key) = self.file_type_key(metadata) {
+            return self.type_style(key);
+        }
+
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default();
+        self.suffix_style(&name)
+            .cloned()
+            .unwrap_or_else(|| self.type_style("fi"))
+    }
+
+    fn insert(&mut self, key: &str, value: &str) {
+        if key == "ln" && value == "target" {
+            self.link_target = true;
+            self.types.remove(key);
+            return;
+        }
+        let Some(style) = parse_sgr(value) else {
+            return;
+        };
+        match key.strip_prefix('*') {
+            Some(suffix) if !suffix.is_empty() => {
+                self.suffixes.retain(|(pattern, _)| pattern != suffix);
+                self.suffixes.push((suffix.to_string(), style));
+            }
+            Some(_) => {}
+            None if key.len() == 2 => {
+                if key == "ln" {
+                    self.link_target = false;
+                }
+                self.types.insert(key.to_string(), style);
+            }
+            None => {}
+        }
+    }
+
+    /// Return the file type code for anything but a plain regular file.
+    fn file_type_key(&self, metadata: &Metadata) -> Option<&'static str> {
+        let file_type = metadata.file_type();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::{FileTypeExt, MetadataExt, PermissionsExt};
+
+            let mode = metadata.permissions().mode();
+            let (setuid, setgid, sticky) =
+                (mode & 0o4000 != 0, mode & 0o2000 != 0, mode & 0o1000 != 0);
+            let other_writable = mode & 0o002 != 0;
+            let key = if file_type.is_dir() {
+                if sticky && other_writable && self.has("tw") {
+                    "tw"
+                } else if other_writable && self.has("ow") {
+                    "ow"
+                } else if sticky && self.has("st") {
+                    "st"
+                } else {
+                    "di"
+                }
+            } else if file_type.is_fifo() {
+                "pi"
+            } else if file_type.is_socket() {
+                "so"
+            } else if file_type.is_block_device() {
+                "bd"
+            } else if file_type.is_char_device() {
+                "cd"
+            } else if setuid && self.has("su") {
+                "su"
+            } else if setgid && self.has("sg") {
+                "sg"
+            } else if mode & 0o111 != 0 && self.has("ex") {
+                "ex"
+            } else if metadata.nlink() > 1 && self.has("mh") {
+                "mh"
+            } else {
+                return None;
+            };
+            Some(key)
+        }
+
+        #[cfg(not(unix))]
+        {
+            file_type.is_dir().then_some("di")
+        }
+    }
+
+    fn has(&self, key: &str) -> bool {
+        self.types.contains_key(key)
+    }
+
+    fn suffix_style(&self, name: &str) -> Option<&Style> {
+        let matching = |ignore_case: bool| {
+            self.suffixes
+                .iter()
+                .rev()
+                .find(|(pattern, _)| {
+                    name.len() >= pattern.len()
+                        && name.is_char_boundary(name.len() - pattern.len())
+                        && if ignore_case {
+                            name[name.len() - pattern.len()..].eq_ignore_ascii_case(pattern)
+                        } else {
+                            name.ends_with(pattern.as_str())
+                        }
+                })
+                .map(|(_, style)| style)
+        };
+        matching(false).or_else(|| matching(true))
+    }
+
+    fn type_style(&self, key: &str) -> Style {
+        self.types.get(key).cloned().unwrap_or_default()
+    }
+}
+
+impl Default for LsColors {
+    fn default() -> Self {
+        Self::gnu_defaults()
+    }
+}
+
+pub(crate) fn from_env(env: &impl EnvProvider) -> LsColors {
+    match env.get("LS_COLORS") {
+        Some(value) if !value.is_empty() => LsColors::parse(&value),
+        _ => LsColors::gnu_defaults(),
+    }
+}
+
+/// Convert an SGR code sequence such as `01;38;5;208` into a style, or return
+/// `None` when it holds something other than numeric codes.
+fn parse_sgr(value: &str) -> Option<Style> {
+    let mut style = Style::new();
+    let mut raw = Vec::new();
+    let mut codes = value.split(';').map(str::trim);
+    while let Some(code) = codes.next() {
+        let number: u8 = if code.is_empty() {
+            0
+        } else {
+            code.parse().ok()?
+        };
+        style = match number {
+            0 => {
+                raw.clear();
+                Style::new()
+            }
+            1 => style.bold(),
+            2 => style.dim(),
+            3 => style.italic(),
+            4 => style.underline(),
+            7 => style.inverse(),
+            9 => style.strikethrough(),
+            30..=37 => style.fg_named(NAMED_COLORS[usize::from(number - 30)]),
+            40..=47 => style.bg_named(NAMED_COLORS[usize::from(number - 40)]),
+            90..=97 => style.fg_named(NAMED_COLORS[usize::from(number - 90) + 8]),
+            100..=107 => style.bg_named(NAMED_COLORS[usize::from(number - 100) + 8]),
+            38 => match extended_color(&mut codes)? {
+                ExtendedColor::Ansi256(index) => style.fg_ansi256(index),
+                ExtendedColor::Rgb(color) => style.fg(color),
+            },
+            48 => match extended_color(&mut codes)? {
+                ExtendedColor::Ansi256(index) => style.bg_ansi256(index),
+                ExtendedColor::Rgb(color) => style.bg(color),
+            },
+            _ => {
+                raw.push(code);
+                style
+            }
+        };
+    }
+    Some(style.raw(&raw.join(";")))
+}
+
+enum ExtendedColor {
+    Ansi256(u8),
+    Rgb(Color),
+}
+
+/// Read the `5;N` or `2;R;G;B` arguments of a `38` or `48` code.
+fn extended_color<'a>(codes: &mut impl Iterator<Item = &'a str>) -> Option<ExtendedColor> {
+    let mut next = || codes.next()?.parse::<u8>().ok();
+    match next()? {
+        5 => Some(ExtendedColor::Ansi256(next()?)),
+        2 => Some(ExtendedColor::Rgb(Color::rgb(next()?, next()?, next()?))),
+        _ => None,
+    }
+}
+
+/// Map a `dircolors` keyword to its `LS_COLORS` file type code.
+fn dircolors_key(keyword: &str) -> Option<&'static str> {
+    let key = match keyword.to_ascii_uppercase().as_str() {
+        "NORMAL" | "NORM" => "no",
+        "FILE" => "fi",
+        "RESET" => "rs",
+        "DIR" => "di",
+        "LINK" | "LNK" | "SYMLINK" => "ln",
+        "MULTIHARDLINK" => "mh",
+        "FIFO" | "PIPE" => "pi",
+        "SOCK" => "so",
+        "DOOR" => "do",
+        "BLK" | "BLOCK" => "bd",
+        "CHR" | "CHAR" => "cd",
+        "ORPHAN" => "or",
+        "MISSING" => "mi",
+        "SETUID" => "su",
+        "SETGID" => "sg",
+        "CAPABILITY" => "ca",
+        "STICKY_OTHER_WRITABLE" | "OWT" => "tw",
+        "OTHER_WRITABLE" | "OWR" => "ow",
+        "STICKY" => "st",
+        "EXEC" => "ex",
+        _ => return None,
+    };
+    Some(key)
+}
+
+/// Drop a `#` comment, which starts a line or follows whitespace.
+fn strip_comment(line: &str) -> &str {
+    let mut previous = ' ';
+    for (index, c) in line.char_indices() {
+        if c == '#' && previous.is_whitespace() {
+            return &line[..index];
+        }
+        previous = c;
+    }
+    line
+}
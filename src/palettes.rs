@@ -0,0 +1,144 @@
+//! Popular color palettes as typed [`Color`] constants.
+//!
+//! Each palette module exposes its colors as constants, such as
+//! [`nord::FROST_1`] or [`tailwind::BLUE_500`], along with one or more
+//! [`Palette`] values that carry the palette's name, the background it is
+//! designed for, and its entries in order.
+//!
+//! ```rust
+//! use colored_text::palettes::{self, nord, tailwind};
+//! use colored_text::{Background, Colorize};
+//!
+//! let (r, g, b) = nord::FROST_2.to_rgb();
+//! println!("{}", "Frost".rgb(r, g, b));
+//! println!("{}", "Scale".gradient(&[tailwind::BLUE_300, tailwind::BLUE_700]));
+//!
+//! assert_eq!(nord::PALETTE.background(), Some(Background::Dark));
+//! assert_eq!(nord::PALETTE.get("frost_2"), Some(nord::FROST_2));
+//! assert_eq!(palettes::by_name("tokyo-night"), Some(palettes::tokyo_night::NIGHT));
+//! ```
+
+use std::iter::Copied;
+use std::slice::Iter;
+
+use crate::{Background, Color};
+
+pub mod catppuccin;
+pub mod dracula;
+pub mod gruvbox;
+pub mod material;
+pub mod nord;
+pub mod solarized;
+pub mod tailwind;
+pub mod tokyo_night;
+
+/// Every built-in palette, in the order returned by [`all`].
+const ALL: [Palette; 14] = [
+    solarized::DARK,
+    solarized::LIGHT,
+    nord::PALETTE,
+    dracula::PALETTE,
+    gruvbox::DARK,
+    gruvbox::LIGHT,
+    catppuccin::latte::PALETTE,
+    catppuccin::frappe::PALETTE,
+    catppuccin::macchiato::PALETTE,
+    catppuccin::mocha::PALETTE,
+    tokyo_night::NIGHT,
+    tokyo_night::STORM,
+    tailwind::PALETTE,
+    material::PALETTE,
+];
+
+/// A named, ordered set of colors.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Palette {
+    name: &'static str,
+    background: Option<Background>,
+    entries: &'static [(&'static str, Color)],
+}
+
+impl Palette {
+    /// Create a palette from its name, intended background, and entries.
+    pub const fn new(
+        name: &'static str,
+        background: Option<Background>,
+        entries: &'static [(&'static str, Color)],
+    ) -> Self {
+        Self {
+            name,
+            background,
+            entries,
+        }
+    }
+
+    /// Return the palette's display name, such as `"Catppuccin Mocha"`.
+    pub const fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// Return the background the palette is designed for, or `None` for
+    /// scales meant for either.
+    pub const fn background(self) -> Option<Background> {
+        self.background
+    }
+
+    /// Return the number of entries.
+    pub const fn len(self) -> usize {
+        self.entries.len()
+    }
+
+    /// Return whether the palette has no entries.
+    pub const fn is_empty(self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the color of the entry with the given name, such as
+    /// `"blue_500"`. Names are the lowercase constant names.
+    pub fn get(self, name: &str) -> Option<Color> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry.eq_ignore_ascii_case(name))
+            .map(|(_, color)| *color)
+    }
+
+    /// Iterate over `(name, color)` entries in palette order.
+    pub fn iter(self) -> Copied<Iter<'static, (&'static str, Color)>> {
+        self.entries.iter().copied()
+    }
+
+    /// Iterate over the colors in palette order.
+    pub fn colors(self) -> impl Iterator<Item = Color> {
+        self.iter().map(|(_, color)| color)
+    }
+}
+
+impl IntoIterator for Palette {
+    type Item = (&'static str, Color);
+    type IntoIter = Copied<Iter<'static, (&'static str, Color)>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterate over every built-in palette.
+pub fn all() -> impl Iterator<Item = Palette> {
+    ALL.into_iter()
+}
+
+/// Find a built-in palette by name.
+///
+/// Matching ignores ASCII case and treats spaces, hyphens, and underscores
+/// alike, so `"tokyo-night"` finds "Tokyo Night".
+pub fn by_name(name: &str) -> Option<Palette> {
+    all().find(|palette| same_name(palette.name, name))
+}
+
+pub(crate) fn same_name(a: &str, b: &str) -> bool {
+    let normalize = |c: char| match c {
+        ' ' | '-' | '_' => ' ',
+        c => c.to_ascii_lowercase(),
+    };
+    a.chars().map(normalize).eq(b.chars().map(normalize))
+}
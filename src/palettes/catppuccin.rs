@@ -0,0 +1,373 @@
+//! The [Catppuccin](https://catppuccin.com) palette, one module per flavor.
+
+/// The Latte flavor (light).
+pub mod latte {
+    use crate::palettes::Palette;
+    use crate::{Background, Color};
+
+    /// Catppuccin Latte rosewater, `#dc8a78`.
+    pub const ROSEWATER: Color = Color::rgb(0xdc, 0x8a, 0x78);
+    /// Catppuccin Latte flamingo, `#dd7878`.
+    pub const FLAMINGO: Color = Color::rgb(0xdd, 0x78, 0x78);
+    /// Catppuccin Latte pink, `#ea76cb`.
+    pub const PINK: Color = Color::rgb(0xea, 0x76, 0xcb);
+    /// Catppuccin Latte mauve, `#8839ef`.
+    pub const MAUVE: Color = Color::rgb(0x88, 0x39, 0xef);
+    /// Catppuccin Latte red, `#d20f39`.
+    pub const RED: Color = Color::rgb(0xd2, 0x0f, 0x39);
+    /// Catppuccin Latte maroon, `#e64553`.
+    pub const MAROON: Color = Color::rgb(0xe6, 0x45, 0x53);
+    /// Catppuccin Latte peach, `#fe640b`.
+    pub const PEACH: Color = Color::rgb(0xfe, 0x64, 0x0b);
+    /// Catppuccin Latte yellow, `#df8e1d`.
+    pub const YELLOW: Color = Color::rgb(0xdf, 0x8e, 0x1d);
+    /// Catppuccin Latte green, `#40a02b`.
+    pub const GREEN: Color = Color::rgb(0x40, 0xa0, 0x2b);
+    /// Catppuccin Latte teal, `#179299`.
+    pub const TEAL: Color = Color::rgb(0x17, 0x92, 0x99);
+    /// Catppuccin Latte sky, `#04a5e5`.
+    pub const SKY: Color = Color::rgb(0x04, 0xa5, 0xe5);
+    /// Catppuccin Latte sapphire, `#209fb5`.
+    pub const SAPPHIRE: Color = Color::rgb(0x20, 0x9f, 0xb5);
+    /// Catppuccin Latte blue, `#1e66f5`.
+    pub const BLUE: Color = Color::rgb(0x1e, 0x66, 0xf5);
+    /// Catppuccin Latte lavender, `#7287fd`.
+    pub const LAVENDER: Color = Color::rgb(0x72, 0x87, 0xfd);
+    /// Catppuccin Latte text, `#4c4f69`.
+    pub const TEXT: Color = Color::rgb(0x4c, 0x4f, 0x69);
+    /// Catppuccin Latte subtext1, `#5c5f77`.
+    pub const SUBTEXT1: Color = Color::rgb(0x5c, 0x5f, 0x77);
+    /// Catppuccin Latte subtext0, `#6c6f85`.
+    pub const SUBTEXT0: Color = Color::rgb(0x6c, 0x6f, 0x85);
+    /// Catppuccin Latte overlay2, `#7c7f93`.
+    pub const OVERLAY2: Color = Color::rgb(0x7c, 0x7f, 0x93);
+    /// Catppuccin Latte overlay1, `#8c8fa1`.
+    pub const OVERLAY1: Color = Color::rgb(0x8c, 0x8f, 0xa1);
+    /// Catppuccin Latte overlay0, `#9ca0b0`.
+    pub const OVERLAY0: Color = Color::rgb(0x9c, 0xa0, 0xb0);
+    /// Catppuccin Latte surface2, `#acb0be`.
+    pub const SURFACE2: Color = Color::rgb(0xac, 0xb0, 0xbe);
+    /// Catppuccin Latte surface1, `#bcc0cc`.
+    pub const SURFACE1: Color = Color::rgb(0xbc, 0xc0, 0xcc);
+    /// Catppuccin Latte surface0, `#ccd0da`.
+    pub const SURFACE0: Color = Color::rgb(0xcc, 0xd0, 0xda);
+    /// Catppuccin Latte base, `#eff1f5`.
+    pub const BASE: Color = Color::rgb(0xef, 0xf1, 0xf5);
+    /// Catppuccin Latte mantle, `#e6e9ef`.
+    pub const MANTLE: Color = Color::rgb(0xe6, 0xe9, 0xef);
+    /// Catppuccin Latte crust, `#dce0e8`.
+    pub const CRUST: Color = Color::rgb(0xdc, 0xe0, 0xe8);
+
+    /// The full Catppuccin Latte palette.
+    pub const PALETTE: Palette = Palette::new(
+        "Catppuccin Latte",
+        Some(Background::Light),
+        &[
+            ("rosewater", ROSEWATER),
+            ("flamingo", FLAMINGO),
+            ("pink", PINK),
+            ("mauve", MAUVE),
+            ("red", RED),
+            ("maroon", MAROON),
+            ("peach", PEACH),
+            ("yellow", YELLOW),
+            ("green", GREEN),
+            ("teal", TEAL),
+            ("sky", SKY),
+            ("sapphire", SAPPHIRE),
+            ("blue", BLUE),
+            ("lavender", LAVENDER),
+            ("text", TEXT),
+            ("subtext1", SUBTEXT1),
+            ("subtext0", SUBTEXT0),
+            ("overlay2", OVERLAY2),
+            ("overlay1", OVERLAY1),
+            ("overlay0", OVERLAY0),
+            ("surface2", SURFACE2),
+            ("surface1", SURFACE1),
+            ("surface0", SURFACE0),
+            ("base", BASE),
+            ("mantle", MANTLE),
+            ("crust", CRUST),
+        ],
+    );
+}
+
+/// The Frappe flavor.
+pub mod frappe {
+    use crate::palettes::Palette;
+    use crate::{Background, Color};
+
+    /// Catppuccin Frappe rosewater, `#f2d5cf`.
+    pub const ROSEWATER: Color = Color::rgb(0xf2, 0xd5, 0xcf);
+    /// Catppuccin Frappe flamingo, `#eebebe`.
+    pub const FLAMINGO: Color = Color::rgb(0xee, 0xbe, 0xbe);
+    /// Catppuccin Frappe pink, `#f4b8e4`.
+    pub const PINK: Color = Color::rgb(0xf4, 0xb8, 0xe4);
+    /// Catppuccin Frappe mauve, `#ca9ee6`.
+    pub const MAUVE: Color = Color::rgb(0xca, 0x9e, 0xe6);
+    /// Catppuccin Frappe red, `#e78284`.
+    pub const RED: Color = Color::rgb(0xe7, 0x82, 0x84);
+    /// Catppuccin Frappe maroon, `#ea999c`.
+    pub const MAROON: Color = Color::rgb(0xea, 0x99, 0x9c);
+    /// Catppuccin Frappe peach, `#ef9f76`.
+    pub const PEACH: Color = Color::rgb(0xef, 0x9f, 0x76);
+    /// Catppuccin Frappe yellow, `#e5c890`.
+    pub const YELLOW: Color = Color::rgb(0xe5, 0xc8, 0x90);
+    /// Catppuccin Frappe green, `#a6d189`.
+    pub const GREEN: Color = Color::rgb(0xa6, 0xd1, 0x89);
+    /// Catppuccin Frappe teal, `#81c8be`.
+    pub const TEAL: Color = Color::rgb(0x81, 0xc8, 0xbe);
+    /// Catppuccin Frappe sky, `#99d1db`.
+    pub const SKY: Color = Color::rgb(0x99, 0xd1, 0xdb);
+    /// Catppuccin Frappe sapphire, `#85c1dc`.
+    pub const SAPPHIRE: Color = Color::rgb(0x85, 0xc1, 0xdc);
+    /// Catppuccin Frappe blue, `#8caaee`.
+    pub const BLUE: Color = Color::rgb(0x8c, 0xaa, 0xee);
+    /// Catppuccin Frappe lavender, `#babbf1`.
+    pub const LAVENDER: Color = Color::rgb(0xba, 0xbb, 0xf1);
+    /// Catppuccin Frappe text, `#c6d0f5`.
+    pub const TEXT: Color = Color::rgb(0xc6, 0xd0, 0xf5);
+    /// Catppuccin Frappe subtext1, `#b5bfe2`.
+    pub const SUBTEXT1: Color = Color::rgb(0xb5, 0xbf, 0xe2);
+    /// Catppuccin Frappe subtext0, `#a5adce`.
+    pub const SUBTEXT0: Color = Color::rgb(0xa5, 0xad, 0xce);
+    /// Catppuccin Frappe overlay2, `#949cbb`.
+    pub const OVERLAY2: Color = Color::rgb(0x94, 0x9c, 0xbb);
+    /// Catppuccin Frappe overlay1, `#838ba7`.
+    pub const OVERLAY1: Color = Color::rgb(0x83, 0x8b, 0xa7);
+    /// Catppuccin Frappe overlay0, `#737994`.
+    pub const OVERLAY0: Color = Color::rgb(0x73, 0x79, 0x94);
+    /// Catppuccin Frappe surface2, `#626880`.
+    pub const SURFACE2: Color = Color::rgb(0x62, 0x68, 0x80);
+    /// Catppuccin Frappe surface1, `#51576d`.
+    pub const SURFACE1: Color = Color::rgb(0x51, 0x57, 0x6d);
+    /// Catppuccin Frappe surface0, `#414559`.
+    pub const SURFACE0: Color = Color::rgb(0x41, 0x45, 0x59);
+    /// Catppuccin Frappe base, `#303446`.
+    pub const BASE: Color = Color::rgb(0x30, 0x34, 0x46);
+    /// Catppuccin Frappe mantle, `#292c3c`.
+    pub const MANTLE: Color = Color::rgb(0x29, 0x2c, 0x3c);
+    /// Catppuccin Frappe crust, `#232634`.
+    pub const CRUST: Color = Color::rgb(0x23, 0x26, 0x34);
+
+    /// The full Catppuccin Frappe palette.
+    pub const PALETTE: Palette = Palette::new(
+        "Catppuccin Frappe",
+        Some(Background::Dark),
+        &[
+            ("rosewater", ROSEWATER),
+            ("flamingo", FLAMINGO),
+            ("pink", PINK),
+            ("mauve", MAUVE),
+            ("red", RED),
+            ("maroon", MAROON),
+            ("peach", PEACH),
+            ("yellow", YELLOW),
+            ("green", GREEN),
+            ("teal", TEAL),
+            ("sky", SKY),
+            ("sapphire", SAPPHIRE),
+            ("blue", BLUE),
+            ("lavender", LAVENDER),
+            ("text", TEXT),
+            ("subtext1", SUBTEXT1),
+            ("subtext0", SUBTEXT0),
+            ("overlay2", OVERLAY2),
+            ("overlay1", OVERLAY1),
+            ("overlay0", OVERLAY0),
+            ("surface2", SURFACE2),
+            ("surface1", SURFACE1),
+            ("surface0", SURFACE0),
+            ("base", BASE),
+            ("mantle", MANTLE),
+            ("crust", CRUST),
+        ],
+    );
+}
+
+/// The Macchiato flavor.
+pub mod macchiato {
+    use crate::palettes::Palette;
+    use crate::{Background, Color};
+
+    /// Catppuccin Macchiato rosewater, `#f4dbd6`.
+    pub const ROSEWATER: Color = Color::rgb(0xf4, 0xdb, 0xd6);
+    /// Catppuccin Macchiato flamingo, `#f0c6c6`.
+    pub const FLAMINGO: Color = Color::rgb(0xf0, 0xc6, 0xc6);
+    /// Catppuccin Macchiato pink, `#f5bde6`.
+    pub const PINK: Color = Color::rgb(0xf5, 0xbd, 0xe6);
+    /// Catppuccin Macchiato mauve, `#c6a0f6`.
+    pub const MAUVE: Color = Color::rgb(0xc6, 0xa0, 0xf6);
+    /// Catppuccin Macchiato red, `#ed8796`.
+    pub const RED: Color = Color::rgb(0xed, 0x87, 0x96);
+    /// Catppuccin Macchiato maroon, `#ee99a0`.
+    pub const MAROON: Color = Color::rgb(0xee, 0x99, 0xa0);
+    /// Catppuccin Macchiato peach, `#f5a97f`.
+    pub const PEACH: Color = Color::rgb(0xf5, 0xa9, 0x7f);
+    /// Catppuccin Macchiato yellow, `#eed49f`.
+    pub const YELLOW: Color = Color::rgb(0xee, 0xd4, 0x9f);
+    /// Catppuccin Macchiato green, `#a6da95`.
+    pub const GREEN: Color = Color::rgb(0xa6, 0xda, 0x95);
+    /// Catppuccin Macchiato teal, `#8bd5ca`.
+    pub const TEAL: Color = Color::rgb(0x8b, 0xd5, 0xca);
+    /// Catppuccin Macchiato sky, `#91d7e3`.
+    pub const SKY: Color = Color::rgb(0x91, 0xd7, 0xe3);
+    /// Catppuccin Macchiato sapphire, `#7dc4e4`.
+    pub const SAPPHIRE: Color = Color::rgb(0x7d, 0xc4, 0xe4);
+    /// Catppuccin Macchiato blue, `#8aadf4`.
+    pub const BLUE: Color = Color::rgb(0x8a, 0xad, 0xf4);
+    /// Catppuccin Macchiato lavender, `#b7bdf8`.
+    pub const LAVENDER: Color = Color::rgb(0xb7, 0xbd, 0xf8);
+    /// Catppuccin Macchiato text, `#cad3f5`.
+    pub const TEXT: Color = Color::rgb(0xca, 0xd3, 0xf5);
+    /// Catppuccin Macchiato subtext1, `#b8c0e0`.
+    pub const SUBTEXT1: Color = Color::rgb(0xb8, 0xc0, 0xe0);
+    /// Catppuccin Macchiato subtext0, `#a5adcb`.
+    pub const SUBTEXT0: Color = Color::rgb(0xa5, 0xad, 0xcb);
+    /// Catppuccin Macchiato overlay2, `#939ab7`.
+    pub const OVERLAY2: Color = Color::rgb(0x93, 0x9a, 0xb7);
+    /// Catppuccin Macchiato overlay1, `#8087a2`.
+    pub const OVERLAY1: Color = Color::rgb(0x80, 0x87, 0xa2);
+    /// Catppuccin Macchiato overlay0, `#6e738d`.
+    pub const OVERLAY0: Color = Color::rgb(0x6e, 0x73, 0x8d);
+    /// Catppuccin Macchiato surface2, `#5b6078`.
+    pub const SURFACE2: Color = Color::rgb(0x5b, 0x60, 0x78);
+    /// Catppuccin Macchiato surface1, `#494d64`.
+    pub const SURFACE1: Color = Color::rgb(0x49, 0x4d, 0x64);
+    /// Catppuccin Macchiato surface0, `#363a4f`.
+    pub const SURFACE0: Color = Color::rgb(0x36, 0x3a, 0x4f);
+    /// Catppuccin Macchiato base, `#24273a`.
+    pub const BASE: Color = Color::rgb(0x24, 0x27, 0x3a);
+    /// Catppuccin Macchiato mantle, `#1e2030`.
+    pub const MANTLE: Color = Color::rgb(0x1e, 0x20, 0x30);
+    /// Catppuccin Macchiato crust, `#181926`.
+    pub const CRUST: Color = Color::rgb(0x18, 0x19, 0x26);
+
+    /// The full Catppuccin Macchiato palette.
+    pub const PALETTE: Palette = Palette::new(
+        "Catppuccin Macchiato",
+        Some(Background::Dark),
+        &[
+            ("rosewater", ROSEWATER),
+            ("flamingo", FLAMINGO),
+            ("pink", PINK),
+            ("mauve", MAUVE),
+            ("red", RED),
+            ("maroon", MAROON),
+            ("peach", PEACH),
+            ("yellow", YELLOW),
+            ("green", GREEN),
+            ("teal", TEAL),
+            ("sky", SKY),
+            ("sapphire", SAPPHIRE),
+            ("blue", BLUE),
+            ("lavender", LAVENDER),
+            ("text", TEXT),
+            ("subtext1", SUBTEXT1),
+            ("subtext0", SUBTEXT0),
+            ("overlay2", OVERLAY2),
+            ("overlay1", OVERLAY1),
+            ("overlay0", OVERLAY0),
+            ("surface2", SURFACE2),
+            ("surface1", SURFACE1),
+            ("surface0", SURFACE0),
+            ("base", BASE),
+            ("mantle", MANTLE),
+            ("crust", CRUST),
+        ],
+    );
+}
+
+/// The Mocha flavor.
+pub mod mocha {
+    use crate::palettes::Palette;
+    use crate::{Background, Color};
+
+    /// Catppuccin Mocha rosewater, `#f5e0dc`.
+    pub const ROSEWATER: Color = Color::rgb(0xf5, 0xe0, 0xdc);
+    /// Catppuccin Mocha flamingo, `#f2cdcd`.
+    pub const FLAMINGO: Color = Color::rgb(0xf2, 0xcd, 0xcd);
+    /// Catppuccin Mocha pink, `#f5c2e7`.
+    pub const PINK: Color = Color::rgb(0xf5, 0xc2, 0xe7);
+    /// Catppuccin Mocha mauve, `#cba6f7`.
+    pub const MAUVE: Color = Color::rgb(0xcb, 0xa6, 0xf7);
+    /// Catppuccin Mocha red, `#f38ba8`.
+    pub const RED: Color = Color::rgb(0xf3, 0x8b, 0xa8);
+    /// Catppuccin Mocha maroon, `#eba0ac`.
+    pub const MAROON: Color = Color::rgb(0xeb, 0xa0, 0xac);
+    /// Catppuccin Mocha peach, `#fab387`.
+    pub const PEACH: Color = Color::rgb(0xfa, 0xb3, 0x87);
+    /// Catppuccin Mocha yellow, `#f9e2af`.
+    pub const YELLOW: Color = Color::rgb(0xf9, 0xe2, 0xaf);
+    /// Catppuccin Mocha green, `#a6e3a1`.
+    pub const GREEN: Color = Color::rgb(0xa6, 0xe3, 0xa1);
+    /// Catppuccin Mocha teal, `#94e2d5`.
+    pub const TEAL: Color = Color::rgb(0x94, 0xe2, 0xd5);
+    /// Catppuccin Mocha sky, `#89dceb`.
+    pub const SKY: Color = Color::rgb(0x89, 0xdc, 0xeb);
+    /// Catppuccin Mocha sapphire, `#74c7ec`.
+    pub const SAPPHIRE: Color = Color::rgb(0x74, 0xc7, 0xec);
+    /// Catppuccin Mocha blue, `#89b4fa`.
+    pub const BLUE: Color = Color::rgb(0x89, 0xb4, 0xfa);
+    /// Catppuccin Mocha lavender, `#b4befe`.
+    pub const LAVENDER: Color = Color::rgb(0xb4, 0xbe, 0xfe);
+    /// Catppuccin Mocha text, `#cdd6f4`.
+    pub const TEXT: Color = Color::rgb(0xcd, 0xd6, 0xf4);
+    /// Catppuccin Mocha subtext1, `#bac2de`.
+    pub const SUBTEXT1: Color = Color::rgb(0xba, 0xc2, 0xde);
+    /// Catppuccin Mocha subtext0, `#a6adc8`.
+    pub const SUBTEXT0: Color = Color::rgb(0xa6, 0xad, 0xc8);
+    /// Catppuccin Mocha overlay2, `#9399b2`.
+    pub const OVERLAY2: Color = Color::rgb(0x93, 0x99, 0xb2);
+    /// Catppuccin Mocha overlay1, `#7f849c`.
+    pub const OVERLAY1: Color = Color::rgb(0x7f, 0x84, 0x9c);
+    /// Catppuccin Mocha overlay0, `#6c7086`.
+    pub const OVERLAY0: Color = Color::rgb(0x6c, 0x70, 0x86);
+    /// Catppuccin Mocha surface2, `#585b70`.
+    pub const SURFACE2: Color = Color::rgb(0x58, 0x5b, 0x70);
+    /// Catppuccin Mocha surface1, `#45475a`.
+    pub const SURFACE1: Color = Color::rgb(0x45, 0x47, 0x5a);
+    /// Catppuccin Mocha surface0, `#313244`.
+    pub const SURFACE0: Color = Color::rgb(0x31, 0x32, 0x44);
+    /// Catppuccin Mocha base, `#1e1e2e`.
+    pub const BASE: Color = Color::rgb(0x1e, 0x1e, 0x2e);
+    /// Catppuccin Mocha mantle, `#181825`.
+    pub const MANTLE: Color = Color::rgb(0x18, 0x18, 0x25);
+    /// Catppuccin Mocha crust, `#11111b`.
+    pub const CRUST: Color = Color::rgb(0x11, 0x11, 0x1b);
+
+    /// The full Catppuccin Mocha palette.
+    pub const PALETTE: Palette = Palette::new(
+        "Catppuccin Mocha",
+        Some(Background::Dark),
+        &[
+            ("rosewater", ROSEWATER),
+            ("flamingo", FLAMINGO),
+            ("pink", PINK),
+            ("mauve", MAUVE),
+            ("red", RED),
+            ("maroon", MAROON),
+            ("peach", PEACH),
+            ("yellow", YELLOW),
+            ("green", GREEN),
+            ("teal", TEAL),
+            ("sky", SKY),
+            ("sapphire", SAPPHIRE),
+            ("blue", BLUE),
+            ("lavender", LAVENDER),
+            ("text", TEXT),
+            ("subtext1", SUBTEXT1),
+            ("subtext0", SUBTEXT0),
+            ("overlay2", OVERLAY2),
+            ("overlay1", OVERLAY1),
+            ("overlay0", OVERLAY0),
+            ("surface2", SURFACE2),
+            ("surface1", SURFACE1),
+            ("surface0", SURFACE0),
+            ("base", BASE),
+            ("mantle", MANTLE),
+            ("crust", CRUST),
+        ],
+    );
+}
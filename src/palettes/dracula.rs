@@ -0,0 +1,46 @@
+//! The [Dracula](https://draculatheme.com) palette.
+
+use super::Palette;
+use crate::{Background, Color};
+
+/// Dracula background, `#282a36`.
+pub const BACKGROUND: Color = Color::rgb(0x28, 0x2a, 0x36);
+/// Dracula current line, `#44475a`.
+pub const CURRENT_LINE: Color = Color::rgb(0x44, 0x47, 0x5a);
+/// Dracula foreground, `#f8f8f2`.
+pub const FOREGROUND: Color = Color::rgb(0xf8, 0xf8, 0xf2);
+/// Dracula comment, `#6272a4`.
+pub const COMMENT: Color = Color::rgb(0x62, 0x72, 0xa4);
+/// Dracula cyan, `#8be9fd`.
+pub const CYAN: Color = Color::rgb(0x8b, 0xe9, 0xfd);
+/// Dracula green, `#50fa7b`.
+pub const GREEN: Color = Color::rgb(0x50, 0xfa, 0x7b);
+/// Dracula orange, `#ffb86c`.
+pub const ORANGE: Color = Color::rgb(0xff, 0xb8, 0x6c);
+/// Dracula pink, `#ff79c6`.
+pub const PINK: Color = Color::rgb(0xff, 0x79, 0xc6);
+/// Dracula purple, `#bd93f9`.
+pub const PURPLE: Color = Color::rgb(0xbd, 0x93, 0xf9);
+/// Dracula red, `#ff5555`.
+pub const RED: Color = Color::rgb(0xff, 0x55, 0x55);
+/// Dracula yellow, `#f1fa8c`.
+pub const YELLOW: Color = Color::rgb(0xf1, 0xfa, 0x8c);
+
+/// The full Dracula palette.
+pub const PALETTE: Palette = Palette::new(
+    "Dracula",
+    Some(Background::Dark),
+    &[
+        ("background", BACKGROUND),
+        ("current_line", CURRENT_LINE),
+        ("foreground", FOREGROUND),
+        ("comment", COMMENT),
+        ("cyan", CYAN),
+        ("green", GREEN),
+        ("orange", ORANGE),
+        ("pink", PINK),
+        ("purple", PURPLE),
+        ("red", RED),
+        ("yellow", YELLOW),
+    ],
+);
@@ -0,0 +1,154 @@
+//! The [Gruvbox](https://github.com/morhetz/gruvbox) palette.
+//!
+//! Dark mode pairs the neutral accents with the bright ones; light mode pairs
+//! them with the faded ones.
+
+use super::Palette;
+use crate::{Background, Color};
+
+/// Gruvbox dark0 hard, `#1d2021`.
+pub const DARK0_HARD: Color = Color::rgb(0x1d, 0x20, 0x21);
+/// Gruvbox dark0, `#282828`.
+pub const DARK0: Color = Color::rgb(0x28, 0x28, 0x28);
+/// Gruvbox dark0 soft, `#32302f`.
+pub const DARK0_SOFT: Color = Color::rgb(0x32, 0x30, 0x2f);
+/// Gruvbox dark1, `#3c3836`.
+pub const DARK1: Color = Color::rgb(0x3c, 0x38, 0x36);
+/// Gruvbox dark2, `#504945`.
+pub const DARK2: Color = Color::rgb(0x50, 0x49, 0x45);
+/// Gruvbox dark3, `#665c54`.
+pub const DARK3: Color = Color::rgb(0x66, 0x5c, 0x54);
+/// Gruvbox dark4, `#7c6f64`.
+pub const DARK4: Color = Color::rgb(0x7c, 0x6f, 0x64);
+/// Gruvbox gray, `#928374`.
+pub const GRAY: Color = Color::rgb(0x92, 0x83, 0x74);
+/// Gruvbox light0 hard, `#f9f5d7`.
+pub const LIGHT0_HARD: Color = Color::rgb(0xf9, 0xf5, 0xd7);
+/// Gruvbox light0, `#fbf1c7`.
+pub const LIGHT0: Color = Color::rgb(0xfb, 0xf1, 0xc7);
+/// Gruvbox light0 soft, `#f2e5bc`.
+pub const LIGHT0_SOFT: Color = Color::rgb(0xf2, 0xe5, 0xbc);
+/// Gruvbox light1, `#ebdbb2`.
+pub const LIGHT1: Color = Color::rgb(0xeb, 0xdb, 0xb2);
+/// Gruvbox light2, `#d5c4a1`.
+pub const LIGHT2: Color = Color::rgb(0xd5, 0xc4, 0xa1);
+/// Gruvbox light3, `#bdae93`.
+pub const LIGHT3: Color = Color::rgb(0xbd, 0xae, 0x93);
+/// Gruvbox light4, `#a89984`.
+pub const LIGHT4: Color = Color::rgb(0xa8, 0x99, 0x84);
+/// Gruvbox neutral red, `#cc241d`.
+pub const NEUTRAL_RED: Color = Color::rgb(0xcc, 0x24, 0x1d);
+/// Gruvbox neutral green, `#98971a`.
+pub const NEUTRAL_GREEN: Color = Color::rgb(0x98, 0x97, 0x1a);
+/// Gruvbox neutral yellow, `#d79921`.
+pub const NEUTRAL_YELLOW: Color = Color::rgb(0xd7, 0x99, 0x21);
+/// Gruvbox neutral blue, `#458588`.
+pub const NEUTRAL_BLUE: Color = Color::rgb(0x45, 0x85, 0x88);
+/// Gruvbox neutral purple, `#b16286`.
+pub const NEUTRAL_PURPLE: Color = Color::rgb(0xb1, 0x62, 0x86);
+/// Gruvbox neutral aqua, `#689d6a`.
+pub const NEUTRAL_AQUA: Color = Color::rgb(0x68, 0x9d, 0x6a);
+/// Gruvbox neutral orange, `#d65d0e`.
+pub const NEUTRAL_ORANGE: Color = Color::rgb(0xd6, 0x5d, 0x0e);
+/// Gruvbox bright red, `#fb4934`.
+pub const BRIGHT_RED: Color = Color::rgb(0xfb, 0x49, 0x34);
+/// Gruvbox bright green, `#b8bb26`.
+pub const BRIGHT_GREEN: Color = Color::rgb(0xb8, 0xbb, 0x26);
+/// Gruvbox bright yellow, `#fabd2f`.
+pub const BRIGHT_YELLOW: Color = Color::rgb(0xfa, 0xbd, 0x2f);
+/// Gruvbox bright blue, `#83a598`.
+pub const BRIGHT_BLUE: Color = Color::rgb(0x83, 0xa5, 0x98);
+/// Gruvbox bright purple, `#d3869b`.
+pub const BRIGHT_PURPLE: Color = Color::rgb(0xd3, 0x86, 0x9b);
+/// Gruvbox bright aqua, `#8ec07c`.
+pub const BRIGHT_AQUA: Color = Color::rgb(0x8e, 0xc0, 0x7c);
+/// Gruvbox bright orange, `#fe8019`.
+pub const BRIGHT_ORANGE: Color = Color::rgb(0xfe, 0x80, 0x19);
+/// Gruvbox faded red, `#9d0006`.
+pub const FADED_RED: Color = Color::rgb(0x9d, 0x00, 0x06);
+/// Gruvbox faded green, `#79740e`.
+pub const FADED_GREEN: Color = Color::rgb(0x79, 0x74, 0x0e);
+/// Gruvbox faded yellow, `#b57614`.
+pub const FADED_YELLOW: Color = Color::rgb(0xb5, 0x76, 0x14);
+/// Gruvbox faded blue, `#076678`.
+pub const FADED_BLUE: Color = Color::rgb(0x07, 0x66, 0x78);
+/// Gruvbox faded purple, `#8f3f71`.
+pub const FADED_PURPLE: Color = Color::rgb(0x8f, 0x3f, 0x71);
+/// Gruvbox faded aqua, `#427b58`.
+pub const FADED_AQUA: Color = Color::rgb(0x42, 0x7b, 0x58);
+/// Gruvbox faded orange, `#af3a03`.
+pub const FADED_ORANGE: Color = Color::rgb(0xaf, 0x3a, 0x03);
+
+/// Gruvbox on a dark background.
+pub const DARK: Palette = Palette::new(
+    "Gruvbox Dark",
+    Some(Background::Dark),
+    &[
+        ("dark0_hard", DARK0_HARD),
+        ("dark0", DARK0),
+        ("dark0_soft", DARK0_SOFT),
+        ("dark1", DARK1),
+        ("dark2", DARK2),
+        ("dark3", DARK3),
+        ("dark4", DARK4),
+        ("gray", GRAY),
+        ("light0_hard", LIGHT0_HARD),
+        ("light0", LIGHT0),
+        ("light0_soft", LIGHT0_SOFT),
+        ("light1", LIGHT1),
+        ("light2", LIGHT2),
+        ("light3", LIGHT3),
+        ("light4", LIGHT4),
+        ("neutral_red", NEUTRAL_RED),
+        ("neutral_green", NEUTRAL_GREEN),
+        ("neutral_yellow", NEUTRAL_YELLOW),
+        ("neutral_blue", NEUTRAL_BLUE),
+        ("neutral_purple", NEUTRAL_PURPLE),
+        ("neutral_aqua", NEUTRAL_AQUA),
+        ("neutral_orange", NEUTRAL_ORANGE),
+        ("bright_red", BRIGHT_RED),
+        ("bright_green", BRIGHT_GREEN),
+        ("bright_yellow", BRIGHT_YELLOW),
+        ("bright_blue", BRIGHT_BLUE),
+        ("bright_purple", BRIGHT_PURPLE),
+        ("bright_aqua", BRIGHT_AQUA),
+        ("bright_orange", BRIGHT_ORANGE),
+    ],
+);
+
+/// Gruvbox on a light background.
+pub const LIGHT: Palette = Palette::new(
+    "Gruvbox Light",
+    Some(Background::Light),
+    &[
+        ("light0_hard", LIGHT0_HARD),
+        ("light0", LIGHT0),
+        ("light0_soft", LIGHT0_SOFT),
+        ("light1", LIGHT1),
+        ("light2", LIGHT2),
+        ("light3", LIGHT3),
+        ("light4", LIGHT4),
+        ("gray", GRAY),
+        ("dark0_hard", DARK0_HARD),
+        ("dark0", DARK0),
+        ("dark0_soft", DARK0_SOFT),
+        ("dark1", DARK1),
+        ("dark2", DARK2),
+        ("dark3", DARK3),
+        ("dark4", DARK4),
+        ("neutral_red", NEUTRAL_RED),
+        ("neutral_green", NEUTRAL_GREEN),
+        ("neutral_yellow", NEUTRAL_YELLOW),
+        ("neutral_blue", NEUTRAL_BLUE),
+        ("neutral_purple", NEUTRAL_PURPLE),
+        ("neutral_aqua", NEUTRAL_AQUA),
+        ("neutral_orange", NEUTRAL_ORANGE),
+        ("faded_red", FADED_RED),
+        ("faded_green", FADED_GREEN),
+        ("faded_yellow", FADED_YELLOW),
+        ("faded_blue", FADED_BLUE),
+        ("faded_purple", FADED_PURPLE),
+        ("faded_aqua", FADED_AQUA),
+        ("faded_orange", FADED_ORANGE),
+    ],
+);
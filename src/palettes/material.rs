@@ -0,0 +1,776 @@
+//! The [Material Design](https://m2.material.io/design/color/the-color-system.html)
+//! 2014 color palette.
+
+use super::Palette;
+use crate::Color;
+
+/// Material red 50, `#ffebee`.
+pub const RED_50: Color = Color::rgb(0xff, 0xeb, 0xee);
+/// Material red 100, `#ffcdd2`.
+pub const RED_100: Color = Color::rgb(0xff, 0xcd, 0xd2);
+/// Material red 200, `#ef9a9a`.
+pub const RED_200: Color = Color::rgb(0xef, 0x9a, 0x9a);
+/// Material red 300, `#e57373`.
+pub const RED_300: Color = Color::rgb(0xe5, 0x73, 0x73);
+/// Material red 400, `#ef5350`.
+pub const RED_400: Color = Color::rgb(0xef, 0x53, 0x50);
+/// Material red 500, `#f44336`.
+pub const RED_500: Color = Color::rgb(0xf4, 0x43, 0x36);
+/// Material red 600, `#e53935`.
+pub const RED_600: Color = Color::rgb(0xe5, 0x39, 0x35);
+/// Material red 700, `#d32f2f`.
+pub const RED_700: Color = Color::rgb(0xd3, 0x2f, 0x2f);
+/// Material red 800, `#c62828`.
+pub const RED_800: Color = Color::rgb(0xc6, 0x28, 0x28);
+/// Material red 900, `#b71c1c`.
+pub const RED_900: Color = Color::rgb(0xb7, 0x1c, 0x1c);
+/// Material red A100, `#ff8a80`.
+pub const RED_A100: Color = Color::rgb(0xff, 0x8a, 0x80);
+/// Material red A200, `#ff5252`.
+pub const RED_A200: Color = Color::rgb(0xff, 0x52, 0x52);
+/// Material red A400, `#ff1744`.
+pub const RED_A400: Color = Color::rgb(0xff, 0x17, 0x44);
+/// Material red A700, `#d50000`.
+pub const RED_A700: Color = Color::rgb(0xd5, 0x00, 0x00);
+/// Material pink 50, `#fce4ec`.
+pub const PINK_50: Color = Color::rgb(0xfc, 0xe4, 0xec);
+/// Material pink 100, `#f8bbd0`.
+pub const PINK_100: Color = Color::rgb(0xf8, 0xbb, 0xd0);
+/// Material pink 200, `#f48fb1`.
+pub const PINK_200: Color = Color::rgb(0xf4, 0x8f, 0xb1);
+/// Material pink 300, `#f06292`.
+pub const PINK_300: Color = Color::rgb(0xf0, 0x62, 0x92);
+/// Material pink 400, `#ec407a`.
+pub const PINK_400: Color = Color::rgb(0xec, 0x40, 0x7a);
+/// Material pink 500, `#e91e63`.
+pub const PINK_500: Color = Color::rgb(0xe9, 0x1e, 0x63);
+/// Material pink 600, `#d81b60`.
+pub const PINK_600: Color = Color::rgb(0xd8, 0x1b, 0x60);
+/// Material pink 700, `#c2185b`.
+pub const PINK_700: Color = Color::rgb(0xc2, 0x18, 0x5b);
+/// Material pink 800, `#ad1457`.
+pub const PINK_800: Color = Color::rgb(0xad, 0x14, 0x57);
+/// Material pink 900, `#880e4f`.
+pub const PINK_900: Color = Color::rgb(0x88, 0x0e, 0x4f);
+/// Material pink A100, `#ff80ab`.
+pub const PINK_A100: Color = Color::rgb(0xff, 0x80, 0xab);
+/// Material pink A200, `#ff4081`.
+pub const PINK_A200: Color = Color::rgb(0xff, 0x40, 0x81);
+/// Material pink A400, `#f50057`.
+pub const PINK_A400: Color = Color::rgb(0xf5, 0x00, 0x57);
+/// Material pink A700, `#c51162`.
+pub const PINK_A700: Color = Color::rgb(0xc5, 0x11, 0x62);
+/// Material purple 50, `#f3e5f5`.
+pub const PURPLE_50: Color = Color::rgb(0xf3, 0xe5, 0xf5);
+/// Material purple 100, `#e1bee7`.
+pub const PURPLE_100: Color = Color::rgb(0xe1, 0xbe, 0xe7);
+/// Material purple 200, `#ce93d8`.
+pub const PURPLE_200: Color = Color::rgb(0xce, 0x93, 0xd8);
+/// Material purple 300, `#ba68c8`.
+pub const PURPLE_300: Color = Color::rgb(0xba, 0x68, 0xc8);
+/// Material purple 400, `#ab47bc`.
+pub const PURPLE_400: Color = Color::rgb(0xab, 0x47, 0xbc);
+/// Material purple 500, `#9c27b0`.
+pub const PURPLE_500: Color = Color::rgb(0x9c, 0x27, 0xb0);
+/// Material purple 600, `#8e24aa`.
+pub const PURPLE_600: Color = Color::rgb(0x8e, 0x24, 0xaa);
+/// Material purple 700, `#7b1fa2`.
+pub const PURPLE_700: Color = Color::rgb(0x7b, 0x1f, 0xa2);
+/// Material purple 800, `#6a1b9a`.
+pub const PURPLE_800: Color = Color::rgb(0x6a, 0x1b, 0x9a);
+/// Material purple 900, `#4a148c`.
+pub const PURPLE_900: Color = Color::rgb(0x4a, 0x14, 0x8c);
+/// Material purple A100, `#ea80fc`.
+pub const PURPLE_A100: Color = Color::rgb(0xea, 0x80, 0xfc);
+/// Material purple A200, `#e040fb`.
+pub const PURPLE_A200: Color = Color::rgb(0xe0, 0x40, 0xfb);
+/// Material purple A400, `#d500f9`.
+pub const PURPLE_A400: Color = Color::rgb(0xd5, 0x00, 0xf9);
+/// Material purple A700, `#aa00ff`.
+pub const PURPLE_A700: Color = Color::rgb(0xaa, 0x00, 0xff);
+/// Material deep purple 50, `#ede7f6`.
+pub const DEEP_PURPLE_50: Color = Color::rgb(0xed, 0xe7, 0xf6);
+/// Material deep purple 100, `#d1c4e9`.
+pub const DEEP_PURPLE_100: Color = Color::rgb(0xd1, 0xc4, 0xe9);
+/// Material deep purple 200, `#b39ddb`.
+pub const DEEP_PURPLE_200: Color = Color::rgb(0xb3, 0x9d, 0xdb);
+/// Material deep purple 300, `#9575cd`.
+pub const DEEP_PURPLE_300: Color = Color::rgb(0x95, 0x75, 0xcd);
+/// Material deep purple 400, `#7e57c2`.
+pub const DEEP_PURPLE_400: Color = Color::rgb(0x7e, 0x57, 0xc2);
+/// Material deep purple 500, `#673ab7`.
+pub const DEEP_PURPLE_500: Color = Color::rgb(0x67, 0x3a, 0xb7);
+/// Material deep purple 600, `#5e35b1`.
+pub const DEEP_PURPLE_600: Color = Color::rgb(0x5e, 0x35, 0xb1);
+/// Material deep purple 700, `#512da8`.
+pub const DEEP_PURPLE_700: Color = Color::rgb(0x51, 0x2d, 0xa8);
+/// Material deep purple 800, `#4527a0`.
+pub const DEEP_PURPLE_800: Color = Color::rgb(0x45, 0x27, 0xa0);
+/// Material deep purple 900, `#311b92`.
+pub const DEEP_PURPLE_900: Color = Color::rgb(0x31, 0x1b, 0x92);
+/// Material deep purple A100, `#b388ff`.
+pub const DEEP_PURPLE_A100: Color = Color::rgb(0xb3, 0x88, 0xff);
+/// Material deep purple A200, `#7c4dff`.
+pub const DEEP_PURPLE_A200: Color = Color::rgb(0x7c, 0x4d, 0xff);
+/// Material deep purple A400, `#651fff`.
+pub const DEEP_PURPLE_A400: Color = Color::rgb(0x65, 0x1f, 0xff);
+/// Material deep purple A700, `#6200ea`.
+pub const DEEP_PURPLE_A700: Color = Color::rgb(0x62, 0x00, 0xea);
+/// Material indigo 50, `#e8eaf6`.
+pub const INDIGO_50: Color = Color::rgb(0xe8, 0xea, 0xf6);
+/// Material indigo 100, `#c5cae9`.
+pub const INDIGO_100: Color = Color::rgb(0xc5, 0xca, 0xe9);
+/// Material indigo 200, `#9fa8da`.
+pub const INDIGO_200: Color = Color::rgb(0x9f, 0xa8, 0xda);
+/// Material indigo 300, `#7986cb`.
+pub const INDIGO_300: Color = Color::rgb(0x79, 0x86, 0xcb);
+/// Material indigo 400, `#5c6bc0`.
+pub const INDIGO_400: Color = Color::rgb(0x5c, 0x6b, 0xc0);
+/// Material indigo 500, `#3f51b5`.
+pub const INDIGO_500: Color = Color::rgb(0x3f, 0x51, 0xb5);
+/// Material indigo 600, `#3949ab`.
+pub const INDIGO_600: Color = Color::rgb(0x39, 0x49, 0xab);
+/// Material indigo 700, `#303f9f`.
+pub const INDIGO_700: Color = Color::rgb(0x30, 0x3f, 0x9f);
+/// Material indigo 800, `#283593`.
+pub const INDIGO_800: Color = Color::rgb(0x28, 0x35, 0x93);
+/// Material indigo 900, `#1a237e`.
+pub const INDIGO_900: Color = Color::rgb(0x1a, 0x23, 0x7e);
+/// Material indigo A100, `#8c9eff`.
+pub const INDIGO_A100: Color = Color::rgb(0x8c, 0x9e, 0xff);
+/// Material indigo A200, `#536dfe`.
+pub const INDIGO_A200: Color = Color::rgb(0x53, 0x6d, 0xfe);
+/// Material indigo A400, `#3d5afe`.
+pub const INDIGO_A400: Color = Color::rgb(0x3d, 0x5a, 0xfe);
+/// Material indigo A700, `#304ffe`.
+pub const INDIGO_A700: Color = Color::rgb(0x30, 0x4f, 0xfe);
+/// Material blue 50, `#e3f2fd`.
+pub const BLUE_50: Color = Color::rgb(0xe3, 0xf2, 0xfd);
+/// Material blue 100, `#bbdefb`.
+pub const BLUE_100: Color = Color::rgb(0xbb, 0xde, 0xfb);
+/// Material blue 200, `#90caf9`.
+pub const BLUE_200: Color = Color::rgb(0x90, 0xca, 0xf9);
+/// Material blue 300, `#64b5f6`.
+pub const BLUE_300: Color = Color::rgb(0x64, 0xb5, 0xf6);
+/// Material blue 400, `#42a5f5`.
+pub const BLUE_400: Color = Color::rgb(0x42, 0xa5, 0xf5);
+/// Material blue 500, `#2196f3`.
+pub const BLUE_500: Color = Color::rgb(0x21, 0x96, 0xf3);
+/// Material blue 600, `#1e88e5`.
+pub const BLUE_600: Color = Color::rgb(0x1e, 0x88, 0xe5);
+/// Material blue 700, `#1976d2`.
+pub const BLUE_700: Color = Color::rgb(0x19, 0x76, 0xd2);
+/// Material blue 800, `#1565c0`.
+pub const BLUE_800: Color = Color::rgb(0x15, 0x65, 0xc0);
+/// Material blue 900, `#0d47a1`.
+pub const BLUE_900: Color = Color::rgb(0x0d, 0x47, 0xa1);
+/// Material blue A100, `#82b1ff`.
+pub const BLUE_A100: Color = Color::rgb(0x82, 0xb1, 0xff);
+/// Material blue A200, `#448aff`.
+pub const BLUE_A200: Color = Color::rgb(0x44, 0x8a, 0xff);
+/// Material blue A400, `#2979ff`.
+pub const BLUE_A400: Color = Color::rgb(0x29, 0x79, 0xff);
+/// Material blue A700, `#2962ff`.
+pub const BLUE_A700: Color = Color::rgb(0x29, 0x62, 0xff);
+/// Material light blue 50, `#e1f5fe`.
+pub const LIGHT_BLUE_50: Color = Color::rgb(0xe1, 0xf5, 0xfe);
+/// Material light blue 100, `#b3e5fc`.
+pub const LIGHT_BLUE_100: Color = Color::rgb(0xb3, 0xe5, 0xfc);
+/// Material light blue 200, `#81d4fa`.
+pub const LIGHT_BLUE_200: Color = Color::rgb(0x81, 0xd4, 0xfa);
+/// Material light blue 300, `#4fc3f7`.
+pub const LIGHT_BLUE_300: Color = Color::rgb(0x4f, 0xc3, 0xf7);
+/// Material light blue 400, `#29b6f6`.
+pub const LIGHT_BLUE_400: Color = Color::rgb(0x29, 0xb6, 0xf6);
+/// Material light blue 500, `#03a9f4`.
+pub const LIGHT_BLUE_500: Color = Color::rgb(0x03, 0xa9, 0xf4);
+/// Material light blue 600, `#039be5`.
+pub const LIGHT_BLUE_600: Color = Color::rgb(0x03, 0x9b, 0xe5);
+/// Material light blue 700, `#0288d1`.
+pub const LIGHT_BLUE_700: Color = Color::rgb(0x02, 0x88, 0xd1);
+/// Material light blue 800, `#0277bd`.
+pub const LIGHT_BLUE_800: Color = Color::rgb(0x02, 0x77, 0xbd);
+/// Material light blue 900, `#01579b`.
+pub const LIGHT_BLUE_900: Color = Color::rgb(0x01, 0x57, 0x9b);
+/// Material light blue A100, `#80d8ff`.
+pub const LIGHT_BLUE_A100: Color = Color::rgb(0x80, 0xd8, 0xff);
+/// Material light blue A200, `#40c4ff`.
+pub const LIGHT_BLUE_A200: Color = Color::rgb(0x40, 0xc4, 0xff);
+/// Material light blue A400, `#00b0ff`.
+pub const LIGHT_BLUE_A400: Color = Color::rgb(0x00, 0xb0, 0xff);
+/// Material light blue A700, `#0091ea`.
+pub const LIGHT_BLUE_A700: Color = Color::rgb(0x00, 0x91, 0xea);
+/// Material cyan 50, `#e0f7fa`.
+pub const CYAN_50: Color = Color::rgb(0xe0, 0xf7, 0xfa);
+/// Material cyan 100, `#b2ebf2`.
+pub const CYAN_100: Color = Color::rgb(0xb2, 0xeb, 0xf2);
+/// Material cyan 200, `#80deea`.
+pub const CYAN_200: Color = Color::rgb(0x80, 0xde, 0xea);
+/// Material cyan 300, `#4dd0e1`.
+pub const CYAN_300: Color = Color::rgb(0x4d, 0xd0, 0xe1);
+/// Material cyan 400, `#26c6da`.
+pub const CYAN_400: Color = Color::rgb(0x26, 0xc6, 0xda);
+/// Material cyan 500, `#00bcd4`.
+pub const CYAN_500: Color = Color::rgb(0x00, 0xbc, 0xd4);
+/// Material cyan 600, `#00acc1`.
+pub const CYAN_600: Color = Color::rgb(0x00, 0xac, 0xc1);
+/// Material cyan 700, `#0097a7`.
+pub const CYAN_700: Color = Color::rgb(0x00, 0x97, 0xa7);
+/// Material cyan 800, `#00838f`.
+pub const CYAN_800: Color = Color::rgb(0x00, 0x83, 0x8f);
+/// Material cyan 900, `#006064`.
+pub const CYAN_900: Color = Color::rgb(0x00, 0x60, 0x64);
+/// Material cyan A100, `#84ffff`.
+pub const CYAN_A100: Color = Color::rgb(0x84, 0xff, 0xff);
+/// Material cyan A200, `#18ffff`.
+pub const CYAN_A200: Color = Color::rgb(0x18, 0xff, 0xff);
+/// Material cyan A400, `#00e5ff`.
+pub const CYAN_A400: Color = Color::rgb(0x00, 0xe5, 0xff);
+/// Material cyan A700, `#00b8d4`.
+pub const CYAN_A700: Color = Color::rgb(0x00, 0xb8, 0xd4);
+/// Material teal 50, `#e0f2f1`.
+pub const TEAL_50: Color = Color::rgb(0xe0, 0xf2, 0xf1);
+/// Material teal 100, `#b2dfdb`.
+pub const TEAL_100: Color = Color::rgb(0xb2, 0xdf, 0xdb);
+/// Material teal 200, `#80cbc4`.
+pub const TEAL_200: Color = Color::rgb(0x80, 0xcb, 0xc4);
+/// Material teal 300, `#4db6ac`.
+pub const TEAL_300: Color = Color::rgb(0x4d, 0xb6, 0xac);
+/// Material teal 400, `#26a69a`.
+pub const TEAL_400: Color = Color::rgb(0x26, 0xa6, 0x9a);
+/// Material teal 500, `#009688`.
+pub const TEAL_500: Color = Color::rgb(0x00, 0x96, 0x88);
+/// Material teal 600, `#00897b`.
+pub const TEAL_600: Color = Color::rgb(0x00, 0x89, 0x7b);
+/// Material teal 700, `#00796b`.
+pub const TEAL_700: Color = Color::rgb(0x00, 0x79, 0x6b);
+/// Material teal 800, `#00695c`.
+pub const TEAL_800: Color = Color::rgb(0x00, 0x69, 0x5c);
+/// Material teal 900, `#004d40`.
+pub const TEAL_900: Color = Color::rgb(0x00, 0x4d, 0x40);
+/// Material teal A100, `#a7ffeb`.
+pub const TEAL_A100: Color = Color::rgb(0xa7, 0xff, 0xeb);
+/// Material teal A200, `#64ffda`.
+pub const TEAL_A200: Color = Color::rgb(0x64, 0xff, 0xda);
+/// Material teal A400, `#1de9b6`.
+pub const TEAL_A400: Color = Color::rgb(0x1d, 0xe9, 0xb6);
+/// Material teal A700, `#00bfa5`.
+pub const TEAL_A700: Color = Color::rgb(0x00, 0xbf, 0xa5);
+/// Material green 50, `#e8f5e9`.
+pub const GREEN_50: Color = Color::rgb(0xe8, 0xf5, 0xe9);
+/// Material green 100, `#c8e6c9`.
+pub const GREEN_100: Color = Color::rgb(0xc8, 0xe6, 0xc9);
+/// Material green 200, `#a5d6a7`.
+pub const GREEN_200: Color = Color::rgb(0xa5, 0xd6, 0xa7);
+/// Material green 300, `#81c784`.
+pub const GREEN_300: Color = Color::rgb(0x81, 0xc7, 0x84);
+/// Material green 400, `#66bb6a`.
+pub const GREEN_400: Color = Color::rgb(0x66, 0xbb, 0x6a);
+/// Material green 500, `#4caf50`.
+pub const GREEN_500: Color = Color::rgb(0x4c, 0xaf, 0x50);
+/// Material green 600, `#43a047`.
+pub const GREEN_600: Color = Color::rgb(0x43, 0xa0, 0x47);
+/// Material green 700, `#388e3c`.
+pub const GREEN_700: Color = Color::rgb(0x38, 0x8e, 0x3c);
+/// Material green 800, `#2e7d32`.
+pub const GREEN_800: Color = Color::rgb(0x2e, 0x7d, 0x32);
+/// Material green 900, `#1b5e20`.
+pub const GREEN_900: Color = Color::rgb(0x1b, 0x5e, 0x20);
+/// Material green A100, `#b9f6ca`.
+pub const GREEN_A100: Color = Color::rgb(0xb9, 0xf6, 0xca);
+/// Material green A200, `#69f0ae`.
+pub const GREEN_A200: Color = Color::rgb(0x69, 0xf0, 0xae);
+/// Material green A400, `#00e676`.
+pub const GREEN_A400: Color = Color::rgb(0x00, 0xe6, 0x76);
+/// Material green A700, `#00c853`.
+pub const GREEN_A700: Color = Color::rgb(0x00, 0xc8, 0x53);
+/// Material light green 50, `#f1f8e9`.
+pub const LIGHT_GREEN_50: Color = Color::rgb(0xf1, 0xf8, 0xe9);
+/// Material light green 100, `#dcedc8`.
+pub const LIGHT_GREEN_100: Color = Color::rgb(0xdc, 0xed, 0xc8);
+/// Material light green 200, `#c5e1a5`.
+pub const LIGHT_GREEN_200: Color = Color::rgb(0xc5, 0xe1, 0xa5);
+/// Material light green 300, `#aed581`.
+pub const LIGHT_GREEN_300: Color = Color::rgb(0xae, 0xd5, 0x81);
+/// Material light green 400, `#9ccc65`.
+pub const LIGHT_GREEN_400: Color = Color::rgb(0x9c, 0xcc, 0x65);
+/// Material light green 500, `#8bc34a`.
+pub const LIGHT_GREEN_500: Color = Color::rgb(0x8b, 0xc3, 0x4a);
+/// Material light green 600, `#7cb342`.
+pub const LIGHT_GREEN_600: Color = Color::rgb(0x7c, 0xb3, 0x42);
+/// Material light green 700, `#689f38`.
+pub const LIGHT_GREEN_700: Color = Color::rgb(0x68, 0x9f, 0x38);
+/// Material light green 800, `#558b2f`.
+pub const LIGHT_GREEN_800: Color = Color::rgb(0x55, 0x8b, 0x2f);
+/// Material light green 900, `#33691e`.
+pub const LIGHT_GREEN_900: Color = Color::rgb(0x33, 0x69, 0x1e);
+/// Material light green A100, `#ccff90`.
+pub const LIGHT_GREEN_A100: Color = Color::rgb(0xcc, 0xff, 0x90);
+/// Material light green A200, `#b2ff59`.
+pub const LIGHT_GREEN_A200: Color = Color::rgb(0xb2, 0xff, 0x59);
+/// Material light green A400, `#76ff03`.
+pub const LIGHT_GREEN_A400: Color = Color::rgb(0x76, 0xff, 0x03);
+/// Material light green A700, `#64dd17`.
+pub const LIGHT_GREEN_A700: Color = Color::rgb(0x64, 0xdd, 0x17);
+/// Material lime 50, `#f9fbe7`.
+pub const LIME_50: Color = Color::rgb(0xf9, 0xfb, 0xe7);
+/// Material lime 100, `#f0f4c3`.
+pub const LIME_100: Color = Color::rgb(0xf0, 0xf4, 0xc3);
+/// Material lime 200, `#e6ee9c`.
+pub const LIME_200: Color = Color::rgb(0xe6, 0xee, 0x9c);
+/// Material lime 300, `#dce775`.
+pub const LIME_300: Color = Color::rgb(0xdc, 0xe7, 0x75);
+/// Material lime 400, `#d4e157`.
+pub const LIME_400: Color = Color::rgb(0xd4, 0xe1, 0x57);
+/// Material lime 500, `#cddc39`.
+pub const LIME_500: Color = Color::rgb(0xcd, 0xdc, 0x39);
+/// Material lime 600, `#c0ca33`.
+pub const LIME_600: Color = Color::rgb(0xc0, 0xca, 0x33);
+/// Material lime 700, `#afb42b`.
+pub const LIME_700: Color = Color::rgb(0xaf, 0xb4, 0x2b);
+/// Material lime 800, `#9e9d24`.
+pub const LIME_800: Color = Color::rgb(0x9e, 0x9d, 0x24);
+/// Material lime 900, `#827717`.
+pub const LIME_900: Color = Color::rgb(0x82, 0x77, 0x17);
+/// Material lime A100, `#f4ff81`.
+pub const LIME_A100: Color = Color::rgb(0xf4, 0xff, 0x81);
+/// Material lime A200, `#eeff41`.
+pub const LIME_A200: Color = Color::rgb(0xee, 0xff, 0x41);
+/// Material lime A400, `#c6ff00`.
+pub const LIME_A400: Color = Color::rgb(0xc6, 0xff, 0x00);
+/// Material lime A700, `#aeea00`.
+pub const LIME_A700: Color = Color::rgb(0xae, 0xea, 0x00);
+/// Material yellow 50, `#fffde7`.
+pub const YELLOW_50: Color = Color::rgb(0xff, 0xfd, 0xe7);
+/// Material yellow 100, `#fff9c4`.
+pub const YELLOW_100: Color = Color::rgb(0xff, 0xf9, 0xc4);
+/// Material yellow 200, `#fff59d`.
+pub const YELLOW_200: Color = Color::rgb(0xff, 0xf5, 0x9d);
+/// Material yellow 300, `#fff176`.
+pub const YELLOW_300: Color = Color::rgb(0xff, 0xf1, 0x76);
+/// Material yellow 400, `#ffee58`.
+pub const YELLOW_400: Color = Color::rgb(0xff, 0xee, 0x58);
+/// Material yellow 500, `#ffeb3b`.
+pub const YELLOW_500: Color = Color::rgb(0xff, 0xeb, 0x3b);
+/// Material yellow 600, `#fdd835`.
+pub const YELLOW_600: Color = Color::rgb(0xfd, 0xd8, 0x35);
+/// Material yellow 700, `#fbc02d`.
+pub const YELLOW_700: Color = Color::rgb(0xfb, 0xc0, 0x2d);
+/// Material yellow 800, `#f9a825`.
+pub const YELLOW_800: Color = Color::rgb(0xf9, 0xa8, 0x25);
+/// Material yellow 900, `#f57f17`.
+pub const YELLOW_900: Color = Color::rgb(0xf5, 0x7f, 0x17);
+/// Material yellow A100, `#ffff8d`.
+pub const YELLOW_A100: Color = Color::rgb(0xff, 0xff, 0x8d);
+/// Material yellow A200, `#ffff00`.
+pub const YELLOW_A200: Color = Color::rgb(0xff, 0xff, 0x00);
+/// Material yellow A400, `#ffea00`.
+pub const YELLOW_A400: Color = Color::rgb(0xff, 0xea, 0x00);
+/// Material yellow A700, `#ffd600`.
+pub const YELLOW_A700: Color = Color::rgb(0xff, 0xd6, 0x00);
+/// Material amber 50, `#fff8e1`.
+pub const AMBER_50: Color = Color::rgb(0xff, 0xf8, 0xe1);
+/// Material amber 100, `#ffecb3`.
+pub const AMBER_100: Color = Color::rgb(0xff, 0xec, 0xb3);
+/// Material amber 200, `#ffe082`.
+pub const AMBER_200: Color = Color::rgb(0xff, 0xe0, 0x82);
+/// Material amber 300, `#ffd54f`.
+pub const AMBER_300: Color = Color::rgb(0xff, 0xd5, 0x4f);
+/// Material amber 400, `#ffca28`.
+pub const AMBER_400: Color = Color::rgb(0xff, 0xca, 0x28);
+/// Material amber 500, `#ffc107`.
+pub const AMBER_500: Color = Color::rgb(0xff, 0xc1, 0x07);
+/// Material amber 600, `#ffb300`.
+pub const AMBER_600: Color = Color::rgb(0xff, 0xb3, 0x00);
+/// Material amber 700, `#ffa000`.
+pub const AMBER_700: Color = Color::rgb(0xff, 0xa0, 0x00);
+/// Material amber 800, `#ff8f00`.
+pub const AMBER_800: Color = Color::rgb(0xff, 0x8f, 0x00);
+/// Material amber 900, `#ff6f00`.
+pub const AMBER_900: Color = Color::rgb(0xff, 0x6f, 0x00);
+/// Material amber A100, `#ffe57f`.
+pub const AMBER_A100: Color = Color::rgb(0xff, 0xe5, 0x7f);
+/// Material amber A200, `#ffd740`.
+pub const AMBER_A200: Color = Color::rgb(0xff, 0xd7, 0x40);
+/// Material amber A400, `#ffc400`.
+pub const AMBER_A400: Color = Color::rgb(0xff, 0xc4, 0x00);
+/// Material amber A700, `#ffab00`.
+pub const AMBER_A700: Color = Color::rgb(0xff, 0xab, 0x00);
+/// Material orange 50, `#fff3e0`.
+pub const ORANGE_50: Color = Color::rgb(0xff, 0xf3, 0xe0);
+/// Material orange 100, `#ffe0b2`.
+pub const ORANGE_100: Color = Color::rgb(0xff, 0xe0, 0xb2);
+/// Material orange 200, `#ffcc80`.
+pub const ORANGE_200: Color = Color::rgb(0xff, 0xcc, 0x80);
+/// Material orange 300, `#ffb74d`.
+pub const ORANGE_300: Color = Color::rgb(0xff, 0xb7, 0x4d);
+/// Material orange 400, `#ffa726`.
+pub const ORANGE_400: Color = Color::rgb(0xff, 0xa7, 0x26);
+/// Material orange 500, `#ff9800`.
+pub const ORANGE_500: Color = Color::rgb(0xff, 0x98, 0x00);
+/// Material orange 600, `#fb8c00`.
+pub const ORANGE_600: Color = Color::rgb(0xfb, 0x8c, 0x00);
+/// Material orange 700, `#f57c00`.
+pub const ORANGE_700: Color = Color::rgb(0xf5, 0x7c, 0x00);
+/// Material orange 800, `#ef6c00`.
+pub const ORANGE_800: Color = Color::rgb(0xef, 0x6c, 0x00);
+/// Material orange 900, `#e65100`.
+pub const ORANGE_900: Color = Color::rgb(0xe6, 0x51, 0x00);
+/// Material orange A100, `#ffd180`.
+pub const ORANGE_A100: Color = Color::rgb(0xff, 0xd1, 0x80);
+/// Material orange A200, `#ffab40`.
+pub const ORANGE_A200: Color = Color::rgb(0xff, 0xab, 0x40);
+/// Material orange A400, `#ff9100`.
+pub const ORANGE_A400: Color = Color::rgb(0xff, 0x91, 0x00);
+/// Material orange A700, `#ff6d00`.
+pub const ORANGE_A700: Color = Color::rgb(0xff, 0x6d, 0x00);
+/// Material deep orange 50, `#fbe9e7`.
+pub const DEEP_ORANGE_50: Color = Color::rgb(0xfb, 0xe9, 0xe7);
+/// Material deep orange 100, `#ffccbc`.
+pub const DEEP_ORANGE_100: Color = Color::rgb(0xff, 0xcc, 0xbc);
+/// Material deep orange 200, `#ffab91`.
+pub const DEEP_ORANGE_200: Color = Color::rgb(0xff, 0xab, 0x91);
+/// Material deep orange 300, `#ff8a65`.
+pub const DEEP_ORANGE_300: Color = Color::rgb(0xff, 0x8a, 0x65);
+/// Material deep orange 400, `#ff7043`.
+pub const DEEP_ORANGE_400: Color = Color::rgb(0xff, 0x70, 0x43);
+/// Material deep orange 500, `#ff5722`.
+pub const DEEP_ORANGE_500: Color = Color::rgb(0xff, 0x57, 0x22);
+/// Material deep orange 600, `#f4511e`.
+pub const DEEP_ORANGE_600: Color = Color::rgb(0xf4, 0x51, 0x1e);
+/// Material deep orange 700, `#e64a19`.
+pub const DEEP_ORANGE_700: Color = Color::rgb(0xe6, 0x4a, 0x19);
+/// Material deep orange 800, `#d84315`.
+pub const DEEP_ORANGE_800: Color = Color::rgb(0xd8, 0x43, 0x15);
+/// Material deep orange 900, `#bf360c`.
+pub const DEEP_ORANGE_900: Color = Color::rgb(0xbf, 0x36, 0x0c);
+/// Material deep orange A100, `#ff9e80`.
+pub const DEEP_ORANGE_A100: Color = Color::rgb(0xff, 0x9e, 0x80);
+/// Material deep orange A200, `#ff6e40`.
+pub const DEEP_ORANGE_A200: Color = Color::rgb(0xff, 0x6e, 0x40);
+/// Material deep orange A400, `#ff3d00`.
+pub const DEEP_ORANGE_A400: Color = Color::rgb(0xff, 0x3d, 0x00);
+/// Material deep orange A700, `#dd2c00`.
+pub const DEEP_ORANGE_A700: Color = Color::rgb(0xdd, 0x2c, 0x00);
+/// Material brown 50, `#efebe9`.
+pub const BROWN_50: Color = Color::rgb(0xef, 0xeb, 0xe9);
+/// Material brown 100, `#d7ccc8`.
+pub const BROWN_100: Color = Color::rgb(0xd7, 0xcc, 0xc8);
+/// Material brown 200, `#bcaaa4`.
+pub const BROWN_200: Color = Color::rgb(0xbc, 0xaa, 0xa4);
+/// Material brown 300, `#a1887f`.
+pub const BROWN_300: Color = Color::rgb(0xa1, 0x88, 0x7f);
+/// Material brown 400, `#8d6e63`.
+pub const BROWN_400: Color = Color::rgb(0x8d, 0x6e, 0x63);
+/// Material brown 500, `#795548`.
+pub const BROWN_500: Color = Color::rgb(0x79, 0x55, 0x48);
+/// Material brown 600, `#6d4c41`.
+pub const BROWN_600: Color = Color::rgb(0x6d, 0x4c, 0x41);
+/// Material brown 700, `#5d4037`.
+pub const BROWN_700: Color = Color::rgb(0x5d, 0x40, 0x37);
+/// Material brown 800, `#4e342e`.
+pub const BROWN_800: Color = Color::rgb(0x4e, 0x34, 0x2e);
+/// Material brown 900, `#3e2723`.
+pub const BROWN_900: Color = Color::rgb(0x3e, 0x27, 0x23);
+/// Material grey 50, `#fafafa`.
+pub const GREY_50: Color = Color::rgb(0xfa, 0xfa, 0xfa);
+/// Material grey 100, `#f5f5f5`.
+pub const GREY_100: Color = Color::rgb(0xf5, 0xf5, 0xf5);
+/// Material grey 200, `#eeeeee`.
+pub const GREY_200: Color = Color::rgb(0xee, 0xee, 0xee);
+/// Material grey 300, `#e0e0e0`.
+pub const GREY_300: Color = Color::rgb(0xe0, 0xe0, 0xe0);
+/// Material grey 400, `#bdbdbd`.
+pub const GREY_400: Color = Color::rgb(0xbd, 0xbd, 0xbd);
+/// Material grey 500, `#9e9e9e`.
+pub const GREY_500: Color = Color::rgb(0x9e, 0x9e, 0x9e);
+/// Material grey 600, `#757575`.
+pub const GREY_600: Color = Color::rgb(0x75, 0x75, 0x75);
+/// Material grey 700, `#616161`.
+pub const GREY_700: Color = Color::rgb(0x61, 0x61, 0x61);
+/// Material grey 800, `#424242`.
+pub const GREY_800: Color = Color::rgb(0x42, 0x42, 0x42);
+/// Material grey 900, `#212121`.
+pub const GREY_900: Color = Color::rgb(0x21, 0x21, 0x21);
+/// Material blue grey 50, `#eceff1`.
+pub const BLUE_GREY_50: Color = Color::rgb(0xec, 0xef, 0xf1);
+/// Material blue grey 100, `#cfd8dc`.
+pub const BLUE_GREY_100: Color = Color::rgb(0xcf, 0xd8, 0xdc);
+/// Material blue grey 200, `#b0bec5`.
+pub const BLUE_GREY_200: Color = Color::rgb(0xb0, 0xbe, 0xc5);
+/// Material blue grey 300, `#90a4ae`.
+pub const BLUE_GREY_300: Color = Color::rgb(0x90, 0xa4, 0xae);
+/// Material blue grey 400, `#78909c`.
+pub const BLUE_GREY_400: Color = Color::rgb(0x78, 0x90, 0x9c);
+/// Material blue grey 500, `#607d8b`.
+pub const BLUE_GREY_500: Color = Color::rgb(0x60, 0x7d, 0x8b);
+/// Material blue grey 600, `#546e7a`.
+pub const BLUE_GREY_600: Color = Color::rgb(0x54, 0x6e, 0x7a);
+/// Material blue grey 700, `#455a64`.
+pub const BLUE_GREY_700: Color = Color::rgb(0x45, 0x5a, 0x64);
+/// Material blue grey 800, `#37474f`.
+pub const BLUE_GREY_800: Color = Color::rgb(0x37, 0x47, 0x4f);
+/// Material blue grey 900, `#263238`.
+pub const BLUE_GREY_900: Color = Color::rgb(0x26, 0x32, 0x38);
+
+/// Every Material scale, lightest shade first, followed by its accents.
+pub const PALETTE: Palette = Palette::new(
+    "Material",
+    None,
+    &[
+        ("red_50", RED_50),
+        ("red_100", RED_100),
+        ("red_200", RED_200),
+        ("red_300", RED_300),
+        ("red_400", RED_400),
+        ("red_500", RED_500),
+        ("red_600", RED_600),
+        ("red_700", RED_700),
+        ("red_800", RED_800),
+        ("red_900", RED_900),
+        ("red_a100", RED_A100),
+        ("red_a200", RED_A200),
+        ("red_a400", RED_A400),
+        ("red_a700", RED_A700),
+        ("pink_50", PINK_50),
+        ("pink_100", PINK_100),
+        ("pink_200", PINK_200),
+        ("pink_300", PINK_300),
+        ("pink_400", PINK_400),
+        ("pink_500", PINK_500),
+        ("pink_600", PINK_600),
+        ("pink_700", PINK_700),
+        ("pink_800", PINK_800),
+        ("pink_900", PINK_900),
+        ("pink_a100", PINK_A100),
+        ("pink_a200", PINK_A200),
+        ("pink_a400", PINK_A400),
+        ("pink_a700", PINK_A700),
+        ("purple_50", PURPLE_50),
+        ("purple_100", PURPLE_100),
+        ("purple_200", PURPLE_200),
+        ("purple_300", PURPLE_300),
+        ("purple_400", PURPLE_400),
+        ("purple_500", PURPLE_500),
+        ("purple_600", PURPLE_600),
+        ("purple_700", PURPLE_700),
+        ("purple_800", PURPLE_800),
+        ("purple_900", PURPLE_900),
+        ("purple_a100", PURPLE_A100),
+        ("purple_a200", PURPLE_A200),
+        ("purple_a400", PURPLE_A400),
+        ("purple_a700", PURPLE_A700),
+        ("deep_purple_50", DEEP_PURPLE_50),
+        ("deep_purple_100", DEEP_PURPLE_100),
+        ("deep_purple_200", DEEP_PURPLE_200),
+        ("deep_purple_300", DEEP_PURPLE_300),
+        ("deep_purple_400", DEEP_PURPLE_400),
+        ("deep_purple_500", DEEP_PURPLE_500),
+        ("deep_purple_600", DEEP_PURPLE_600),
+        ("deep_purple_700", DEEP_PURPLE_700),
+        ("deep_purple_800", DEEP_PURPLE_800),
+        ("deep_purple_900", DEEP_PURPLE_900),
+        ("deep_purple_a100", DEEP_PURPLE_A100),
+        ("deep_purple_a200", DEEP_PURPLE_A200),
+        ("deep_purple_a400", DEEP_PURPLE_A400),
+        ("deep_purple_a700", DEEP_PURPLE_A700),
+        ("indigo_50", INDIGO_50),
+        ("indigo_100", INDIGO_100),
+        ("indigo_200", INDIGO_200),
+        ("indigo_300", INDIGO_300),
+        ("indigo_400", INDIGO_400),
+        ("indigo_500", INDIGO_500),
+        ("indigo_600", INDIGO_600),
+        ("indigo_700", INDIGO_700),
+        ("indigo_800", INDIGO_800),
+        ("indigo_900", INDIGO_900),
+        ("indigo_a100", INDIGO_A100),
+        ("indigo_a200", INDIGO_A200),
+        ("indigo_a400", INDIGO_A400),
+        ("indigo_a700", INDIGO_A700),
+        ("blue_50", BLUE_50),
+        ("blue_100", BLUE_100),
+        ("blue_200", BLUE_200),
+        ("blue_300", BLUE_300),
+        ("blue_400", BLUE_400),
+        ("blue_500", BLUE_500),
+        ("blue_600", BLUE_600),
+        ("blue_700", BLUE_700),
+        ("blue_800", BLUE_800),
+        ("blue_900", BLUE_900),
+        ("blue_a100", BLUE_A100),
+        ("blue_a200", BLUE_A200),
+        ("blue_a400", BLUE_A400),
+        ("blue_a700", BLUE_A700),
+        ("light_blue_50", LIGHT_BLUE_50),
+        ("light_blue_100", LIGHT_BLUE_100),
+        ("light_blue_200", LIGHT_BLUE_200),
+        ("light_blue_300", LIGHT_BLUE_300),
+        ("light_blue_400", LIGHT_BLUE_400),
+        ("light_blue_500", LIGHT_BLUE_500),
+        ("light_blue_600", LIGHT_BLUE_600),
+        ("light_blue_700", LIGHT_BLUE_700),
+        ("light_blue_800", LIGHT_BLUE_800),
+        ("light_blue_900", LIGHT_BLUE_900),
+        ("light_blue_a100", LIGHT_BLUE_A100),
+        ("light_blue_a200", LIGHT_BLUE_A200),
+        ("light_blue_a400", LIGHT_BLUE_A400),
+        ("light_blue_a700", LIGHT_BLUE_A700),
+        ("cyan_50", CYAN_50),
+        ("cyan_100", CYAN_100),
+        ("cyan_200", CYAN_200),
+        ("cyan_300", CYAN_300),
+        ("cyan_400", CYAN_400),
+        ("cyan_500", CYAN_500),
+        ("cyan_600", CYAN_600),
+        ("cyan_700", CYAN_700),
+        ("cyan_800", CYAN_800),
+        ("cyan_900", CYAN_900),
+        ("cyan_a100", CYAN_A100),
+        ("cyan_a200", CYAN_A200),
+        ("cyan_a400", CYAN_A400),
+        ("cyan_a700", CYAN_A700),
+        ("teal_50", TEAL_50),
+        ("teal_100", TEAL_100),
+        ("teal_200", TEAL_200),
+        ("teal_300", TEAL_300),
+        ("teal_400", TEAL_400),
+        ("teal_500", TEAL_500),
+        ("teal_600", TEAL_600),
+        ("teal_700", TEAL_700),
+        ("teal_800", TEAL_800),
+        ("teal_900", TEAL_900),
+        ("teal_a100", TEAL_A100),
+        ("teal_a200", TEAL_A200),
+        ("teal_a400", TEAL_A400),
+        ("teal_a700", TEAL_A700),
+        ("green_50", GREEN_50),
+        ("green_100", GREEN_100),
+        ("green_200", GREEN_200),
+        ("green_300", GREEN_300),
+        ("green_400", GREEN_400),
+        ("green_500", GREEN_500),
+        ("green_600", GREEN_600),
+        ("green_700", GREEN_700),
+        ("green_800", GREEN_800),
+        ("green_900", GREEN_900),
+        ("green_a100", GREEN_A100),
+        ("green_a200", GREEN_A200),
+        ("green_a400", GREEN_A400),
+        ("green_a700", GREEN_A700),
+        ("light_green_50", LIGHT_GREEN_50),
+        ("light_green_100", LIGHT_GREEN_100),
+        ("light_green_200", LIGHT_GREEN_200),
+        ("light_green_300", LIGHT_GREEN_300),
+        ("light_green_400", LIGHT_GREEN_400),
+        ("light_green_500", LIGHT_GREEN_500),
+        ("light_green_600", LIGHT_GREEN_600),
+        ("light_green_700", LIGHT_GREEN_700),
+        ("light_green_800", LIGHT_GREEN_800),
+        ("light_green_900", LIGHT_GREEN_900),
+        ("light_green_a100", LIGHT_GREEN_A100),
+        ("light_green_a200", LIGHT_GREEN_A200),
+        ("light_green_a400", LIGHT_GREEN_A400),
+        ("light_green_a700", LIGHT_GREEN_A700),
+        ("lime_50", LIME_50),
+        ("lime_100", LIME_100),
+        ("lime_200", LIME_200),
+        ("lime_300", LIME_300),
+        ("lime_400", LIME_400),
+        ("lime_500", LIME_500),
+        ("lime_600", LIME_600),
+        ("lime_700", LIME_700),
+        ("lime_800", LIME_800),
+        ("lime_900", LIME_900),
+        ("lime_a100", LIME_A100),
+        ("lime_a200", LIME_A200),
+        ("lime_a400", LIME_A400),
+        ("lime_a700", LIME_A700),
+        ("yellow_50", YELLOW_50),
+        ("yellow_100", YELLOW_100),
+        ("yellow_200", YELLOW_200),
+        ("yellow_300", YELLOW_300),
+        ("yellow_400", YELLOW_400),
+        ("yellow_500", YELLOW_500),
+        ("yellow_600", YELLOW_600),
+        ("yellow_700", YELLOW_700),
+        ("yellow_800", YELLOW_800),
+        ("yellow_900", YELLOW_900),
+        ("yellow_a100", YELLOW_A100),
+        ("yellow_a200", YELLOW_A200),
+        ("yellow_a400", YELLOW_A400),
+        ("yellow_a700", YELLOW_A700),
+        ("amber_50", AMBER_50),
+        ("amber_100", AMBER_100),
+        ("amber_200", AMBER_200),
+        ("amber_300", AMBER_300),
+        ("amber_400", AMBER_400),
+        ("amber_500", AMBER_500),
+        ("amber_600", AMBER_600),
+        ("amber_700", AMBER_700),
+        ("amber_800", AMBER_800),
+        ("amber_900", AMBER_900),
+        ("amber_a100", AMBER_A100),
+        ("amber_a200", AMBER_A200),
+        ("amber_a400", AMBER_A400),
+        ("amber_a700", AMBER_A700),
+        ("orange_50", ORANGE_50),
+        ("orange_100", ORANGE_100),
+        ("orange_200", ORANGE_200),
+        ("orange_300", ORANGE_300),
+        ("orange_400", ORANGE_400),
+        ("orange_500", ORANGE_500),
+        ("orange_600", ORANGE_600),
+        ("orange_700", ORANGE_700),
+        ("orange_800", ORANGE_800),
+        ("orange_900", ORANGE_900),
+        ("orange_a100", ORANGE_A100),
+        ("orange_a200", ORANGE_A200),
+        ("orange_a400", ORANGE_A400),
+        ("orange_a700", ORANGE_A700),
+        ("deep_orange_50", DEEP_ORANGE_50),
+        ("deep_orange_100", DEEP_ORANGE_100),
+        ("deep_orange_200", DEEP_ORANGE_200),
+        ("deep_orange_300", DEEP_ORANGE_300),
+        ("deep_orange_400", DEEP_ORANGE_400),
+        ("deep_orange_500", DEEP_ORANGE_500),
+        ("deep_orange_600", DEEP_ORANGE_600),
+        ("deep_orange_700", DEEP_ORANGE_700),
+        ("deep_orange_800", DEEP_ORANGE_800),
+        ("deep_orange_900", DEEP_ORANGE_900),
+        ("deep_orange_a100", DEEP_ORANGE_A100),
+        ("deep_orange_a200", DEEP_ORANGE_A200),
+        ("deep_orange_a400", DEEP_ORANGE_A400),
+        ("deep_orange_a700", DEEP_ORANGE_A700),
+        ("brown_50", BROWN_50),
+        ("brown_100", BROWN_100),
+        ("brown_200", BROWN_200),
+        ("brown_300", BROWN_300),
+        ("brown_400", BROWN_400),
+        ("brown_500", BROWN_500),
+        ("brown_600", BROWN_600),
+        ("brown_700", BROWN_700),
+        ("brown_800", BROWN_800),
+        ("brown_900", BROWN_900),
+        ("grey_50", GREY_50),
+        ("grey_100", GREY_100),
+        ("grey_200", GREY_200),
+        ("grey_300", GREY_300),
+        ("grey_400", GREY_400),
+        ("grey_500", GREY_500),
+        ("grey_600", GREY_600),
+        ("grey_700", GREY_700),
+        ("grey_800", GREY_800),
+        ("grey_900", GREY_900),
+        ("blue_grey_50", BLUE_GREY_50),
+        ("blue_grey_100", BLUE_GREY_100),
+        ("blue_grey_200", BLUE_GREY_200),
+        ("blue_grey_300", BLUE_GREY_300),
+        ("blue_grey_400", BLUE_GREY_400),
+        ("blue_grey_500", BLUE_GREY_500),
+        ("blue_grey_600", BLUE_GREY_600),
+        ("blue_grey_700", BLUE_GREY_700),
+        ("blue_grey_800", BLUE_GREY_800),
+        ("blue_grey_900", BLUE_GREY_900),
+    ],
+);
@@ -0,0 +1,61 @@
+//! The [Nord](https://www.nordtheme.com) palette.
+
+use super::Palette;
+use crate::{Background, Color};
+
+/// Nord polar night 1 (nord0), `#2e3440`.
+pub const POLAR_NIGHT_1: Color = Color::rgb(0x2e, 0x34, 0x40);
+/// Nord polar night 2 (nord1), `#3b4252`.
+pub const POLAR_NIGHT_2: Color = Color::rgb(0x3b, 0x42, 0x52);
+/// Nord polar night 3 (nord2), `#434c5e`.
+pub const POLAR_NIGHT_3: Color = Color::rgb(0x43, 0x4c, 0x5e);
+/// Nord polar night 4 (nord3), `#4c566a`.
+pub const POLAR_NIGHT_4: Color = Color::rgb(0x4c, 0x56, 0x6a);
+/// Nord snow storm 1 (nord4), `#d8dee9`.
+pub const SNOW_STORM_1: Color = Color::rgb(0xd8, 0xde, 0xe9);
+/// Nord snow storm 2 (nord5), `#e5e9f0`.
+pub const SNOW_STORM_2: Color = Color::rgb(0xe5, 0xe9, 0xf0);
+/// Nord snow storm 3 (nord6), `#eceff4`.
+pub const SNOW_STORM_3: Color = Color::rgb(0xec, 0xef, 0xf4);
+/// Nord frost 1 (nord7), `#8fbcbb`.
+pub const FROST_1: Color = Color::rgb(0x8f, 0xbc, 0xbb);
+/// Nord frost 2 (nord8), `#88c0d0`.
+pub const FROST_2: Color = Color::rgb(0x88, 0xc0, 0xd0);
+/// Nord frost 3 (nord9), `#81a1c1`.
+pub const FROST_3: Color = Color::rgb(0x81, 0xa1, 0xc1);
+/// Nord frost 4 (nord10), `#5e81ac`.
+pub const FROST_4: Color = Color::rgb(0x5e, 0x81, 0xac);
+/// Nord aurora red (nord11), `#bf616a`.
+pub const AURORA_RED: Color = Color::rgb(0xbf, 0x61, 0x6a);
+/// Nord aurora orange (nord12), `#d08770`.
+pub const AURORA_ORANGE: Color = Color::rgb(0xd0, 0x87, 0x70);
+/// Nord aurora yellow (nord13), `#ebcb8b`.
+pub const AURORA_YELLOW: Color = Color::rgb(0xeb, 0xcb, 0x8b);
+/// Nord aurora green (nord14), `#a3be8c`.
+pub const AURORA_GREEN: Color = Color::rgb(0xa3, 0xbe, 0x8c);
+/// Nord aurora purple (nord15), `#b48ead`.
+pub const AURORA_PURPLE: Color = Color::rgb(0xb4, 0x8e, 0xad);
+
+/// The full Nord palette.
+pub const PALETTE: Palette = Palette::new(
+    "Nord",
+    Some(Background::Dark),
+    &[
+        ("polar_night_1", POLAR_NIGHT_1),
+        ("polar_night_2", POLAR_NIGHT_2),
+        ("polar_night_3", POLAR_NIGHT_3),
+        ("polar_night_4", POLAR_NIGHT_4),
+        ("snow_storm_1", SNOW_STORM_1),
+        ("snow_storm_2", SNOW_STORM_2),
+        ("snow_storm_3", SNOW_STORM_3),
+        ("frost_1", FROST_1),
+        ("frost_2", FROST_2),
+        ("frost_3", FROST_3),
+        ("frost_4", FROST_4),
+        ("aurora_red", AURORA_RED),
+        ("aurora_orange", AURORA_ORANGE),
+        ("aurora_yellow", AURORA_YELLOW),
+        ("aurora_green", AURORA_GREEN),
+        ("aurora_purple", AURORA_PURPLE),
+    ],
+);
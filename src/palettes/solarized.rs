@@ -0,0 +1,88 @@
+//! The [Solarized](https://ethanschoonover.com/solarized/) palette.
+//!
+//! Both variants share the same colors; the light variant swaps the roles of
+//! the `BASE0*` and `BASE*` tones.
+
+use super::Palette;
+use crate::{Background, Color};
+
+/// Solarized base03, `#002b36`.
+pub const BASE03: Color = Color::rgb(0x00, 0x2b, 0x36);
+/// Solarized base02, `#073642`.
+pub const BASE02: Color = Color::rgb(0x07, 0x36, 0x42);
+/// Solarized base01, `#586e75`.
+pub const BASE01: Color = Color::rgb(0x58, 0x6e, 0x75);
+/// Solarized base00, `#657b83`.
+pub const BASE00: Color = Color::rgb(0x65, 0x7b, 0x83);
+/// Solarized base0, `#839496`.
+pub const BASE0: Color = Color::rgb(0x83, 0x94, 0x96);
+/// Solarized base1, `#93a1a1`.
+pub const BASE1: Color = Color::rgb(0x93, 0xa1, 0xa1);
+/// Solarized base2, `#eee8d5`.
+pub const BASE2: Color = Color::rgb(0xee, 0xe8, 0xd5);
+/// Solarized base3, `#fdf6e3`.
+pub const BASE3: Color = Color::rgb(0xfd, 0xf6, 0xe3);
+/// Solarized yellow, `#b58900`.
+pub const YELLOW: Color = Color::rgb(0xb5, 0x89, 0x00);
+/// Solarized orange, `#cb4b16`.
+pub const ORANGE: Color = Color::rgb(0xcb, 0x4b, 0x16);
+/// Solarized red, `#dc322f`.
+pub const RED: Color = Color::rgb(0xdc, 0x32, 0x2f);
+/// Solarized magenta, `#d33682`.
+pub const MAGENTA: Color = Color::rgb(0xd3, 0x36, 0x82);
+/// Solarized violet, `#6c71c4`.
+pub const VIOLET: Color = Color::rgb(0x6c, 0x71, 0xc4);
+/// Solarized blue, `#268bd2`.
+pub const BLUE: Color = Color::rgb(0x26, 0x8b, 0xd2);
+/// Solarized cyan, `#2aa198`.
+pub const CYAN: Color = Color::rgb(0x2a, 0xa1, 0x98);
+/// Solarized green, `#859900`.
+pub const GREEN: Color = Color::rgb(0x85, 0x99, 0x00);
+
+/// Solarized on a dark background.
+pub const DARK: Palette = Palette::new(
+    "Solarized Dark",
+    Some(Background::Dark),
+    &[
+        ("base03", BASE03),
+        ("base02", BASE02),
+        ("base01", BASE01),
+        ("base00", BASE00),
+        ("base0", BASE0),
+        ("base1", BASE1),
+        ("base2", BASE2),
+        ("base3", BASE3),
+        ("yellow", YELLOW),
+        ("orange", ORANGE),
+        ("red", RED),
+        ("magenta", MAGENTA),
+        ("violet", VIOLET),
+        ("blue", BLUE),
+        ("cyan", CYAN),
+        ("green", GREEN),
+    ],
+);
+
+/// Solarized on a light background.
+pub const LIGHT: Palette = Palette::new(
+    "Solarized Light",
+    Some(Background::Light),
+    &[
+        ("base03", BASE03),
+        ("base02", BASE02),
+        ("base01", BASE01),
+        ("base00", BASE00),
+        ("base0", BASE0),
+        ("base1", BASE1),
+        ("base2", BASE2),
+        ("base3", BASE3),
+        ("yellow", YELLOW),
+        ("orange", ORANGE),
+        ("red", RED),
+        ("magenta", MAGENTA),
+        ("violet", VIOLET),
+        ("blue", BLUE),
+        ("cyan", CYAN),
+        ("green", GREEN),
+    ],
+);
@@ -0,0 +1,739 @@
+//! The [Tailwind CSS](https://tailwindcss.com/docs/colors) color scales.
+
+use super::Palette;
+use crate::Color;
+
+/// Tailwind slate 50, `#f8fafc`.
+pub const SLATE_50: Color = Color::rgb(0xf8, 0xfa, 0xfc);
+/// Tailwind slate 100, `#f1f5f9`.
+pub const SLATE_100: Color = Color::rgb(0xf1, 0xf5, 0xf9);
+/// Tailwind slate 200, `#e2e8f0`.
+pub const SLATE_200: Color = Color::rgb(0xe2, 0xe8, 0xf0);
+/// Tailwind slate 300, `#cbd5e1`.
+pub const SLATE_300: Color = Color::rgb(0xcb, 0xd5, 0xe1);
+/// Tailwind slate 400, `#94a3b8`.
+pub const SLATE_400: Color = Color::rgb(0x94, 0xa3, 0xb8);
+/// Tailwind slate 500, `#64748b`.
+pub const SLATE_500: Color = Color::rgb(0x64, 0x74, 0x8b);
+/// Tailwind slate 600, `#475569`.
+pub const SLATE_600: Color = Color::rgb(0x47, 0x55, 0x69);
+/// Tailwind slate 700, `#334155`.
+pub const SLATE_700: Color = Color::rgb(0x33, 0x41, 0x55);
+/// Tailwind slate 800, `#1e293b`.
+pub const SLATE_800: Color = Color::rgb(0x1e, 0x29, 0x3b);
+/// Tailwind slate 900, `#0f172a`.
+pub const SLATE_900: Color = Color::rgb(0x0f, 0x17, 0x2a);
+/// Tailwind slate 950, `#020617`.
+pub const SLATE_950: Color = Color::rgb(0x02, 0x06, 0x17);
+/// Tailwind gray 50, `#f9fafb`.
+pub const GRAY_50: Color = Color::rgb(0xf9, 0xfa, 0xfb);
+/// Tailwind gray 100, `#f3f4f6`.
+pub const GRAY_100: Color = Color::rgb(0xf3, 0xf4, 0xf6);
+/// Tailwind gray 200, `#e5e7eb`.
+pub const GRAY_200: Color = Color::rgb(0xe5, 0xe7, 0xeb);
+/// Tailwind gray 300, `#d1d5db`.
+pub const GRAY_300: Color = Color::rgb(0xd1, 0xd5, 0xdb);
+/// Tailwind gray 400, `#9ca3af`.
+pub const GRAY_400: Color = Color::rgb(0x9c, 0xa3, 0xaf);
+/// Tailwind gray 500, `#6b7280`.
+pub const GRAY_500: Color = Color::rgb(0x6b, 0x72, 0x80);
+/// Tailwind gray 600, `#4b5563`.
+pub const GRAY_600: Color = Color::rgb(0x4b, 0x55, 0x63);
+/// Tailwind gray 700, `#374151`.
+pub const GRAY_700: Color = Color::rgb(0x37, 0x41, 0x51);
+/// Tailwind gray 800, `#1f2937`.
+pub const GRAY_800: Color = Color::rgb(0x1f, 0x29, 0x37);
+/// Tailwind gray 900, `#111827`.
+pub const GRAY_900: Color = Color::rgb(0x11, 0x18, 0x27);
+/// Tailwind gray 950, `#030712`.
+pub const GRAY_950: Color = Color::rgb(0x03, 0x07, 0x12);
+/// Tailwind zinc 50, `#fafafa`.
+pub const ZINC_50: Color = Color::rgb(0xfa, 0xfa, 0xfa);
+/// Tailwind zinc 100, `#f4f4f5`.
+pub const ZINC_100: Color = Color::rgb(0xf4, 0xf4, 0xf5);
+/// Tailwind zinc 200, `#e4e4e7`.
+pub const ZINC_200: Color = Color::rgb(0xe4, 0xe4, 0xe7);
+/// Tailwind zinc 300, `#d4d4d8`.
+pub const ZINC_300: Color = Color::rgb(0xd4, 0xd4, 0xd8);
+/// Tailwind zinc 400, `#a1a1aa`.
+pub const ZINC_400: Color = Color::rgb(0xa1, 0xa1, 0xaa);
+/// Tailwind zinc 500, `#71717a`.
+pub const ZINC_500: Color = Color::rgb(0x71, 0x71, 0x7a);
+/// Tailwind zinc 600, `#52525b`.
+pub const ZINC_600: Color = Color::rgb(0x52, 0x52, 0x5b);
+/// Tailwind zinc 700, `#3f3f46`.
+pub const ZINC_700: Color = Color::rgb(0x3f, 0x3f, 0x46);
+/// Tailwind zinc 800, `#27272a`.
+pub const ZINC_800: Color = Color::rgb(0x27, 0x27, 0x2a);
+/// Tailwind zinc 900, `#18181b`.
+pub const ZINC_900: Color = Color::rgb(0x18, 0x18, 0x1b);
+/// Tailwind zinc 950, `#09090b`.
+pub const ZINC_950: Color = Color::rgb(0x09, 0x09, 0x0b);
+/// Tailwind neutral 50, `#fafafa`.
+pub const NEUTRAL_50: Color = Color::rgb(0xfa, 0xfa, 0xfa);
+/// Tailwind neutral 100, `#f5f5f5`.
+pub const NEUTRAL_100: Color = Color::rgb(0xf5, 0xf5, 0xf5);
+/// Tailwind neutral 200, `#e5e5e5`.
+pub const NEUTRAL_200: Color = Color::rgb(0xe5, 0xe5, 0xe5);
+/// Tailwind neutral 300, `#d4d4d4`.
+pub const NEUTRAL_300: Color = Color::rgb(0xd4, 0xd4, 0xd4);
+/// Tailwind neutral 400, `#a3a3a3`.
+pub const NEUTRAL_400: Color = Color::rgb(0xa3, 0xa3, 0xa3);
+/// Tailwind neutral 500, `#737373`.
+pub const NEUTRAL_500: Color = Color::rgb(0x73, 0x73, 0x73);
+/// Tailwind neutral 600, `#525252`.
+pub const NEUTRAL_600: Color = Color::rgb(0x52, 0x52, 0x52);
+/// Tailwind neutral 700, `#404040`.
+pub const NEUTRAL_700: Color = Color::rgb(0x40, 0x40, 0x40);
+/// Tailwind neutral 800, `#262626`.
+pub const NEUTRAL_800: Color = Color::rgb(0x26, 0x26, 0x26);
+/// Tailwind neutral 900, `#171717`.
+pub const NEUTRAL_900: Color = Color::rgb(0x17, 0x17, 0x17);
+/// Tailwind neutral 950, `#0a0a0a`.
+pub const NEUTRAL_950: Color = Color::rgb(0x0a, 0x0a, 0x0a);
+/// Tailwind stone 50, `#fafaf9`.
+pub const STONE_50: Color = Color::rgb(0xfa, 0xfa, 0xf9);
+/// Tailwind stone 100, `#f5f5f4`.
+pub const STONE_100: Color = Color::rgb(0xf5, 0xf5, 0xf4);
+/// Tailwind stone 200, `#e7e5e4`.
+pub const STONE_200: Color = Color::rgb(0xe7, 0xe5, 0xe4);
+/// Tailwind stone 300, `#d6d3d1`.
+pub const STONE_300: Color = Color::rgb(0xd6, 0xd3, 0xd1);
+/// Tailwind stone 400, `#a8a29e`.
+pub const STONE_400: Color = Color::rgb(0xa8, 0xa2, 0x9e);
+/// Tailwind stone 500, `#78716c`.
+pub const STONE_500: Color = Color::rgb(0x78, 0x71, 0x6c);
+/// Tailwind stone 600, `#57534e`.
+pub const STONE_600: Color = Color::rgb(0x57, 0x53, 0x4e);
+/// Tailwind stone 700, `#44403c`.
+pub const STONE_700: Color = Color::rgb(0x44, 0x40, 0x3c);
+/// Tailwind stone 800, `#292524`.
+pub const STONE_800: Color = Color::rgb(0x29, 0x25, 0x24);
+/// Tailwind stone 900, `#1c1917`.
+pub const STONE_900: Color = Color::rgb(0x1c, 0x19, 0x17);
+/// Tailwind stone 950, `#0c0a09`.
+pub const STONE_950: Color = Color::rgb(0x0c, 0x0a, 0x09);
+/// Tailwind red 50, `#fef2f2`.
+pub const RED_50: Color = Color::rgb(0xfe, 0xf2, 0xf2);
+/// Tailwind red 100, `#fee2e2`.
+pub const RED_100: Color = Color::rgb(0xfe, 0xe2, 0xe2);
+/// Tailwind red 200, `#fecaca`.
+pub const RED_200: Color = Color::rgb(0xfe, 0xca, 0xca);
+/// Tailwind red 300, `#fca5a5`.
+pub const RED_300: Color = Color::rgb(0xfc, 0xa5, 0xa5);
+/// Tailwind red 400, `#f87171`.
+pub const RED_400: Color = Color::rgb(0xf8, 0x71, 0x71);
+/// Tailwind red 500, `#ef4444`.
+pub const RED_500: Color = Color::rgb(0xef, 0x44, 0x44);
+/// Tailwind red 600, `#dc2626`.
+pub const RED_600: Color = Color::rgb(0xdc, 0x26, 0x26);
+/// Tailwind red 700, `#b91c1c`.
+pub const RED_700: Color = Color::rgb(0xb9, 0x1c, 0x1c);
+/// Tailwind red 800, `#991b1b`.
+pub const RED_800: Color = Color::rgb(0x99, 0x1b, 0x1b);
+/// Tailwind red 900, `#7f1d1d`.
+pub const RED_900: Color = Color::rgb(0x7f, 0x1d, 0x1d);
+/// Tailwind red 950, `#450a0a`.
+pub const RED_950: Color = Color::rgb(0x45, 0x0a, 0x0a);
+/// Tailwind orange 50, `#fff7ed`.
+pub const ORANGE_50: Color = Color::rgb(0xff, 0xf7, 0xed);
+/// Tailwind orange 100, `#ffedd5`.
+pub const ORANGE_100: Color = Color::rgb(0xff, 0xed, 0xd5);
+/// Tailwind orange 200, `#fed7aa`.
+pub const ORANGE_200: Color = Color::rgb(0xfe, 0xd7, 0xaa);
+/// Tailwind orange 300, `#fdba74`.
+pub const ORANGE_300: Color = Color::rgb(0xfd, 0xba, 0x74);
+/// Tailwind orange 400, `#fb923c`.
+pub const ORANGE_400: Color = Color::rgb(0xfb, 0x92, 0x3c);
+/// Tailwind orange 500, `#f97316`.
+pub const ORANGE_500: Color = Color::rgb(0xf9, 0x73, 0x16);
+/// Tailwind orange 600, `#ea580c`.
+pub const ORANGE_600: Color = Color::rgb(0xea, 0x58, 0x0c);
+/// Tailwind orange 700, `#c2410c`.
+pub const ORANGE_700: Color = Color::rgb(0xc2, 0x41, 0x0c);
+/// Tailwind orange 800, `#9a3412`.
+pub const ORANGE_800: Color = Color::rgb(0x9a, 0x34, 0x12);
+/// Tailwind orange 900, `#7c2d12`.
+pub const ORANGE_900: Color = Color::rgb(0x7c, 0x2d, 0x12);
+/// Tailwind orange 950, `#431407`.
+pub const ORANGE_950: Color = Color::rgb(0x43, 0x14, 0x07);
+/// Tailwind amber 50, `#fffbeb`.
+pub const AMBER_50: Color = Color::rgb(0xff, 0xfb, 0xeb);
+/// Tailwind amber 100, `#fef3c7`.
+pub const AMBER_100: Color = Color::rgb(0xfe, 0xf3, 0xc7);
+/// Tailwind amber 200, `#fde68a`.
+pub const AMBER_200: Color = Color::rgb(0xfd, 0xe6, 0x8a);
+/// Tailwind amber 300, `#fcd34d`.
+pub const AMBER_300: Color = Color::rgb(0xfc, 0xd3, 0x4d);
+/// Tailwind amber 400, `#fbbf24`.
+pub const AMBER_400: Color = Color::rgb(0xfb, 0xbf, 0x24);
+/// Tailwind amber 500, `#f59e0b`.
+pub const AMBER_500: Color = Color::rgb(0xf5, 0x9e, 0x0b);
+/// Tailwind amber 600, `#d97706`.
+pub const AMBER_600: Color = Color::rgb(0xd9, 0x77, 0x06);
+/// Tailwind amber 700, `#b45309`.
+pub const AMBER_700: Color = Color::rgb(0xb4, 0x53, 0x09);
+/// Tailwind amber 800, `#92400e`.
+pub const AMBER_800: Color = Color::rgb(0x92, 0x40, 0x0e);
+/// Tailwind amber 900, `#78350f`.
+pub const AMBER_900: Color = Color::rgb(0x78, 0x35, 0x0f);
+/// Tailwind amber 950, `#451a03`.
+pub const AMBER_950: Color = Color::rgb(0x45, 0x1a, 0x03);
+/// Tailwind yellow 50, `#fefce8`.
+pub const YELLOW_50: Color = Color::rgb(0xfe, 0xfc, 0xe8);
+/// Tailwind yellow 100, `#fef9c3`.
+pub const YELLOW_100: Color = Color::rgb(0xfe, 0xf9, 0xc3);
+/// Tailwind yellow 200, `#fef08a`.
+pub const YELLOW_200: Color = Color::rgb(0xfe, 0xf0, 0x8a);
+/// Tailwind yellow 300, `#fde047`.
+pub const YELLOW_300: Color = Color::rgb(0xfd, 0xe0, 0x47);
+/// Tailwind yellow 400, `#facc15`.
+pub const YELLOW_400: Color = Color::rgb(0xfa, 0xcc, 0x15);
+/// Tailwind yellow 500, `#eab308`.
+pub const YELLOW_500: Color = Color::rgb(0xea, 0xb3, 0x08);
+/// Tailwind yellow 600, `#ca8a04`.
+pub const YELLOW_600: Color = Color::rgb(0xca, 0x8a, 0x04);
+/// Tailwind yellow 700, `#a16207`.
+pub const YELLOW_700: Color = Color::rgb(0xa1, 0x62, 0x07);
+/// Tailwind yellow 800, `#854d0e`.
+pub const YELLOW_800: Color = Color::rgb(0x85, 0x4d, 0x0e);
+/// Tailwind yellow 900, `#713f12`.
+pub const YELLOW_900: Color = Color::rgb(0x71, 0x3f, 0x12);
+/// Tailwind yellow 950, `#422006`.
+pub const YELLOW_950: Color = Color::rgb(0x42, 0x20, 0x06);
+/// Tailwind lime 50, `#f7fee7`.
+pub const LIME_50: Color = Color::rgb(0xf7, 0xfe, 0xe7);
+/// Tailwind lime 100, `#ecfccb`.
+pub const LIME_100: Color = Color::rgb(0xec, 0xfc, 0xcb);
+/// Tailwind lime 200, `#d9f99d`.
+pub const LIME_200: Color = Color::rgb(0xd9, 0xf9, 0x9d);
+/// Tailwind lime 300, `#bef264`.
+pub const LIME_300: Color = Color::rgb(0xbe, 0xf2, 0x64);
+/// Tailwind lime 400, `#a3e635`.
+pub const LIME_400: Color = Color::rgb(0xa3, 0xe6, 0x35);
+/// Tailwind lime 500, `#84cc16`.
+pub const LIME_500: Color = Color::rgb(0x84, 0xcc, 0x16);
+/// Tailwind lime 600, `#65a30d`.
+pub const LIME_600: Color = Color::rgb(0x65, 0xa3, 0x0d);
+/// Tailwind lime 700, `#4d7c0f`.
+pub const LIME_700: Color = Color::rgb(0x4d, 0x7c, 0x0f);
+/// Tailwind lime 800, `#3f6212`.
+pub const LIME_800: Color = Color::rgb(0x3f, 0x62, 0x12);
+/// Tailwind lime 900, `#365314`.
+pub const LIME_900: Color = Color::rgb(0x36, 0x53, 0x14);
+/// Tailwind lime 950, `#1a2e05`.
+pub const LIME_950: Color = Color::rgb(0x1a, 0x2e, 0x05);
+/// Tailwind green 50, `#f0fdf4`.
+pub const GREEN_50: Color = Color::rgb(0xf0, 0xfd, 0xf4);
+/// Tailwind green 100, `#dcfce7`.
+pub const GREEN_100: Color = Color::rgb(0xdc, 0xfc, 0xe7);
+/// Tailwind green 200, `#bbf7d0`.
+pub const GREEN_200: Color = Color::rgb(0xbb, 0xf7, 0xd0);
+/// Tailwind green 300, `#86efac`.
+pub const GREEN_300: Color = Color::rgb(0x86, 0xef, 0xac);
+/// Tailwind green 400, `#4ade80`.
+pub const GREEN_400: Color = Color::rgb(0x4a, 0xde, 0x80);
+/// Tailwind green 500, `#22c55e`.
+pub const GREEN_500: Color = Color::rgb(0x22, 0xc5, 0x5e);
+/// Tailwind green 600, `#16a34a`.
+pub const GREEN_600: Color = Color::rgb(0x16, 0xa3, 0x4a);
+/// Tailwind green 700, `#15803d`.
+pub const GREEN_700: Color = Color::rgb(0x15, 0x80, 0x3d);
+/// Tailwind green 800, `#166534`.
+pub const GREEN_800: Color = Color::rgb(0x16, 0x65, 0x34);
+/// Tailwind green 900, `#14532d`.
+pub const GREEN_900: Color = Color::rgb(0x14, 0x53, 0x2d);
+/// Tailwind green 950, `#052e16`.
+pub const GREEN_950: Color = Color::rgb(0x05, 0x2e, 0x16);
+/// Tailwind emerald 50, `#ecfdf5`.
+pub const EMERALD_50: Color = Color::rgb(0xec, 0xfd, 0xf5);
+/// Tailwind emerald 100, `#d1fae5`.
+pub const EMERALD_100: Color = Color::rgb(0xd1, 0xfa, 0xe5);
+/// Tailwind emerald 200, `#a7f3d0`.
+pub const EMERALD_200: Color = Color::rgb(0xa7, 0xf3, 0xd0);
+/// Tailwind emerald 300, `#6ee7b7`.
+pub const EMERALD_300: Color = Color::rgb(0x6e, 0xe7, 0xb7);
+/// Tailwind emerald 400, `#34d399`.
+pub const EMERALD_400: Color = Color::rgb(0x34, 0xd3, 0x99);
+/// Tailwind emerald 500, `#10b981`.
+pub const EMERALD_500: Color = Color::rgb(0x10, 0xb9, 0x81);
+/// Tailwind emerald 600, `#059669`.
+pub const EMERALD_600: Color = Color::rgb(0x05, 0x96, 0x69);
+/// Tailwind emerald 700, `#047857`.
+pub const EMERALD_700: Color = Color::rgb(0x04, 0x78, 0x57);
+/// Tailwind emerald 800, `#065f46`.
+pub const EMERALD_800: Color = Color::rgb(0x06, 0x5f, 0x46);
+/// Tailwind emerald 900, `#064e3b`.
+pub const EMERALD_900: Color = Color::rgb(0x06, 0x4e, 0x3b);
+/// Tailwind emerald 950, `#022c22`.
+pub const EMERALD_950: Color = Color::rgb(0x02, 0x2c, 0x22);
+/// Tailwind teal 50, `#f0fdfa`.
+pub const TEAL_50: Color = Color::rgb(0xf0, 0xfd, 0xfa);
+/// Tailwind teal 100, `#ccfbf1`.
+pub const TEAL_100: Color = Color::rgb(0xcc, 0xfb, 0xf1);
+/// Tailwind teal 200, `#99f6e4`.
+pub const TEAL_200: Color = Color::rgb(0x99, 0xf6, 0xe4);
+/// Tailwind teal 300, `#5eead4`.
+pub const TEAL_300: Color = Color::rgb(0x5e, 0xea, 0xd4);
+/// Tailwind teal 400, `#2dd4bf`.
+pub const TEAL_400: Color = Color::rgb(0x2d, 0xd4, 0xbf);
+/// Tailwind teal 500, `#14b8a6`.
+pub const TEAL_500: Color = Color::rgb(0x14, 0xb8, 0xa6);
+/// Tailwind teal 600, `#0d9488`.
+pub const TEAL_600: Color = Color::rgb(0x0d, 0x94, 0x88);
+/// Tailwind teal 700, `#0f766e`.
+pub const TEAL_700: Color = Color::rgb(0x0f, 0x76, 0x6e);
+/// Tailwind teal 800, `#115e59`.
+pub const TEAL_800: Color = Color::rgb(0x11, 0x5e, 0x59);
+/// Tailwind teal 900, `#134e4a`.
+pub const TEAL_900: Color = Color::rgb(0x13, 0x4e, 0x4a);
+/// Tailwind teal 950, `#042f2e`.
+pub const TEAL_950: Color = Color::rgb(0x04, 0x2f, 0x2e);
+/// Tailwind cyan 50, `#ecfeff`.
+pub const CYAN_50: Color = Color::rgb(0xec, 0xfe, 0xff);
+/// Tailwind cyan 100, `#cffafe`.
+pub const CYAN_100: Color = Color::rgb(0xcf, 0xfa, 0xfe);
+/// Tailwind cyan 200, `#a5f3fc`.
+pub const CYAN_200: Color = Color::rgb(0xa5, 0xf3, 0xfc);
+/// Tailwind cyan 300, `#67e8f9`.
+pub const CYAN_300: Color = Color::rgb(0x67, 0xe8, 0xf9);
+/// Tailwind cyan 400, `#22d3ee`.
+pub const CYAN_400: Color = Color::rgb(0x22, 0xd3, 0xee);
+/// Tailwind cyan 500, `#06b6d4`.
+pub const CYAN_500: Color = Color::rgb(0x06, 0xb6, 0xd4);
+/// Tailwind cyan 600, `#0891b2`.
+pub const CYAN_600: Color = Color::rgb(0x08, 0x91, 0xb2);
+/// Tailwind cyan 700, `#0e7490`.
+pub const CYAN_700: Color = Color::rgb(0x0e, 0x74, 0x90);
+/// Tailwind cyan 800, `#155e75`.
+pub const CYAN_800: Color = Color::rgb(0x15, 0x5e, 0x75);
+/// Tailwind cyan 900, `#164e63`.
+pub const CYAN_900: Color = Color::rgb(0x16, 0x4e, 0x63);
+/// Tailwind cyan 950, `#083344`.
+pub const CYAN_950: Color = Color::rgb(0x08, 0x33, 0x44);
+/// Tailwind sky 50, `#f0f9ff`.
+pub const SKY_50: Color = Color::rgb(0xf0, 0xf9, 0xff);
+/// Tailwind sky 100, `#e0f2fe`.
+pub const SKY_100: Color = Color::rgb(0xe0, 0xf2, 0xfe);
+/// Tailwind sky 200, `#bae6fd`.
+pub const SKY_200: Color = Color::rgb(0xba, 0xe6, 0xfd);
+/// Tailwind sky 300, `#7dd3fc`.
+pub const SKY_300: Color = Color::rgb(0x7d, 0xd3, 0xfc);
+/// Tailwind sky 400, `#38bdf8`.
+pub const SKY_400: Color = Color::rgb(0x38, 0xbd, 0xf8);
+/// Tailwind sky 500, `#0ea5e9`.
+pub const SKY_500: Color = Color::rgb(0x0e, 0xa5, 0xe9);
+/// Tailwind sky 600, `#0284c7`.
+pub const SKY_600: Color = Color::rgb(0x02, 0x84, 0xc7);
+/// Tailwind sky 700, `#0369a1`.
+pub const SKY_700: Color = Color::rgb(0x03, 0x69, 0xa1);
+/// Tailwind sky 800, `#075985`.
+pub const SKY_800: Color = Color::rgb(0x07, 0x59, 0x85);
+/// Tailwind sky 900, `#0c4a6e`.
+pub const SKY_900: Color = Color::rgb(0x0c, 0x4a, 0x6e);
+/// Tailwind sky 950, `#082f49`.
+pub const SKY_950: Color = Color::rgb(0x08, 0x2f, 0x49);
+/// Tailwind blue 50, `#eff6ff`.
+pub const BLUE_50: Color = Color::rgb(0xef, 0xf6, 0xff);
+/// Tailwind blue 100, `#dbeafe`.
+pub const BLUE_100: Color = Color::rgb(0xdb, 0xea, 0xfe);
+/// Tailwind blue 200, `#bfdbfe`.
+pub const BLUE_200: Color = Color::rgb(0xbf, 0xdb, 0xfe);
+/// Tailwind blue 300, `#93c5fd`.
+pub const BLUE_300: Color = Color::rgb(0x93, 0xc5, 0xfd);
+/// Tailwind blue 400, `#60a5fa`.
+pub const BLUE_400: Color = Color::rgb(0x60, 0xa5, 0xfa);
+/// Tailwind blue 500, `#3b82f6`.
+pub const BLUE_500: Color = Color::rgb(0x3b, 0x82, 0xf6);
+/// Tailwind blue 600, `#2563eb`.
+pub const BLUE_600: Color = Color::rgb(0x25, 0x63, 0xeb);
+/// Tailwind blue 700, `#1d4ed8`.
+pub const BLUE_700: Color = Color::rgb(0x1d, 0x4e, 0xd8);
+/// Tailwind blue 800, `#1e40af`.
+pub const BLUE_800: Color = Color::rgb(0x1e, 0x40, 0xaf);
+/// Tailwind blue 900, `#1e3a8a`.
+pub const BLUE_900: Color = Color::rgb(0x1e, 0x3a, 0x8a);
+/// Tailwind blue 950, `#172554`.
+pub const BLUE_950: Color = Color::rgb(0x17, 0x25, 0x54);
+/// Tailwind indigo 50, `#eef2ff`.
+pub const INDIGO_50: Color = Color::rgb(0xee, 0xf2, 0xff);
+/// Tailwind indigo 100, `#e0e7ff`.
+pub const INDIGO_100: Color = Color::rgb(0xe0, 0xe7, 0xff);
+/// Tailwind indigo 200, `#c7d2fe`.
+pub const INDIGO_200: Color = Color::rgb(0xc7, 0xd2, 0xfe);
+/// Tailwind indigo 300, `#a5b4fc`.
+pub const INDIGO_300: Color = Color::rgb(0xa5, 0xb4, 0xfc);
+/// Tailwind indigo 400, `#818cf8`.
+pub const INDIGO_400: Color = Color::rgb(0x81, 0x8c, 0xf8);
+/// Tailwind indigo 500, `#6366f1`.
+pub const INDIGO_500: Color = Color::rgb(0x63, 0x66, 0xf1);
+/// Tailwind indigo 600, `#4f46e5`.
+pub const INDIGO_600: Color = Color::rgb(0x4f, 0x46, 0xe5);
+/// Tailwind indigo 700, `#4338ca`.
+pub const INDIGO_700: Color = Color::rgb(0x43, 0x38, 0xca);
+/// Tailwind indigo 800, `#3730a3`.
+pub const INDIGO_800: Color = Color::rgb(0x37, 0x30, 0xa3);
+/// Tailwind indigo 900, `#312e81`.
+pub const INDIGO_900: Color = Color::rgb(0x31, 0x2e, 0x81);
+/// Tailwind indigo 950, `#1e1b4b`.
+pub const INDIGO_950: Color = Color::rgb(0x1e, 0x1b, 0x4b);
+/// Tailwind violet 50, `#f5f3ff`.
+pub const VIOLET_50: Color = Color::rgb(0xf5, 0xf3, 0xff);
+/// Tailwind violet 100, `#ede9fe`.
+pub const VIOLET_100: Color = Color::rgb(0xed, 0xe9, 0xfe);
+/// Tailwind violet 200, `#ddd6fe`.
+pub const VIOLET_200: Color = Color::rgb(0xdd, 0xd6, 0xfe);
+/// Tailwind violet 300, `#c4b5fd`.
+pub const VIOLET_300: Color = Color::rgb(0xc4, 0xb5, 0xfd);
+/// Tailwind violet 400, `#a78bfa`.
+pub const VIOLET_400: Color = Color::rgb(0xa7, 0x8b, 0xfa);
+/// Tailwind violet 500, `#8b5cf6`.
+pub const VIOLET_500: Color = Color::rgb(0x8b, 0x5c, 0xf6);
+/// Tailwind violet 600, `#7c3aed`.
+pub const VIOLET_600: Color = Color::rgb(0x7c, 0x3a, 0xed);
+/// Tailwind violet 700, `#6d28d9`.
+pub const VIOLET_700: Color = Color::rgb(0x6d, 0x28, 0xd9);
+/// Tailwind violet 800, `#5b21b6`.
+pub const VIOLET_800: Color = Color::rgb(0x5b, 0x21, 0xb6);
+/// Tailwind violet 900, `#4c1d95`.
+pub const VIOLET_900: Color = Color::rgb(0x4c, 0x1d, 0x95);
+/// Tailwind violet 950, `#2e1065`.
+pub const VIOLET_950: Color = Color::rgb(0x2e, 0x10, 0x65);
+/// Tailwind purple 50, `#faf5ff`.
+pub const PURPLE_50: Color = Color::rgb(0xfa, 0xf5, 0xff);
+/// Tailwind purple 100, `#f3e8ff`.
+pub const PURPLE_100: Color = Color::rgb(0xf3, 0xe8, 0xff);
+/// Tailwind purple 200, `#e9d5ff`.
+pub const PURPLE_200: Color = Color::rgb(0xe9, 0xd5, 0xff);
+/// Tailwind purple 300, `#d8b4fe`.
+pub const PURPLE_300: Color = Color::rgb(0xd8, 0xb4, 0xfe);
+/// Tailwind purple 400, `#c084fc`.
+pub const PURPLE_400: Color = Color::rgb(0xc0, 0x84, 0xfc);
+/// Tailwind purple 500, `#a855f7`.
+pub const PURPLE_500: Color = Color::rgb(0xa8, 0x55, 0xf7);
+/// Tailwind purple 600, `#9333ea`.
+pub const PURPLE_600: Color = Color::rgb(0x93, 0x33, 0xea);
+/// Tailwind purple 700, `#7e22ce`.
+pub const PURPLE_700: Color = Color::rgb(0x7e, 0x22, 0xce);
+/// Tailwind purple 800, `#6b21a8`.
+pub const PURPLE_800: Color = Color::rgb(0x6b, 0x21, 0xa8);
+/// Tailwind purple 900, `#581c87`.
+pub const PURPLE_900: Color = Color::rgb(0x58, 0x1c, 0x87);
+/// Tailwind purple 950, `#3b0764`.
+pub const PURPLE_950: Color = Color::rgb(0x3b, 0x07, 0x64);
+/// Tailwind fuchsia 50, `#fdf4ff`.
+pub const FUCHSIA_50: Color = Color::rgb(0xfd, 0xf4, 0xff);
+/// Tailwind fuchsia 100, `#fae8ff`.
+pub const FUCHSIA_100: Color = Color::rgb(0xfa, 0xe8, 0xff);
+/// Tailwind fuchsia 200, `#f5d0fe`.
+pub const FUCHSIA_200: Color = Color::rgb(0xf5, 0xd0, 0xfe);
+/// Tailwind fuchsia 300, `#f0abfc`.
+pub const FUCHSIA_300: Color = Color::rgb(0xf0, 0xab, 0xfc);
+/// Tailwind fuchsia 400, `#e879f9`.
+pub const FUCHSIA_400: Color = Color::rgb(0xe8, 0x79, 0xf9);
+/// Tailwind fuchsia 500, `#d946ef`.
+pub const FUCHSIA_500: Color = Color::rgb(0xd9, 0x46, 0xef);
+/// Tailwind fuchsia 600, `#c026d3`.
+pub const FUCHSIA_600: Color = Color::rgb(0xc0, 0x26, 0xd3);
+/// Tailwind fuchsia 700, `#a21caf`.
+pub const FUCHSIA_700: Color = Color::rgb(0xa2, 0x1c, 0xaf);
+/// Tailwind fuchsia 800, `#86198f`.
+pub const FUCHSIA_800: Color = Color::rgb(0x86, 0x19, 0x8f);
+/// Tailwind fuchsia 900, `#701a75`.
+pub const FUCHSIA_900: Color = Color::rgb(0x70, 0x1a, 0x75);
+/// Tailwind fuchsia 950, `#4a044e`.
+pub const FUCHSIA_950: Color = Color::rgb(0x4a, 0x04, 0x4e);
+/// Tailwind pink 50, `#fdf2f8`.
+pub const PINK_50: Color = Color::rgb(0xfd, 0xf2, 0xf8);
+/// Tailwind pink 100, `#fce7f3`.
+pub const PINK_100: Color = Color::rgb(0xfc, 0xe7, 0xf3);
+/// Tailwind pink 200, `#fbcfe8`.
+pub const PINK_200: Color = Color::rgb(0xfb, 0xcf, 0xe8);
+/// Tailwind pink 300, `#f9a8d4`.
+pub const PINK_300: Color = Color::rgb(0xf9, 0xa8, 0xd4);
+/// Tailwind pink 400, `#f472b6`.
+pub const PINK_400: Color = Color::rgb(0xf4, 0x72, 0xb6);
+/// Tailwind pink 500, `#ec4899`.
+pub const PINK_500: Color = Color::rgb(0xec, 0x48, 0x99);
+/// Tailwind pink 600, `#db2777`.
+pub const PINK_600: Color = Color::rgb(0xdb, 0x27, 0x77);
+/// Tailwind pink 700, `#be185d`.
+pub const PINK_700: Color = Color::rgb(0xbe, 0x18, 0x5d);
+/// Tailwind pink 800, `#9d174d`.
+pub const PINK_800: Color = Color::rgb(0x9d, 0x17, 0x4d);
+/// Tailwind pink 900, `#831843`.
+pub const PINK_900: Color = Color::rgb(0x83, 0x18, 0x43);
+/// Tailwind pink 950, `#500724`.
+pub const PINK_950: Color = Color::rgb(0x50, 0x07, 0x24);
+/// Tailwind rose 50, `#fff1f2`.
+pub const ROSE_50: Color = Color::rgb(0xff, 0xf1, 0xf2);
+/// Tailwind rose 100, `#ffe4e6`.
+pub const ROSE_100: Color = Color::rgb(0xff, 0xe4, 0xe6);
+/// Tailwind rose 200, `#fecdd3`.
+pub const ROSE_200: Color = Color::rgb(0xfe, 0xcd, 0xd3);
+/// Tailwind rose 300, `#fda4af`.
+pub const ROSE_300: Color = Color::rgb(0xfd, 0xa4, 0xaf);
+/// Tailwind rose 400, `#fb7185`.
+pub const ROSE_400: Color = Color::rgb(0xfb, 0x71, 0x85);
+/// Tailwind rose 500, `#f43f5e`.
+pub const ROSE_500: Color = Color::rgb(0xf4, 0x3f, 0x5e);
+/// Tailwind rose 600, `#e11d48`.
+pub const ROSE_600: Color = Color::rgb(0xe1, 0x1d, 0x48);
+/// Tailwind rose 700, `#be123c`.
+pub const ROSE_700: Color = Color::rgb(0xbe, 0x12, 0x3c);
+/// Tailwind rose 800, `#9f1239`.
+pub const ROSE_800: Color = Color::rgb(0x9f, 0x12, 0x39);
+/// Tailwind rose 900, `#881337`.
+pub const ROSE_900: Color = Color::rgb(0x88, 0x13, 0x37);
+/// Tailwind rose 950, `#4c0519`.
+pub const ROSE_950: Color = Color::rgb(0x4c, 0x05, 0x19);
+
+/// Every Tailwind scale, lightest shade first.
+pub const PALETTE: Palette = Palette::new(
+    "Tailwind",
+    None,
+    &[
+        ("slate_50", SLATE_50),
+        ("slate_100", SLATE_100),
+        ("slate_200", SLATE_200),
+        ("slate_300", SLATE_300),
+        ("slate_400", SLATE_400),
+        ("slate_500", SLATE_500),
+        ("slate_600", SLATE_600),
+        ("slate_700", SLATE_700),
+        ("slate_800", SLATE_800),
+        ("slate_900", SLATE_900),
+        ("slate_950", SLATE_950),
+        ("gray_50", GRAY_50),
+        ("gray_100", GRAY_100),
+        ("gray_200", GRAY_200),
+        ("gray_300", GRAY_300),
+        ("gray_400", GRAY_400),
+        ("gray_500", GRAY_500),
+        ("gray_600", GRAY_600),
+        ("gray_700", GRAY_700),
+        ("gray_800", GRAY_800),
+        ("gray_900", GRAY_900),
+        ("gray_950", GRAY_950),
+        ("zinc_50", ZINC_50),
+        ("zinc_100", ZINC_100),
+        ("zinc_200", ZINC_200),
+        ("zinc_300", ZINC_300),
+        ("zinc_400", ZINC_400),
+        ("zinc_500", ZINC_500),
+        ("zinc_600", ZINC_600),
+        ("zinc_700", ZINC_700),
+        ("zinc_800", ZINC_800),
+        ("zinc_900", ZINC_900),
+        ("zinc_950", ZINC_950),
+        ("neutral_50", NEUTRAL_50),
+        ("neutral_100", NEUTRAL_100),
+        ("neutral_200", NEUTRAL_200),
+        ("neutral_300", NEUTRAL_300),
+        ("neutral_400", NEUTRAL_400),
+        ("neutral_500", NEUTRAL_500),
+        ("neutral_600", NEUTRAL_600),
+        ("neutral_700", NEUTRAL_700),
+        ("neutral_800", NEUTRAL_800),
+        ("neutral_900", NEUTRAL_900),
+        ("neutral_950", NEUTRAL_950),
+        ("stone_50", STONE_50),
+        ("stone_100", STONE_100),
+        ("stone_200", STONE_200),
+        ("stone_300", STONE_300),
+        ("stone_400", STONE_400),
+        ("stone_500", STONE_500),
+        ("stone_600", STONE_600),
+        ("stone_700", STONE_700),
+        ("stone_800", STONE_800),
+        ("stone_900", STONE_900),
+        ("stone_950", STONE_950),
+        ("red_50", RED_50),
+        ("red_100", RED_100),
+        ("red_200", RED_200),
+        ("red_300", RED_300),
+        ("red_400", RED_400),
+        ("red_500", RED_500),
+        ("red_600", RED_600),
+        ("red_700", RED_700),
+        ("red_800", RED_800),
+        ("red_900", RED_900),
+        ("red_950", RED_950),
+        ("orange_50", ORANGE_50),
+        ("orange_100", ORANGE_100),
+        ("orange_200", ORANGE_200),
+        ("orange_300", ORANGE_300),
+        ("orange_400", ORANGE_400),
+        ("orange_500", ORANGE_500),
+        ("orange_600", ORANGE_600),
+        ("orange_700", ORANGE_700),
+        ("orange_800", ORANGE_800),
+        ("orange_900", ORANGE_900),
+        ("orange_950", ORANGE_950),
+        ("amber_50", AMBER_50),
+        ("amber_100", AMBER_100),
+        ("amber_200", AMBER_200),
+        ("amber_300", AMBER_300),
+        ("amber_400", AMBER_400),
+        ("amber_500", AMBER_500),
+        ("amber_600", AMBER_600),
+        ("amber_700", AMBER_700),
+        ("amber_800", AMBER_800),
+        ("amber_900", AMBER_900),
+        ("amber_950", AMBER_950),
+        ("yellow_50", YELLOW_50),
+        ("yellow_100", YELLOW_100),
+        ("yellow_200", YELLOW_200),
+        ("yellow_300", YELLOW_300),
+        ("yellow_400", YELLOW_400),
+        ("yellow_500", YELLOW_500),
+        ("yellow_600", YELLOW_600),
+        ("yellow_700", YELLOW_700),
+        ("yellow_800", YELLOW_800),
+        ("yellow_900", YELLOW_900),
+        ("yellow_950", YELLOW_950),
+        ("lime_50", LIME_50),
+        ("lime_100", LIME_100),
+        ("lime_200", LIME_200),
+        ("lime_300", LIME_300),
+        ("lime_400", LIME_400),
+        ("lime_500", LIME_500),
+        ("lime_600", LIME_600),
+        ("lime_700", LIME_700),
+        ("lime_800", LIME_800),
+        ("lime_900", LIME_900),
+        ("lime_950", LIME_950),
+        ("green_50", GREEN_50),
+        ("green_100", GREEN_100),
+        ("green_200", GREEN_200),
+        ("green_300", GREEN_300),
+        ("green_400", GREEN_400),
+        ("green_500", GREEN_500),
+        ("green_600", GREEN_600),
+        ("green_700", GREEN_700),
+        ("green_800", GREEN_800),
+        ("green_900", GREEN_900),
+        ("green_950", GREEN_950),
+        ("emerald_50", EMERALD_50),
+        ("emerald_100", EMERALD_100),
+        ("emerald_200", EMERALD_200),
+        ("emerald_300", EMERALD_300),
+        ("emerald_400", EMERALD_400),
+        ("emerald_500", EMERALD_500),
+        ("emerald_600", EMERALD_600),
+        ("emerald_700", EMERALD_700),
+        ("emerald_800", EMERALD_800),
+        ("emerald_900", EMERALD_900),
+        ("emerald_950", EMERALD_950),
+        ("teal_50", TEAL_50),
+        ("teal_100", TEAL_100),
+        ("teal_200", TEAL_200),
+        ("teal_300", TEAL_300),
+        ("teal_400", TEAL_400),
+        ("teal_500", TEAL_500),
+        ("teal_600", TEAL_600),
+        ("teal_700", TEAL_700),
+        ("teal_800", TEAL_800),
+        ("teal_900", TEAL_900),
+        ("teal_950", TEAL_950),
+        ("cyan_50", CYAN_50),
+        ("cyan_100", CYAN_100),
+        ("cyan_200", CYAN_200),
+        ("cyan_300", CYAN_300),
+        ("cyan_400", CYAN_400),
+        ("cyan_500", CYAN_500),
+        ("cyan_600", CYAN_600),
+        ("cyan_700", CYAN_700),
+        ("cyan_800", CYAN_800),
+        ("cyan_900", CYAN_900),
+        ("cyan_950", CYAN_950),
+        ("sky_50", SKY_50),
+        ("sky_100", SKY_100),
+        ("sky_200", SKY_200),
+        ("sky_300", SKY_300),
+        ("sky_400", SKY_400),
+        ("sky_500", SKY_500),
+        ("sky_600", SKY_600),
+        ("sky_700", SKY_700),
+        ("sky_800", SKY_800),
+        ("sky_900", SKY_900),
+        ("sky_950", SKY_950),
+        ("blue_50", BLUE_50),
+        ("blue_100", BLUE_100),
+        ("blue_200", BLUE_200),
+        ("blue_300", BLUE_300),
+        ("blue_400", BLUE_400),
+        ("blue_500", BLUE_500),
+        ("blue_600", BLUE_600),
+        ("blue_700", BLUE_700),
+        ("blue_800", BLUE_800),
+        ("blue_900", BLUE_900),
+        ("blue_950", BLUE_950),
+        ("indigo_50", INDIGO_50),
+        ("indigo_100", INDIGO_100),
+        ("indigo_200", INDIGO_200),
+        ("indigo_300", INDIGO_300),
+        ("indigo_400", INDIGO_400),
+        ("indigo_500", INDIGO_500),
+        ("indigo_600", INDIGO_600),
+        ("indigo_700", INDIGO_700),
+        ("indigo_800", INDIGO_800),
+        ("indigo_900", INDIGO_900),
+        ("indigo_950", INDIGO_950),
+        ("violet_50", VIOLET_50),
+        ("violet_100", VIOLET_100),
+        ("violet_200", VIOLET_200),
+        ("violet_300", VIOLET_300),
+        ("violet_400", VIOLET_400),
+        ("violet_500", VIOLET_500),
+        ("violet_600", VIOLET_600),
+        ("violet_700", VIOLET_700),
+        ("violet_800", VIOLET_800),
+        ("violet_900", VIOLET_900),
+        ("violet_950", VIOLET_950),
+        ("purple_50", PURPLE_50),
+        ("purple_100", PURPLE_100),
+        ("purple_200", PURPLE_200),
+        ("purple_300", PURPLE_300),
+        ("purple_400", PURPLE_400),
+        ("purple_500", PURPLE_500),
+        ("purple_600", PURPLE_600),
+        ("purple_700", PURPLE_700),
+        ("purple_800", PURPLE_800),
+        ("purple_900", PURPLE_900),
+        ("purple_950", PURPLE_950),
+        ("fuchsia_50", FUCHSIA_50),
+        ("fuchsia_100", FUCHSIA_100),
+        ("fuchsia_200", FUCHSIA_200),
+        ("fuchsia_300", FUCHSIA_300),
+        ("fuchsia_400", FUCHSIA_400),
+        ("fuchsia_500", FUCHSIA_500),
+        ("fuchsia_600", FUCHSIA_600),
+        ("fuchsia_700", FUCHSIA_700),
+        ("fuchsia_800", FUCHSIA_800),
+        ("fuchsia_900", FUCHSIA_900),
+        ("fuchsia_950", FUCHSIA_950),
+        ("pink_50", PINK_50),
+        ("pink_100", PINK_100),
+        ("pink_200", PINK_200),
+        ("pink_300", PINK_300),
+        ("pink_400", PINK_400),
+        ("pink_500", PINK_500),
+        ("pink_600", PINK_600),
+        ("pink_700", PINK_700),
+        ("pink_800", PINK_800),
+        ("pink_900", PINK_900),
+        ("pink_950", PINK_950),
+        ("rose_50", ROSE_50),
+        ("rose_100", ROSE_100),
+        ("rose_200", ROSE_200),
+        ("rose_300", ROSE_300),
+        ("rose_400", ROSE_400),
+        ("rose_500", ROSE_500),
+        ("rose_600", ROSE_600),
+        ("rose_700", ROSE_700),
+        ("rose_800", ROSE_800),
+        ("rose_900", ROSE_900),
+        ("rose_950", ROSE_950),
+    ],
+);
@@ -0,0 +1,145 @@
+//! The [Tokyo Night](https://github.com/enkia/tokyo-night-vscode-theme)
+//! palette.
+//!
+//! The Night and Storm styles share their accents and differ only in their
+//! backgrounds.
+
+use super::Palette;
+use crate::{Background, Color};
+
+/// Tokyo Night bg, `#1a1b26`.
+pub const BG: Color = Color::rgb(0x1a, 0x1b, 0x26);
+/// Tokyo Night bg dark, `#16161e`.
+pub const BG_DARK: Color = Color::rgb(0x16, 0x16, 0x1e);
+/// Tokyo Night bg highlight, `#292e42`.
+pub const BG_HIGHLIGHT: Color = Color::rgb(0x29, 0x2e, 0x42);
+/// Tokyo Night terminal black, `#414868`.
+pub const TERMINAL_BLACK: Color = Color::rgb(0x41, 0x48, 0x68);
+/// Tokyo Night fg, `#c0caf5`.
+pub const FG: Color = Color::rgb(0xc0, 0xca, 0xf5);
+/// Tokyo Night fg dark, `#a9b1d6`.
+pub const FG_DARK: Color = Color::rgb(0xa9, 0xb1, 0xd6);
+/// Tokyo Night fg gutter, `#3b4261`.
+pub const FG_GUTTER: Color = Color::rgb(0x3b, 0x42, 0x61);
+/// Tokyo Night dark3, `#545c7e`.
+pub const DARK3: Color = Color::rgb(0x54, 0x5c, 0x7e);
+/// Tokyo Night comment, `#565f89`.
+pub const COMMENT: Color = Color::rgb(0x56, 0x5f, 0x89);
+/// Tokyo Night dark5, `#737aa2`.
+pub const DARK5: Color = Color::rgb(0x73, 0x7a, 0xa2);
+/// Tokyo Night blue0, `#3d59a1`.
+pub const BLUE0: Color = Color::rgb(0x3d, 0x59, 0xa1);
+/// Tokyo Night blue, `#7aa2f7`.
+pub const BLUE: Color = Color::rgb(0x7a, 0xa2, 0xf7);
+/// Tokyo Night cyan, `#7dcfff`.
+pub const CYAN: Color = Color::rgb(0x7d, 0xcf, 0xff);
+/// Tokyo Night blue1, `#2ac3de`.
+pub const BLUE1: Color = Color::rgb(0x2a, 0xc3, 0xde);
+/// Tokyo Night blue2, `#0db9d7`.
+pub const BLUE2: Color = Color::rgb(0x0d, 0xb9, 0xd7);
+/// Tokyo Night blue5, `#89ddff`.
+pub const BLUE5: Color = Color::rgb(0x89, 0xdd, 0xff);
+/// Tokyo Night blue6, `#b4f9f8`.
+pub const BLUE6: Color = Color::rgb(0xb4, 0xf9, 0xf8);
+/// Tokyo Night blue7, `#394b70`.
+pub const BLUE7: Color = Color::rgb(0x39, 0x4b, 0x70);
+/// Tokyo Night magenta, `#bb9af7`.
+pub const MAGENTA: Color = Color::rgb(0xbb, 0x9a, 0xf7);
+/// Tokyo Night magenta2, `#ff007c`.
+pub const MAGENTA2: Color = Color::rgb(0xff, 0x00, 0x7c);
+/// Tokyo Night purple, `#9d7cd8`.
+pub const PURPLE: Color = Color::rgb(0x9d, 0x7c, 0xd8);
+/// Tokyo Night orange, `#ff9e64`.
+pub const ORANGE: Color = Color::rgb(0xff, 0x9e, 0x64);
+/// Tokyo Night yellow, `#e0af68`.
+pub const YELLOW: Color = Color::rgb(0xe0, 0xaf, 0x68);
+/// Tokyo Night green, `#9ece6a`.
+pub const GREEN: Color = Color::rgb(0x9e, 0xce, 0x6a);
+/// Tokyo Night green1, `#73daca`.
+pub const GREEN1: Color = Color::rgb(0x73, 0xda, 0xca);
+/// Tokyo Night green2, `#41a6b5`.
+pub const GREEN2: Color = Color::rgb(0x41, 0xa6, 0xb5);
+/// Tokyo Night teal, `#1abc9c`.
+pub const TEAL: Color = Color::rgb(0x1a, 0xbc, 0x9c);
+/// Tokyo Night red, `#f7768e`.
+pub const RED: Color = Color::rgb(0xf7, 0x76, 0x8e);
+/// Tokyo Night red1, `#db4b4b`.
+pub const RED1: Color = Color::rgb(0xdb, 0x4b, 0x4b);
+/// Tokyo Night storm bg, `#24283b`.
+pub const STORM_BG: Color = Color::rgb(0x24, 0x28, 0x3b);
+/// Tokyo Night storm bg dark, `#1f2335`.
+pub const STORM_BG_DARK: Color = Color::rgb(0x1f, 0x23, 0x35);
+
+/// The Night style.
+pub const NIGHT: Palette = Palette::new(
+    "Tokyo Night",
+    Some(Background::Dark),
+    &[
+        ("bg", BG),
+        ("bg_dark", BG_DARK),
+        ("bg_highlight", BG_HIGHLIGHT),
+        ("terminal_black", TERMINAL_BLACK),
+        ("fg", FG),
+        ("fg_dark", FG_DARK),
+        ("fg_gutter", FG_GUTTER),
+        ("dark3", DARK3),
+        ("comment", COMMENT),
+        ("dark5", DARK5),
+        ("blue0", BLUE0),
+        ("blue", BLUE),
+        ("cyan", CYAN),
+        ("blue1", BLUE1),
+        ("blue2", BLUE2),
+        ("blue5", BLUE5),
+        ("blue6", BLUE6),
+        ("blue7", BLUE7),
+        ("magenta", MAGENTA),
+        ("magenta2", MAGENTA2),
+        ("purple", PURPLE),
+        ("orange", ORANGE),
+        ("yellow", YELLOW),
+        ("green", GREEN),
+        ("green1", GREEN1),
+        ("green2", GREEN2),
+        ("teal", TEAL),
+        ("red", RED),
+        ("red1", RED1),
+    ],
+);
+
+/// The Storm style.
+pub const STORM: Palette = Palette::new(
+    "Tokyo Night Storm",
+    Some(Background::Dark),
+    &[
+        ("storm_bg", STORM_BG),
+        ("storm_bg_dark", STORM_BG_DARK),
+        ("bg_highlight", BG_HIGHLIGHT),
+        ("terminal_black", TERMINAL_BLACK),
+        ("fg", FG),
+        ("fg_dark", FG_DARK),
+        ("fg_gutter", FG_GUTTER),
+        ("dark3", DARK3),
+        ("comment", COMMENT),
+        ("dark5", DARK5),
+        ("blue0", BLUE0),
+        ("blue", BLUE),
+        ("cyan", CYAN),
+        ("blue1", BLUE1),
+        ("blue2", BLUE2),
+        ("blue5", BLUE5),
+        ("blue6", BLUE6),
+        ("blue7", BLUE7),
+        ("magenta", MAGENTA),
+        ("magenta2", MAGENTA2),
+        ("purple", PURPLE),
+        ("orange", ORANGE),
+        ("yellow", YELLOW),
+        ("green", GREEN),
+        ("green1", GREEN1),
+        ("green2", GREEN2),
+        ("teal", TEAL),
+        ("red", RED),
+        ("red1", RED1),
+    ],
+);
@@ -0,0 +1,410 @@
+use std::io::{self, ErrorKind, Read, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::color::Color;
+use crate::config::effective_color_depth_mode;
+use crate::terminal::{
+    color_level_limit, Background, ColorLevel, ProcessEnv, TerminalCapabilities,
+};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_millis(100);
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+const TRUECOLOR_TERMINALS: [&str; 10] = [
+    "alacritty",
+    "contour",
+    "foot",
+    "ghostty",
+    "iterm2",
+    "kitty",
+    "konsole",
+    "mintty",
+    "rio",
+    "wezterm",
+];
+
+/// An opt-in active query for terminal colors and identification.
+///
+/// The query writes OSC 10/11 (default foreground and background), OSC 4
+/// (palette entries), XTVERSION, and DA1 requests to a terminal, then parses
+/// the replies. DA1 is sent last because virtually every terminal answers it,
+/// so its reply marks the end of the response stream.
+///
+/// The reader and writer are supplied by the caller. For a real terminal the
+/// caller is responsible for opening the tty and switching it to raw,
+/// non-canonical mode so replies are not echoed or line-buffered. The reader
+/// should return [`ErrorKind::WouldBlock`] or [`ErrorKind::TimedOut`] when no
+/// input is pending; a reader that blocks indefinitely cannot be interrupted by
+/// the timeout.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TerminalQuery {
+    timeout: Duration,
+    palette: Vec<u8>,
+}
+
+impl Default for TerminalQuery {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_TIMEOUT,
+            palette: Vec::new(),
+        }
+    }
+}
+
+impl TerminalQuery {
+    /// Create a query for the default colors, XTVERSION, and DA1.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how long to wait for replies before giving up.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Also query the given palette entries with OSC 4.
+    pub fn palette(mut self, indexes: impl IntoIterator<Item = u8>) -> Self {
+        self.palette.extend(indexes);
+        self
+    }
+
+    /// Return the raw request bytes written to the terminal.
+    pub fn request(&self) -> Vec<u8> {
+        let mut request = Vec::new();
+        request.extend_from_slice(b"\x1b]10;?\x1b\\");
+        request.extend_from_slice(b"\x1b]11;?\x1b\\");
+        for index in &self.palette {
+            request.extend_from_slice(format!("\x1b]4;{index};?\x1b\\").as_bytes());
+        }
+        request.extend_from_slice(b"\x1b[>0q");
+        request.extend_from_slice(b"\x1b[c");
+        request
+    }
+
+    /// Write the query to `writer` and collect replies from `reader`.
+    ///
+    /// Reading stops once the DA1 reply arrives, the reader reaches end of
+    /// input, or the timeout elapses. Replies received before the timeout are
+    /// still parsed, so a terminal that ignores some requests yields a partial
+    /// report rather than an error.
+    pub fn run(
+        &self,
+        reader: &mut impl Read,
+        writer: &mut impl Write,
+    ) -> io::Result<TerminalReport> {
+        writer.write_all(&self.request())?;
+        writer.flush()?;
+
+        let deadline = Instant::now() + self.timeout;
+        let mut received = Vec::new();
+        let mut scanned = 0;
+        let mut buffer = [0; 256];
+
+        while !contains_da1_reply(&received, &mut scanned) {
+            if Instant::now() >= deadline {
+                break;
+            }
+
+            match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(count) => received.extend_from_slice(&buffer[..count]),
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error)
+                    if matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) =>
+                {
+                    thread::sleep(POLL_INTERVAL);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(TerminalReport::parse(&received))
+    }
+}
+
+/// Parsed replies from a [`TerminalQuery`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TerminalReport {
+    /// The default foreground color reported via OSC 10.
+    pub foreground: Option<(u8, u8, u8)>,
+    /// The default background color reported via OSC 11.
+    pub background: Option<(u8, u8, u8)>,
+    /// Palette entries reported via OSC 4, in reply order.
+    pub palette: Vec<(u8, (u8, u8, u8))>,
+    /// Attribute parameters from the DA1 reply, starting with the device class.
+    pub device_attributes: Option<Vec<u16>>,
+    /// The terminal name and version reported via XTVERSION.
+    pub version: Option<String>,
+}
+
+impl TerminalReport {
+    /// Parse a buffer of terminal replies.
+    ///
+    /// Unrecognized sequences and stray bytes are ignored. OSC replies may be
+    /// terminated by either BEL or ST.
+    pub fn parse(bytes: &[u8]) -> Self {
+        let mut report = Self::default();
+        let mut position = 0;
+
+        while position < bytes.len() {
+            if bytes[position] != 0x1b || position + 1 >= bytes.len() {
+                position += 1;
+                continue;
+            }
+
+            let rest = &bytes[position + 2..];
+            let consumed = match bytes[position + 1] {
+                b']' => string_body(rest).map(|(body, length)| {
+                    report.apply_osc(body);
+                    length
+                }),
+                b'P' => string_body(rest).map(|(body, length)| {
+                    report.apply_dcs(body);
+                    length
+                }),
+                b'[' => csi_body(rest).map(|(body, length)| {
+                    report.apply_csi(body);
+                    length
+                }),
+                _ => None,
+            };
+
+            position += 2 + consumed.unwrap_or(0);
+        }
+
+        report
+    }
+
+    /// Return the color for a queried palette entry, if it was reported.
+    pub fn palette_color(&self, index: u8) -> Option<(u8, u8, u8)> {
+        self.palette
+            .iter()
+            .rev()
+            .find(|(entry, _)| *entry == index)
+            .map(|(_, color)| *color)
+    }
+
+    /// Fold the query results into detected terminal capabilities.
+    ///
+    /// Replies are positive evidence of support, so they can only raise the
+    /// color level: a known truecolor terminal in XTVERSION selects
+    /// [`ColorLevel::TrueColor`], a reply for a palette entry above 15 selects
+    /// at least [`ColorLevel::Ansi256`], and the DA1 ANSI color attribute (22)
+    /// selects at least [`ColorLevel::Ansi16`]. Capabilities that resolved to
+    /// [`ColorLevel::NoColor`] keep that level, because it can come from user
+    /// policy rather than terminal support. For the same reason the raised
+    /// level never exceeds a level pinned by `FORCE_COLOR` or an explicit
+    /// [`ColorDepthMode`](crate::ColorDepthMode).
+    ///
+    /// A reported OSC 11 background replaces the background color and
+    /// brightness.
+    pub fn apply_to(&self, capabilities: TerminalCapabilities) -> TerminalCapabilities {
+        let background = self
+            .background
+            .map(|(r, g, b)| Background::from_rgb(r, g, b))
+            .or(capabilities.background);
+        let background_color = self
+            .background
+            .map(Color::from)
+            .or(capabilities.background_color);
+
+        if capabilities.color_level == ColorLevel::NoColor {
+            return TerminalCapabilities {
+                background,
+                background_color,
+                ..capabilities
+            };
+        }
+
+        let limit = color_level_limit(effective_color_depth_mode(), &ProcessEnv);
+        let color_level = match self.color_level() {
+            Some(detected) if detected > capabilities.color_level => limit
+                .map_or(detected, |limit| detected.min(limit))
+                .max(capabilities.color_level),
+            _ => capabilities.color_level,
+        };
+
+        TerminalCapabilities {
+            color_level,
+            background,
+            background_color,
+            ..capabilities
+        }
+    }
+
+    fn color_level(&self) -> Option<ColorLevel> {
+        if self.version.as_deref().is_some_and(is_truecolor_terminal) {
+            return Some(ColorLevel::TrueColor);
+        }
+
+        if self.palette.iter().any(|(index, _)| *index > 15) {
+            return Some(ColorLevel::Ansi256);
+        }
+
+        if self
+            .device_attributes
+            .as_ref()
+            .is_some_and(|attributes| attributes.iter().skip(1).any(|value| *value == 22))
+        {
+            return Some(ColorLevel::Ansi16);
+        }
+
+        None
+    }
+
+    fn apply_osc(&mut self, body: &[u8]) {
+        let Ok(body) = std::str::from_utf8(body) else {
+            return;
+        };
+        let mut parts = body.split(';');
+
+        match parts.next() {
+            Some("10") => self.foreground = parts.next().and_then(parse_color_spec),
+            Some("11") => self.background = parts.next().and_then(parse_color_spec),
+            Some("4") => {
+                while let (Some(index), Some(spec)) = (parts.next(), parts.next()) {
+                    if let (Ok(index), Some(color)) = (index.parse(), parse_color_spec(spec)) {
+                        self.palette.push((index, color));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn apply_dcs(&mut self, body: &[u8]) {
+        if let Some(version) = body.strip_prefix(b">|") {
+            self.version = Some(String::from_utf8_lossy(version).into_owned());
+        }
+    }
+
+    fn apply_csi(&mut self, body: &[u8]) {
+        let Some(parameters) = body
+            .strip_prefix(b"?")
+            .and_then(|body| body.strip_suffix(b"c"))
+        else {
+            return;
+        };
+
+        let attributes = String::from_utf8_lossy(parameters)
+            .split(';')
+            .filter_map(|value| value.parse().ok())
+            .collect();
+        self.device_attributes = Some(attributes);
+    }
+}
+
+/// Return the body of an OSC or DCS string and the bytes consumed, including
+/// the BEL or ST terminator.
+fn string_body(bytes: &[u8]) -> Option<(&[u8], usize)> {
+    for (index, byte) in bytes.iter().enumerate() {
+        match byte {
+            0x07 => return Some((&bytes[..index], index + 1)),
+            0x1b if bytes.get(index + 1) == Some(&b'\\') => {
+                return Some((&bytes[..index], index + 2));
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Return the parameters and final byte of a CSI sequence and the bytes
+/// consumed.
+fn csi_body(bytes: &[u8]) -> Option<(&[u8], usize)> {
+    let end = bytes.iter().position(|byte| (0x40..=0x7e).contains(byte))?;
+    Some((&bytes[..=end], end + 1))
+}
+
+/// Return whether `bytes` contains a DA1 reply, starting at `*scanned`.
+///
+/// `scanned` advances past every complete escape sequence, and stops at the
+/// start of an incomplete one, so repeated calls on a growing buffer only look
+/// at the new bytes.
+fn contains_da1_reply(bytes: &[u8], scanned: &mut usize) -> bool {
+    while let Some(offset) = bytes[*scanned..].iter().position(|byte| *byte == 0x1b) {
+        let start = *scanned + offset;
+
+        match bytes.get(start + 1) {
+            Some(b'[') => match csi_body(&bytes[start + 2..]) {
+                Some((body, consumed)) => {
+                    if body.starts_with(b"?") && body.ends_with(b"c") {
+                        return true;
+                    }
+                    *scanned = start + 2 + consumed;
+                }
+                None => {
+                    *scanned = start;
+                    return false;
+                }
+            },
+            Some(_) => *scanned = start + 1,
+            None => {
+                *scanned = start;
+                return false;
+            }
+        }
+    }
+
+    *scanned = bytes.len();
+    false
+}
+
+/// Parse an X11 color specification such as `rgb:ffff/8080/0000`.
+fn parse_color_spec(spec: &str) -> Option<(u8, u8, u8)> {
+    if let Some(hex) = spec.strip_prefix('#') {
+        return parse_legacy_hex(hex);
+    }
+
+    let components = spec
+        .strip_prefix("rgb:")
+        .or_else(|| spec.strip_prefix("rgba:"))?;
+    let mut components = components.split('/').map(scale_hex_component);
+    let r = components.next()??;
+    let g = components.next()??;
+    let b = components.next()??;
+
+    Some((r, g, b))
+}
+
+fn parse_legacy_hex(hex: &str) -> Option<(u8, u8, u8)> {
+    // Terminal replies are untrusted, so check the digits before slicing on
+    // byte offsets.
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    if hex.is_empty() || !hex.len().is_multiple_of(3) {
+        return None;
+    }
+
+    let width = hex.len() / 3;
+    let r = scale_hex_component(&hex[..width])?;
+    let g = scale_hex_component(&hex[width..2 * width])?;
+    let b = scale_hex_component(&hex[2 * width..])?;
+
+    Some((r, g, b))
+}
+
+/// Scale a 1-4 digit hex component to 8 bits.
+fn scale_hex_component(component: &str) -> Option<u8> {
+    if component.is_empty()
+        || component.len() > 4
+        || !component.bytes().all(|byte| byte.is_ascii_hexdigit())
+    {
+        return None;
+    }
+
+    let value = u32::from_str_radix(component, 16).ok()?;
+    let max = (1_u32 << (4 * component.len())) - 1;
+    Some(((value * 255 + max / 2) / max) as u8)
+}
+
+fn is_truecolor_terminal(version: &str) -> bool {
+    let version = version.to_ascii_lowercase();
+    TRUECOLOR_TERMINALS
+        .iter()
+        .any(|name| version.starts_with(name))
+}
@@ -0,0 +1,171 @@
+use crate::color::NamedColor;
+use crate::style::{Style, StyledText};
+
+/// Maps numbers to [`Style`] values, for percentages, deltas, and other
+/// values whose color depends on how large they are.
+///
+/// A threshold scale picks the style of the first threshold the value is
+/// below, and otherwise a fallback style. A sign scale picks a style for
+/// negative, zero, and positive values.
+///
+/// ```rust
+/// use colored_text::{NamedColor, Scale, Style};
+///
+/// let usage = Scale::new()
+///     .below(70.0, Style::new().fg_named(NamedColor::Green))
+///     .below(90.0, Style::new().fg_named(NamedColor::Yellow))
+///     .otherwise(Style::new().fg_named(NamedColor::Red).bold())
+///     .precision(1);
+/// println!("disk {}%", usage.paint(93.25));
+///
+/// let change = Scale::sign().precision(2);
+/// println!("{}", change.paint(-0.42));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scale {
+    kind: ScaleKind,
+    precision: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ScaleKind {
+    Thresholds {
+        steps: Vec<(f64, Style)>,
+        otherwise: Style,
+    },
+    Sign {
+        negative: Style,
+        zero: Style,
+        positive: Style,
+    },
+}
+
+impl Scale {
+    /// Create a threshold scale with no thresholds, which leaves every value
+    /// plain until thresholds are added.
+    pub fn new() -> Self {
+        Self {
+            kind: ScaleKind::Thresholds {
+                steps: Vec::new(),
+                otherwise: Style::new(),
+            },
+            precision: None,
+        }
+    }
+
+    /// Create a sign scale: negative values red, zero dim, and positive
+    /// values green.
+    pub fn sign() -> Self {
+        Self::sign_with(
+            Style::new().fg_named(NamedColor::Red),
+            Style::new().dim(),
+            Style::new().fg_named(NamedColor::Green),
+        )
+    }
+
+    /// Create a sign scale with custom styles.
+    pub fn sign_with(negative: Style, zero: Style, positive: Style) -> Self {
+        Self {
+            kind: ScaleKind::Sign {
+                negative,
+                zero,
+                positive,
+            },
+            precision: None,
+        }
+    }
+
+    /// Use `style` for values below `limit` that are not below a lower
+    /// threshold.
+    ///
+    /// Thresholds may be added in any order. This has no effect on a sign
+    /// scale, and a NaN limit is ignored.
+    pub fn below(mut self, limit: f64, style: Style) -> Self {
+        if let ScaleKind::Thresholds { steps, .. } = &mut self.kind {
+            if !limit.is_nan() {
+                let index = steps.partition_point(|(existing, _)| *existing <= limit);
+                steps.insert(index, (limit, style));
+            }
+        }
+        self
+    }
+
+    /// Use `style` for values at or above every threshold, and for NaN.
+    ///
+    /// This has no effect on a sign scale.
+    pub fn otherwise(mut self, style: Style) -> Self {
+        if let ScaleKind::Thresholds { otherwise, .. } = &mut self.kind {
+            *otherwise = style;
+        }
+        self
+    }
+
+    /// Format painted values with this many digits after the decimal point.
+    ///
+    /// Values are rounded to the precision before a style is chosen, so the
+    /// style always matches the digits shown, and a value that rounds to zero
+    /// is shown without a minus sign.
+    pub fn precision(mut self, digits: usize) -> Self {
+        self.precision = Some(digits);
+        self
+    }
+
+    /// Return the style for a value.
+    ///
+    /// On a sign scale, NaN uses the zero style.
+    pub fn style_for(&self, value: f64) -> &Style {
+        let value = self.rounded(value);
+        match &self.kind {
+            ScaleKind::Thresholds { steps, otherwise } => steps
+                .iter()
+                .find(|(limit, _)| value < *limit)
+                .map_or(otherwise, |(_, style)| style),
+            ScaleKind::Sign {
+                negative,
+                zero,
+                positive,
+            } => {
+                if value < 0.0 {
+                    negative
+                } else if value > 0.0 {
+                    positive
+                } else {
+                    zero
+                }
+            }
+        }
+    }
+
+    /// Format a value and style it in one step.
+    pub fn paint(&self, value: impl Into<f64>) -> StyledText {
+        let value = self.rounded(value.into());
+        let text = match self.precision {
+            Some(digits) => format!("{value:.digits$}"),
+            None => value.to_string(),
+        };
+        self.style_for(value).paint(text)
+    }
+
+    fn rounded(&self, value: f64) -> f64 {
+        let Some(digits) = self.precision else {
+            return value;
+        };
+        let factor = 10_f64.powi(i32::try_from(digits).unwrap_or(i32::MAX));
+        let rounded = (value * factor).round() / factor;
+        if !rounded.is_finite() {
+            // Too many digits to scale; the value is shown as precise as it is.
+            value
+        } else if rounded == 0.0 {
+            // Drop the sign of negative zero.
+            0.0
+        } else {
+            rounded
+        }
+    }
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
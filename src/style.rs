@@ -1,10 +1,24 @@
 use std::fmt::{self, Display};
 
-use crate::color::{hex_to_rgb, hsl_to_rgb, ColorSpec, NamedColor};
-use crate::config::{color_level, color_level_for, RenderTarget};
+use crate::color::{
+    hex_to_rgb, quantization_palette, rgb_to_oklab, AdaptiveColor, Color, ColorSpec, FallbackColor,
+    NamedColor, RenderContext, Rgba,
+};
+use crate::colormap::Colormap;
+use crate::config::{render_context, render_context_for, RenderTarget};
+use crate::contrast::{contrasting, ContrastTarget};
+use crate::dither::{Dither, Ditherer};
+use crate::gradient::Gradient;
+use crate::grapheme::graphemes;
+use crate::hsl::Hsl;
 use crate::terminal::ColorLevel;
+use crate::theme::role_style;
 
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+/// Contrast ratio a downgraded RGB foreground keeps against its background,
+/// enough to tell the text apart rather than to make it comfortable to read.
+const MIN_DOWNGRADED_CONTRAST: f32 = 1.5;
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 struct StyleFlags {
     bold: bool,
     dim: bool,
@@ -37,6 +51,160 @@ impl StyleFlags {
         }
         codes
     }
+
+    fn merge(&mut self, other: &Self) {
+        self.bold |= other.bold;
+        self.dim |= other.dim;
+        self.italic |= other.italic;
+        self.underline |= other.underline;
+        self.inverse |= other.inverse;
+        self.strikethrough |= other.strikethrough;
+    }
+}
+
+/// A reusable combination of colors and text styles.
+///
+/// A `Style` holds everything a [`StyledText`] can carry except the text, so it
+/// can be defined once and applied to many values with [`Style::paint`] or
+/// [`StyledText::style`].
+///
+/// ```rust
+/// use colored_text::{Color, NamedColor, Style};
+///
+/// let alert = Style::new().fg_named(NamedColor::Red).bold();
+/// println!("{}", alert.paint("disk full"));
+///
+/// let badge = Style::new().fg(Color::rgb(255, 255, 255)).bg(Color::rgb(0, 95, 135));
+/// println!("{}", badge.paint(" v1.2 "));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Style {
+    foreground: Option<ColorSpec>,
+    background: Option<ColorSpec>,
+    styles: StyleFlags,
+    raw_codes: Vec<String>,
+}
+
+impl Style {
+    /// Create a style with no colors or attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return whether the style has no colors, attributes, or raw codes.
+    pub fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Set an RGB foreground color.
+    pub fn fg(self, color: Color) -> Self {
+        let Color { r, g, b } = color;
+        self.with_foreground(ColorSpec::Rgb(r, g, b))
+    }
+
+    /// Set an RGB background color.
+    pub fn bg(self, color: Color) -> Self {
+        let Color { r, g, b } = color;
+        self.with_background(ColorSpec::Rgb(r, g, b))
+    }
+
+    /// Set a named ANSI foreground color.
+    pub fn fg_named(self, color: NamedColor) -> Self {
+        self.with_foreground(ColorSpec::Named(color))
+    }
+
+    /// Set a named ANSI background color.
+    pub fn bg_named(self, color: NamedColor) -> Self {
+        self.with_background(ColorSpec::Named(color))
+    }
+
+    /// Set an ANSI 256 foreground color.
+    pub fn fg_ansi256(self, index: u8) -> Self {
+        self.with_foreground(ColorSpec::Ansi256(index))
+    }
+
+    /// Set an ANSI 256 background color.
+    pub fn bg_ansi256(self, index: u8) -> Self {
+        self.with_background(ColorSpec::Ansi256(index))
+    }
+
+    /// Set a foreground color that adapts to the terminal background.
+    pub fn fg_adaptive(self, color: AdaptiveColor) -> Self {
+        self.with_foreground(ColorSpec::Adaptive(color))
+    }
+
+    /// Set a background color that adapts to the terminal background.
+    pub fn bg_adaptive(self, color: AdaptiveColor) -> Self {
+        self.with_background(ColorSpec::Adaptive(color))
+    }
+
+    /// Set a foreground color with hand-picked fallbacks.
+    pub fn fg_fallback(self, color: FallbackColor) -> Self {
+        self.with_foreground(ColorSpec::Fallback(color))
+    }
+
+    /// Set a background color with hand-picked fallbacks.
+    pub fn bg_fallback(self, color: FallbackColor) -> Self {
+        self.with_background(ColorSpec::Fallback(color))
+    }
+
+    /// Add bold text styling.
+    pub fn bold(self) -> Self {
+        self.set_style(|styles| styles.bold = true)
+    }
+
+    /// Add dim text styling.
+    pub fn dim(self) -> Self {
+        self.set_style(|styles| styles.dim = true)
+    }
+
+    /// Add italic text styling.
+    pub fn italic(self) -> Self {
+        self.set_style(|styles| styles.italic = true)
+    }
+
+    /// Add underline text styling.
+    pub fn underline(self) -> Self {
+        self.set_style(|styles| styles.underline = true)
+    }
+
+    /// Swap foreground and background when rendered.
+    pub fn inverse(self) -> Self {
+        self.set_style(|styles| styles.inverse = true)
+    }
+
+    /// Add strikethrough text styling.
+    pub fn strikethrough(self) -> Self {
+        self.set_style(|styles| styles.strikethrough = true)
+    }
+
+    /// Add a raw ANSI SGR code sequence, as [`StyledText::colorize`] does.
+    pub fn raw(mut self, code: &str) -> Self {
+        if !code.trim().is_empty() {
+            self.raw_codes.push(code.to_string());
+        }
+        self
+    }
+
+    /// Apply the style to a displayable value.
+    pub fn paint(&self, text: impl Display) -> StyledText {
+        StyledText::plain(text.to_string()).style(self)
+    }
+
+    fn with_foreground(mut self, color: ColorSpec) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+
+    fn with_background(mut self, color: ColorSpec) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    fn set_style(mut self, update: impl FnOnce(&mut StyleFlags)) -> Self {
+        update(&mut self.styles);
+        self
+    }
 }
 
 /// A styled text value that composes colors and text attributes before render.
@@ -51,6 +219,7 @@ pub struct StyledText {
     background: Option<ColorSpec>,
     styles: StyleFlags,
     raw_codes: Vec<String>,
+    dither: Dither,
 }
 
 impl StyledText {
@@ -62,6 +231,7 @@ impl StyledText {
             background: None,
             styles: StyleFlags::default(),
             raw_codes: Vec::new(),
+            dither: Dither::None,
         }
     }
 
@@ -85,22 +255,70 @@ impl StyledText {
         self
     }
 
-    fn active_codes(&self, level: ColorLevel) -> Vec<String> {
-        if level == ColorLevel::NoColor {
+    fn active_codes(&self, context: RenderContext) -> Vec<String> {
+        let (foreground, background) = self.colors_at(context, 0.0);
+        let foreground = foreground.map(|color| {
+            separate_from_background(
+                context,
+                (&color, background.as_ref()),
+                color.clone(),
+                background.as_ref(),
+            )
+        });
+        self.codes_for(context, foreground.as_ref(), background.as_ref())
+    }
+
+    /// Return the colors at position `t` of the span, with adaptive and alpha
+    /// colors resolved and the color transform applied. An alpha foreground is
+    /// composited over the span's own background when it has one.
+    fn colors_at(&self, context: RenderContext, t: f32) -> (Option<ColorSpec>, Option<ColorSpec>) {
+        let background = self
+            .background
+            .as_ref()
+            .map(|color| color.at(t).resolve(context));
+        let foreground = self
+            .foreground
+            .as_ref()
+            .map(|color| match (color, &background) {
+                (ColorSpec::Rgba(rgba), Some(background)) => {
+                    let Color { r, g, b } = rgba.over(background.to_color(context));
+                    ColorSpec::Rgb(r, g, b)
+                }
+                _ => color.at(t).resolve(context),
+            });
+
+        (
+            foreground.map(|color| color.transformed(context)),
+            background.map(|color| color.transformed(context)),
+        )
+    }
+
+    fn codes_for(
+        &self,
+        context: RenderContext,
+        foreground: Option<&ColorSpec>,
+        background: Option<&ColorSpec>,
+    ) -> Vec<String> {
+        if context.level == ColorLevel::NoColor {
             return Vec::new();
         }
 
-        let mut codes = self.raw_codes.clone();
-        codes.extend(self.styles.sgr_codes());
+        // 8-color output draws bright foregrounds as bold, which merges with
+        // the text's own bold.
+        let mut styles = self.styles.clone();
+        let foreground = foreground
+            .and_then(|color| color.foreground_code(context))
+            .map(|(code, bold)| {
+                styles.bold |= bold;
+                code
+            });
 
-        if let Some(foreground) = &self.foreground {
-            if let Some(code) = foreground.foreground_code(level) {
-                codes.push(code);
-            }
-        }
+        let mut codes = self.raw_codes.clone();
+        codes.extend(styles.sgr_codes());
+        codes.extend(foreground);
 
-        if let Some(background) = &self.background {
-            if let Some(code) = background.background_code(level) {
+        if let Some(background) = background {
+            if let Some(code) = background.background_code(context) {
                 codes.push(code);
             }
         }
@@ -108,6 +326,24 @@ impl StyledText {
         codes
     }
 
+    fn has_gradient(&self) -> bool {
+        self.foreground.as_ref().is_some_and(ColorSpec::is_gradient)
+            || self.background.as_ref().is_some_and(ColorSpec::is_gradient)
+    }
+
+    fn has_rgb_color(&self) -> bool {
+        let is_rgb = |color: &ColorSpec| {
+            matches!(
+                color,
+                ColorSpec::Rgb(..)
+                    | ColorSpec::Adaptive(_)
+                    | ColorSpec::Rgba(_)
+                    | ColorSpec::Gradient(_)
+            )
+        };
+        self.foreground.as_ref().is_some_and(is_rgb) || self.background.as_ref().is_some_and(is_rgb)
+    }
+
     /// Apply a raw ANSI SGR code sequence to the value.
     ///
     /// This is an escape hatch for manual SGR composition. Prefer the typed
@@ -340,17 +576,112 @@ impl StyledText {
     }
 
     /// Convert HSL to RGB and apply it to the foreground color.
+    ///
+    /// Components are normalized as by [`Hsl::new`].
     pub fn hsl(self, h: f32, s: f32, l: f32) -> Self {
-        let (r, g, b) = hsl_to_rgb(h, s, l);
+        let (r, g, b) = Hsl::new(h, s, l).to_color().to_rgb();
         self.rgb(r, g, b)
     }
 
     /// Convert HSL to RGB and apply it to the background color.
+    ///
+    /// Components are normalized as by [`Hsl::new`].
     pub fn on_hsl(self, h: f32, s: f32, l: f32) -> Self {
-        let (r, g, b) = hsl_to_rgb(h, s, l);
+        let (r, g, b) = Hsl::new(h, s, l).to_color().to_rgb();
+        self.on_rgb(r, g, b)
+    }
+
+    /// Apply a foreground color that adapts to the terminal background.
+    pub fn adaptive(self, color: AdaptiveColor) -> Self {
+        self.with_foreground(ColorSpec::Adaptive(color))
+    }
+
+    /// Apply a background color that adapts to the terminal background.
+    pub fn on_adaptive(self, color: AdaptiveColor) -> Self {
+        self.with_background(ColorSpec::Adaptive(color))
+    }
+
+    /// Apply a foreground color with hand-picked fallbacks for lower color
+    /// levels.
+    pub fn fallback(self, color: FallbackColor) -> Self {
+        self.with_foreground(ColorSpec::Fallback(color))
+    }
+
+    /// Apply a background color with hand-picked fallbacks for lower color
+    /// levels.
+    pub fn on_fallback(self, color: FallbackColor) -> Self {
+        self.with_background(ColorSpec::Fallback(color))
+    }
+
+    /// Apply a foreground color with alpha.
+    ///
+    /// The color is composited over the span's background when one is set,
+    /// and otherwise over the terminal background, before degrading to the
+    /// resolved color level.
+    pub fn rgba(self, color: Rgba) -> Self {
+        self.with_foreground(ColorSpec::Rgba(color))
+    }
+
+    /// Apply a background color with alpha, composited over the terminal
+    /// background.
+    pub fn on_rgba(self, color: Rgba) -> Self {
+        self.with_background(ColorSpec::Rgba(color))
+    }
+
+    /// Apply a foreground gradient through evenly spaced stops.
+    ///
+    /// Each grapheme cluster gets its own interpolated color. At lower color
+    /// levels each color is quantized separately, and neighbouring clusters
+    /// that quantize to the same color share one escape sequence.
+    pub fn gradient(self, stops: &[Color]) -> Self {
+        self.gradient_with(&Gradient::from(stops))
+    }
+
+    /// Apply a background gradient through evenly spaced stops.
+    pub fn on_gradient(self, stops: &[Color]) -> Self {
+        self.on_gradient_with(&Gradient::from(stops))
+    }
+
+    /// Apply a foreground gradient with a custom interpolation space or preset.
+    pub fn gradient_with(self, gradient: &Gradient) -> Self {
+        self.with_foreground(ColorSpec::Gradient(gradient.clone()))
+    }
+
+    /// Apply a background gradient with a custom interpolation space or preset.
+    pub fn on_gradient_with(self, gradient: &Gradient) -> Self {
+        self.with_background(ColorSpec::Gradient(gradient.clone()))
+    }
+
+    /// Apply the foreground color `map` gives `value` within `min` to `max`.
+    ///
+    /// Values outside the range are clamped to its ends.
+    pub fn colormap(self, value: f32, min: f32, max: f32, map: &Colormap) -> Self {
+        let (r, g, b) = map.sample_range(value, min, max).to_rgb();
+        self.rgb(r, g, b)
+    }
+
+    /// Apply the background color `map` gives `value` within `min` to `max`.
+    ///
+    /// Values outside the range are clamped to its ends.
+    pub fn on_colormap(self, value: f32, min: f32, max: f32, map: &Colormap) -> Self {
+        let (r, g, b) = map.sample_range(value, min, max).to_rgb();
         self.on_rgb(r, g, b)
     }
 
+    /// Set how RGB colors are dithered when quantized for this value.
+    ///
+    /// Dithering is off by default. When enabled, RGB, HSL, hex, adaptive, and
+    /// gradient colors rendered at [`ColorLevel::Ansi256`],
+    /// [`ColorLevel::Ansi16`], or [`ColorLevel::Ansi8`] choose between the two
+    /// nearest palette entries for each grapheme cluster, so ramps look smooth
+    /// instead of banded. Truecolor output and named or ANSI 256 colors are
+    /// unaffected. The setting survives [`StyledText::clear`] because it
+    /// controls rendering rather than styling.
+    pub fn dither(mut self, dither: Dither) -> Self {
+        self.dither = dither;
+        self
+    }
+
     /// Apply a hex foreground color.
     ///
     /// Invalid input clears all styling and returns plain text.
@@ -373,6 +704,26 @@ impl StyledText {
         }
     }
 
+    /// Apply an RGB background with a foreground that is readable on it.
+    ///
+    /// An existing RGB, HSL, or hex foreground is kept if it meets `target`,
+    /// otherwise its lightness is adjusted with [`Color::readable_on`]. Any
+    /// other foreground is replaced by black or white, whichever contrasts
+    /// more by `target`'s measure. Contrast is computed on the RGB values
+    /// before any color-level degradation.
+    pub fn readable_on(self, background: Color, target: ContrastTarget) -> Self {
+        let foreground = match self.foreground {
+            Some(ColorSpec::Rgb(r, g, b)) => Color::rgb(r, g, b).readable_on(background, target),
+            _ => contrasting(background, target),
+        };
+
+        self.rgb(foreground.r, foreground.g, foreground.b).on_rgb(
+            background.r,
+            background.g,
+            background.b,
+        )
+    }
+
     /// Remove all applied styling and return plain text.
     pub fn clear(mut self) -> Self {
         self.foreground = None;
@@ -382,6 +733,33 @@ impl StyledText {
         self
     }
 
+    /// Apply a [`Style`] on top of the current styling.
+    ///
+    /// Colors set by the style replace the current ones, while its attributes
+    /// and raw codes are added to them.
+    pub fn style(mut self, style: &Style) -> Self {
+        if let Some(color) = &style.foreground {
+            self.foreground = Some(color.clone());
+        }
+        if let Some(color) = &style.background {
+            self.background = Some(color.clone());
+        }
+        self.styles.merge(&style.styles);
+        self.raw_codes.extend(style.raw_codes.iter().cloned());
+        self
+    }
+
+    /// Apply the active theme's style for a role, such as `"error"`.
+    ///
+    /// Roles the theme does not define leave the text unchanged. See
+    /// [`crate::Theme`] for how the active theme is chosen.
+    pub fn role(self, role: &str) -> Self {
+        match role_style(role) {
+            Some(style) => self.style(&style),
+            None => self,
+        }
+    }
+
     /// Render the styled value for a specific output target.
     ///
     /// This is useful when the caller knows the real destination is stderr or a
@@ -389,22 +767,148 @@ impl StyledText {
     /// destination instead of the default stdout-based behavior used by
     /// [`Display`].
     pub fn render(&self, target: RenderTarget) -> String {
-        self.render_with_color_level(color_level_for(target))
+        self.render_with_context(render_context_for(target))
     }
 
-    fn render_with_color_level(&self, level: ColorLevel) -> String {
-        let codes = self.active_codes(level);
+    fn render_with_context(&self, context: RenderContext) -> String {
+        if context.level != ColorLevel::NoColor
+            && (self.has_gradient()
+                || (self.has_rgb_color() && self.dither.applies_to(context.level)))
+        {
+            return self.render_graphemes(context);
+        }
+
+        let codes = self.active_codes(context);
         if codes.is_empty() {
             return self.text.clone();
         }
 
         format!("\x1b[{}m{}\x1b[0m", codes.join(";"), self.text)
     }
+
+    /// Render one run per group of neighbouring grapheme clusters that share
+    /// the same codes, followed by a single reset.
+    fn render_graphemes(&self, context: RenderContext) -> String {
+        let clusters = graphemes(&self.text);
+        let steps = clusters.len().saturating_sub(1).max(1) as f32;
+        let mut foreground_dither = Ditherer::new(self.dither, context);
+        let mut background_dither = foreground_dither.clone();
+        let mut runs: Vec<(Vec<String>, String)> = Vec::new();
+
+        for (index, cluster) in clusters.iter().enumerate() {
+            let t = index as f32 / steps;
+            let (original_foreground, original_background) = self.colors_at(context, t);
+            let background = original_background
+                .clone()
+                .map(|color| background_dither.apply(color, index));
+            let foreground = original_foreground.map(|color| {
+                let dithered = foreground_dither.apply(color.clone(), index);
+                separate_from_background(
+                    context,
+                    (&color, original_background.as_ref()),
+                    dithered,
+                    background.as_ref(),
+                )
+            });
+            let codes = self.codes_for(context, foreground.as_ref(), background.as_ref());
+
+            match runs.last_mut() {
+                Some((run_codes, text)) if *run_codes == codes => text.push_str(cluster),
+                _ => runs.push((codes, cluster.to_string())),
+            }
+        }
+
+        if runs.is_empty() {
+            runs.push((self.active_codes(context), String::new()));
+        }
+
+        // Runs are not reset between each other, so bold implied by an
+        // 8-color bright foreground must be cleared when the next run drops it.
+        let mut output = String::new();
+        let mut bold = false;
+        for (mut codes, text) in runs {
+            let run_bold = codes.iter().any(|code| code == "1");
+            if bold && !run_bold {
+                codes.insert(0, "22".to_string());
+            }
+            bold = run_bold;
+            output.push_str(&format!("\x1b[{}m", codes.join(";")));
+            output.push_str(&text);
+        }
+        output.push_str("\x1b[0m");
+        output
+    }
+}
+
+/// Return the foreground to render over `background`, keeping an RGB
+/// foreground distinguishable when both are downgraded to a limited palette.
+///
+/// `original` is the color pair before dithering, and `foreground` and
+/// `background` are the colors about to be rendered. When the rendered pair,
+/// once quantized, falls below [`MIN_DOWNGRADED_CONTRAST`], or below the
+/// contrast of the original pair if that was already lower, the perceptually
+/// nearest palette entry to the original foreground that restores it is used
+/// instead. Named and ANSI 256 foregrounds, including those a
+/// [`FallbackColor`] picks for the level, are explicit choices and are left
+/// alone.
+fn separate_from_background(
+    context: RenderContext,
+    original: (&ColorSpec, Option<&ColorSpec>),
+    foreground: ColorSpec,
+    background: Option<&ColorSpec>,
+) -> ColorSpec {
+    let original_foreground = match original.0 {
+        ColorSpec::Fallback(color) => color.spec_for(context.level),
+        color => color.clone(),
+    };
+    let (ColorSpec::Rgb(r, g, b), Some(original_background), Some(background)) =
+        (original_foreground, original.1, background)
+    else {
+        return foreground;
+    };
+    if matches!(context.level, ColorLevel::NoColor | ColorLevel::TrueColor) {
+        return foreground;
+    }
+
+    let original = Color::rgb(r, g, b);
+    let wanted =
+        MIN_DOWNGRADED_CONTRAST.min(original.contrast_ratio(original_background.to_color(context)));
+    let background = background.quantized(context).to_color(context);
+    if foreground
+        .quantized(context)
+        .to_color(context)
+        .contrast_ratio(background)
+        >= wanted
+    {
+        return foreground;
+    }
+
+    let target = if context.grayscale {
+        original.grayscale()
+    } else {
+        original
+    };
+    quantization_palette(context)
+        .iter()
+        .filter(|(_, rgb)| Color::from(*rgb).contrast_ratio(background) >= wanted)
+        .min_by(|(_, a), (_, b)| {
+            replacement_distance(target, *a).total_cmp(&replacement_distance(target, *b))
+        })
+        .map_or(foreground, |(color, _)| color.clone())
+}
+
+/// Return how far a palette entry strays from the intended color, in Oklab
+/// with hue and chroma changes weighted double so that a gray foreground is
+/// replaced by a lighter or darker gray rather than a dull hue.
+fn replacement_distance(target: Color, candidate: (u8, u8, u8)) -> f32 {
+    let (l1, a1, b1) = rgb_to_oklab(target.r, target.g, target.b);
+    let (l2, a2, b2) = rgb_to_oklab(candidate.0, candidate.1, candidate.2);
+    (l1 - l2).powi(2) + 4.0 * ((a1 - a2).powi(2) + (b1 - b2).powi(2))
 }
 
 impl Display for StyledText {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.render_with_color_level(color_level()))
+        f.write_str(&self.render_with_context(render_context()))
     }
 }
 
@@ -516,10 +1020,87 @@ pub trait Colorize {
     fn hsl(&self, h: f32, s: f32, l: f32) -> StyledText;
     /// Convert HSL to RGB and apply it to the background.
     fn on_hsl(&self, h: f32, s: f32, l: f32) -> StyledText;
+
+    /// Apply a foreground color that adapts to the terminal background.
+    fn adaptive(&self, color: AdaptiveColor) -> StyledText {
+        self.clear().adaptive(color)
+    }
+
+    /// Apply a background color that adapts to the terminal background.
+    fn on_adaptive(&self, color: AdaptiveColor) -> StyledText {
+        self.clear().on_adaptive(color)
+    }
+
+    /// Apply a foreground color with hand-picked fallbacks.
+    fn fallback(&self, color: FallbackColor) -> StyledText {
+        self.clear().fallback(color)
+    }
+
+    /// Apply a background color with hand-picked fallbacks.
+    fn on_fallback(&self, color: FallbackColor) -> StyledText {
+        self.clear().on_fallback(color)
+    }
+
+    /// Apply a foreground color with alpha.
+    fn rgba(&self, color: Rgba) -> StyledText {
+        self.clear().rgba(color)
+    }
+
+    /// Apply a background color with alpha.
+    fn on_rgba(&self, color: Rgba) -> StyledText {
+        self.clear().on_rgba(color)
+    }
+
+    /// Apply a foreground gradient through evenly spaced stops.
+    fn gradient(&self, stops: &[Color]) -> StyledText {
+        self.clear().gradient(stops)
+    }
+
+    /// Apply a background gradient through evenly spaced stops.
+    fn on_gradient(&self, stops: &[Color]) -> StyledText {
+        self.clear().on_gradient(stops)
+    }
+
+    /// Apply a foreground gradient with a custom interpolation space or preset.
+    fn gradient_with(&self, gradient: &Gradient) -> StyledText {
+        self.clear().gradient_with(gradient)
+    }
+
+    /// Apply a background gradient with a custom interpolation space or preset.
+    fn on_gradient_with(&self, gradient: &Gradient) -> StyledText {
+        self.clear().on_gradient_with(gradient)
+    }
+
+    /// Apply the foreground color a colormap gives a value within a range.
+    fn colormap(&self, value: f32, min: f32, max: f32, map: &Colormap) -> StyledText {
+        self.clear().colormap(value, min, max, map)
+    }
+
+    /// Apply the background color a colormap gives a value within a range.
+    fn on_colormap(&self, value: f32, min: f32, max: f32, map: &Colormap) -> StyledText {
+        self.clear().on_colormap(value, min, max, map)
+    }
+
+    /// Set how RGB colors are dithered when quantized for this value.
+    fn dither(&self, dither: Dither) -> StyledText {
+        self.clear().dither(dither)
+    }
+
     /// Apply a hex foreground color, or plain text on invalid input.
     fn hex(&self, hex: &str) -> StyledText;
     /// Apply a hex background color, or plain text on invalid input.
     fn on_hex(&self, hex: &str) -> StyledText;
+
+    /// Apply a reusable [`Style`].
+    fn style(&self, style: &Style) -> StyledText {
+        self.clear().style(style)
+    }
+
+    /// Apply the active theme's style for a role, such as `"error"`.
+    fn role(&self, role: &str) -> StyledText {
+        self.clear().role(role)
+    }
+
     /// Remove all styling and return plain text.
     fn clear(&self) -> StyledText;
 }
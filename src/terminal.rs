@@ -1,8 +1,14 @@
-use crate::config::{ColorDepthMode, ColorMode};
+use crate::color::{ansi256_to_rgb, relative_luminance, Color};
+use crate::config::{ColorDepthMode, ColorMode, ExtendedColorSyntax};
+
+/// Luminance at which a background is treated as light. This is the midpoint
+/// of CIE lightness (`L* = 50`).
+const LIGHT_BACKGROUND_LUMINANCE: f32 = 0.184;
 
 /// Resolved color support level for an output target.
 ///
-/// Named colors and text styles render at [`ColorLevel::Ansi16`] and above.
+/// Named colors and text styles render at [`ColorLevel::Ansi8`] and above, with
+/// bright colors limited to [`ColorLevel::Ansi16`] and above.
 /// ANSI 256-color values render directly at [`ColorLevel::Ansi256`] and
 /// [`ColorLevel::TrueColor`], while RGB, HSL, and hex colors degrade to the
 /// nearest supported color level.
@@ -10,6 +16,12 @@ use crate::config::{ColorDepthMode, ColorMode};
 pub enum ColorLevel {
     /// Do not emit ANSI SGR sequences.
     NoColor,
+    /// Emit only the eight base ANSI colors (30-37 and 40-47) and text styles.
+    ///
+    /// Bright foregrounds render as their base color in bold, bright
+    /// backgrounds as their base color, and other colors degrade to the
+    /// nearest base color.
+    Ansi8,
     /// Emit named ANSI colors and text styles.
     Ansi16,
     /// Emit ANSI 256-color sequences.
@@ -18,18 +30,69 @@ pub enum ColorLevel {
     TrueColor,
 }
 
+/// Brightness of a terminal's background.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum Background {
+    /// A light background that needs dark text.
+    Light,
+    /// A dark background that needs light text.
+    Dark,
+}
+
+impl Background {
+    /// Classify an RGB background color, such as an OSC 11 query reply.
+    pub fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        if relative_luminance(r, g, b) >= LIGHT_BACKGROUND_LUMINANCE {
+            Self::Light
+        } else {
+            Self::Dark
+        }
+    }
+}
+
 /// Resolved terminal capability information for an output target.
 ///
 /// Values returned by [`crate::ColorizeConfig::terminal_capabilities`] are
 /// detected heuristically from the target and environment. Values supplied via
 /// [`crate::RenderTarget::Capabilities`] are treated as exact caller knowledge,
 /// subject only to hard color-disabling policy.
+///
+/// New fields may be added in minor releases, so build values with
+/// [`TerminalCapabilities::new`] and set fields on the result.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct TerminalCapabilities {
     /// Whether the target is known to be a terminal.
     pub is_terminal: bool,
     /// The color depth this target is expected to support.
     pub color_level: ColorLevel,
+    /// The background brightness, when known.
+    pub background: Option<Background>,
+    /// The exact background color, when known.
+    ///
+    /// Alpha colors are composited against this color. When it is unknown
+    /// they are composited against black or white, following `background`.
+    pub background_color: Option<Color>,
+    /// Whether the target can only show shades of gray.
+    ///
+    /// Grayscale targets render every color as a gray of the same luminance.
+    pub grayscale: bool,
+    /// The syntax used for ANSI 256 and RGB color codes.
+    pub extended_color_syntax: ExtendedColorSyntax,
+}
+
+impl TerminalCapabilities {
+    /// Create capabilities with an unknown background.
+    pub const fn new(is_terminal: bool, color_level: ColorLevel) -> Self {
+        Self {
+            is_terminal,
+            color_level,
+            background: None,
+            background_color: None,
+            grayscale: false,
+            extended_color_syntax: ExtendedColorSyntax::Semicolon,
+        }
+    }
 }
 
 pub(crate) trait EnvProvider {
@@ -56,13 +119,42 @@ pub(crate) fn terminal_capabilities(
     is_terminal: bool,
     color_mode: ColorMode,
     depth_mode: ColorDepthMode,
+    background: Option<Background>,
+    background_color: Option<Color>,
+    extended_color_syntax: ExtendedColorSyntax,
 ) -> TerminalCapabilities {
+    // Only an explicit or queried color is classified by luminance.
+    // `COLORFGBG` names a palette index, whose brightness is fixed by
+    // `detect_background` even where the standard RGB value disagrees.
     TerminalCapabilities {
         is_terminal,
         color_level: detect_color_level(is_terminal, color_mode, depth_mode, &ProcessEnv),
+        background: background
+            .or_else(|| {
+                background_color.map(|color| Background::from_rgb(color.r, color.g, color.b))
+            })
+            .or_else(|| detect_background(&ProcessEnv)),
+        background_color: background_color.or_else(|| detect_background_color(&ProcessEnv)),
+        grayscale: grayscale_requested(depth_mode, &ProcessEnv),
+        extended_color_syntax,
     }
 }
 
+/// Return whether the depth mode or `FORCE_COLOR` asks for grayscale output.
+pub(crate) fn grayscale_requested(depth_mode: ColorDepthMode, env: &impl EnvProvider) -> bool {
+    depth_mode == ColorDepthMode::Grayscale
+        || env
+            .get("FORCE_COLOR")
+            .is_some_and(|value| is_grayscale_value(&normalize_env_value(&value)))
+}
+
+fn is_grayscale_value(value: &str) -> bool {
+    matches!(
+        value,
+        "grayscale" | "greyscale" | "gray" | "grey" | "monochrome" | "mono"
+    )
+}
+
 pub(crate) fn color_level_for_capabilities(
     capabilities: TerminalCapabilities,
     color_mode: ColorMode,
@@ -100,7 +192,7 @@ pub(crate) fn detect_color_level(
     }
 
     let mut level = match detect_env_color_level(env) {
-        Some(detected) if clicolor_forced => detected.max(ColorLevel::Ansi16),
+        Some(ColorLevel::NoColor) if clicolor_forced => ColorLevel::Ansi16,
         Some(detected) => detected,
         None if clicolor_forced => ColorLevel::Ansi16,
         None => ColorLevel::Ansi16,
@@ -113,6 +205,24 @@ pub(crate) fn detect_color_level(
     level
 }
 
+/// Return the color level that `FORCE_COLOR` or an explicit depth mode pins
+/// output to, if any.
+///
+/// Runtime evidence such as terminal query replies must not raise the level
+/// past this limit. A grayscale `FORCE_COLOR` value names a rendering style
+/// rather than a level, so it sets no limit.
+pub(crate) fn color_level_limit(
+    depth_mode: ColorDepthMode,
+    env: &impl EnvProvider,
+) -> Option<ColorLevel> {
+    let grayscale = env
+        .get("FORCE_COLOR")
+        .is_some_and(|value| is_grayscale_value(&normalize_env_value(&value)));
+    let forced = force_color_level(env).filter(|_| !grayscale);
+
+    forced.or_else(|| explicit_depth_level(depth_mode))
+}
+
 fn hard_disable_level(
     color_mode: ColorMode,
     depth_mode: ColorDepthMode,
@@ -135,18 +245,25 @@ fn force_color_level(env: &impl EnvProvider) -> Option<ColorLevel> {
     match normalized.as_str() {
         "" => None,
         "0" | "no_color" | "none" | "never" | "false" | "off" => Some(ColorLevel::NoColor),
+        "ansi8" | "8" | "8color" | "8-color" => Some(ColorLevel::Ansi8),
         "1" | "ansi" | "ansi16" | "basic" | "true" | "yes" | "on" => Some(ColorLevel::Ansi16),
         "2" | "ansi256" | "256" | "8bit" | "8-bit" => Some(ColorLevel::Ansi256),
         "3" | "truecolor" | "true_color" | "24bit" | "24-bit" | "16m" => {
             Some(ColorLevel::TrueColor)
         }
+        value if is_grayscale_value(value) => Some(
+            detect_env_color_level(env)
+                .filter(|level| *level != ColorLevel::NoColor)
+                .unwrap_or(ColorLevel::Ansi16),
+        ),
         _ => Some(ColorLevel::Ansi16),
     }
 }
 
 fn explicit_depth_level(depth_mode: ColorDepthMode) -> Option<ColorLevel> {
     match depth_mode {
-        ColorDepthMode::Auto | ColorDepthMode::NoColor => None,
+        ColorDepthMode::Auto | ColorDepthMode::NoColor | ColorDepthMode::Grayscale => None,
+        ColorDepthMode::Ansi8 => Some(ColorLevel::Ansi8),
         ColorDepthMode::Ansi16 => Some(ColorLevel::Ansi16),
         ColorDepthMode::Ansi256 => Some(ColorLevel::Ansi256),
         ColorDepthMode::TrueColor => Some(ColorLevel::TrueColor),
@@ -191,6 +308,13 @@ fn detect_env_color_level(env: &impl EnvProvider) -> Option<ColorLevel> {
         return Some(ColorLevel::Ansi256);
     }
 
+    if env
+        .get("TERM")
+        .is_some_and(|term| is_eight_color_term(&term))
+    {
+        return Some(ColorLevel::Ansi8);
+    }
+
     if env
         .get("ConEmuANSI")
         .is_some_and(|value| value.eq_ignore_ascii_case("ON"))
@@ -207,6 +331,63 @@ fn detect_env_color_level(env: &impl EnvProvider) -> Option<ColorLevel> {
     None
 }
 
+/// Terminals known to support only the eight base colors, such as the Linux
+/// virtual console and DEC VT-series terminals.
+fn is_eight_color_term(term: &str) -> bool {
+    matches!(
+        normalize_env_value(term).as_str(),
+        "linux" | "vt100" | "vt102" | "vt220" | "vt320" | "ansi" | "cons25" | "xterm-old" | "sun"
+    )
+}
+
+pub(crate) fn detect_background(env: &impl EnvProvider) -> Option<Background> {
+    if let Some(background) = env
+        .get("COLORFGBG")
+        .and_then(|value| colorfgbg_background(&value))
+    {
+        return Some(background);
+    }
+
+    env.get("ITERM_PROFILE")
+        .and_then(|profile| background_from_name(&profile))
+}
+
+/// Return the standard palette color of the `COLORFGBG` background index.
+pub(crate) fn detect_background_color(env: &impl EnvProvider) -> Option<Color> {
+    let index = env
+        .get("COLORFGBG")
+        .and_then(|value| colorfgbg_index(&value))?;
+    (index <= 15).then(|| Color::from(ansi256_to_rgb(index)))
+}
+
+/// Parse the background palette index from `COLORFGBG` values such as `15;0`
+/// or `0;default;15`, where it is the last field.
+fn colorfgbg_index(value: &str) -> Option<u8> {
+    value.rsplit(';').next()?.trim().parse().ok()
+}
+
+/// Indexes 0-6 and 8 are the dark half of the standard palette, and 7 and
+/// 9-15 are the light half.
+fn colorfgbg_background(value: &str) -> Option<Background> {
+    match colorfgbg_index(value)? {
+        0..=6 | 8 => Some(Background::Dark),
+        7 | 9..=15 => Some(Background::Light),
+        _ => None,
+    }
+}
+
+fn background_from_name(name: &str) -> Option<Background> {
+    let name = normalize_env_value(name);
+    let light = name.contains("light");
+    let dark = name.contains("dark");
+
+    match (light, dark) {
+        (true, false) => Some(Background::Light),
+        (false, true) => Some(Background::Dark),
+        _ => None,
+    }
+}
+
 fn normalize_env_value(value: &str) -> String {
     value.trim().to_ascii_lowercase()
 }
@@ -1,12 +1,17 @@
 use crate::color::{
-    ansi256_to_named_color, ansi256_to_rgb, rgb_to_ansi256, rgb_to_named_color, ColorSpec,
-    NamedColor,
+    ansi256_to_named_color, ansi256_to_rgb, quantization_palette, rgb_to_ansi256,
+    rgb_to_ansi256_gray, rgb_to_named_color, ColorSpec, NamedColor, RenderContext,
 };
 use crate::config::{
     get_stderr_terminal_override_for_tests, get_terminal_override_for_tests,
     set_stderr_terminal_override_for_tests, set_terminal_override_for_tests,
 };
-use crate::terminal::{detect_color_level, tests::TestEnv};
+use crate::grapheme::graphemes;
+use crate::terminal::{
+    detect_background, detect_background_color, detect_color_level, grayscale_requested,
+    tests::TestEnv,
+};
+use crate::theme::warned_about_role_for_tests;
 use crate::*;
 use rstest::*;
 use std::env;
@@ -15,7 +20,7 @@ use std::io::IsTerminal;
 use std::sync::{LazyLock, Mutex, MutexGuard};
 
 static TEST_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
-const COLOR_ENV_KEYS: [&str; 10] = [
+const COLOR_ENV_KEYS: [&str; 13] = [
     "NO_COLOR",
     "FORCE_COLOR",
     "CLICOLOR",
@@ -26,12 +31,25 @@ const COLOR_ENV_KEYS: [&str; 10] = [
     "WT_SESSION",
     "ConEmuANSI",
     "ANSICON",
+    "COLORFGBG",
+    "ITERM_PROFILE",
+    "XDG_CONFIG_HOME",
 ];
 
 struct TestStateGuard {
     _lock: MutexGuard<'static, ()>,
     previous_mode: ColorMode,
     previous_depth_mode: ColorDepthMode,
+    previous_background: Option<Background>,
+    previous_background_color: Option<Color>,
+    previous_color_transform: ColorTransform,
+    previous_default_background: Background,
+    previous_extended_color_syntax: ExtendedColorSyntax,
+    previous_theme: Option<Theme>,
+    previous_process_theme: Option<Theme>,
+    previous_warn_unknown_roles: bool,
+    #[cfg(feature = "serde")]
+    previous_user_config: Option<UserConfig>,
     previous_env: Vec<(&'static str, Option<OsString>)>,
     previous_terminal_override: Option<bool>,
     previous_stderr_terminal_override: Option<bool>,
@@ -74,6 +92,16 @@ impl TestStateGuard {
             .unwrap_or_else(|poisoned| poisoned.into_inner());
         let previous_mode = ColorizeConfig::color_mode();
         let previous_depth_mode = ColorizeConfig::color_depth_mode();
+        let previous_background = ColorizeConfig::background();
+        let previous_background_color = ColorizeConfig::background_color();
+        let previous_color_transform = ColorizeConfig::color_transform();
+        let previous_default_background = ColorizeConfig::default_background();
+        let previous_extended_color_syntax = ColorizeConfig::extended_color_syntax();
+        let previous_theme = ColorizeConfig::theme();
+        let previous_process_theme = ColorizeConfig::process_theme();
+        let previous_warn_unknown_roles = ColorizeConfig::warn_unknown_roles();
+        #[cfg(feature = "serde")]
+        let previous_user_config = ColorizeConfig::user_config();
         let previous_env = COLOR_ENV_KEYS
             .into_iter()
             .map(|key| (key, env::var_os(key)))
@@ -92,6 +120,16 @@ impl TestStateGuard {
         env::set_var("TERM", "xterm-256color");
         ColorizeConfig::set_color_mode(mode);
         ColorizeConfig::set_color_depth_mode(depth_mode);
+        ColorizeConfig::set_background(None);
+        ColorizeConfig::set_background_color(None);
+        ColorizeConfig::set_color_transform(ColorTransform::None);
+        ColorizeConfig::set_default_background(Background::Dark);
+        ColorizeConfig::set_extended_color_syntax(ExtendedColorSyntax::Semicolon);
+        ColorizeConfig::set_theme(None);
+        ColorizeConfig::set_process_theme(None);
+        ColorizeConfig::set_warn_unknown_roles(false);
+        #[cfg(feature = "serde")]
+        ColorizeConfig::set_user_config(None);
         set_terminal_override_for_tests(terminal_override);
         set_stderr_terminal_override_for_tests(None);
 
@@ -99,6 +137,16 @@ impl TestStateGuard {
             _lock: guard,
             previous_mode,
             previous_depth_mode,
+            previous_background,
+            previous_background_color,
+            previous_color_transform,
+            previous_default_background,
+            previous_extended_color_syntax,
+            previous_theme,
+            previous_process_theme,
+            previous_warn_unknown_roles,
+            #[cfg(feature = "serde")]
+            previous_user_config,
             previous_env,
             previous_terminal_override,
             previous_stderr_terminal_override,
@@ -106,10 +154,53 @@ impl TestStateGuard {
     }
 }
 
+struct TempDir(std::path::PathBuf);
+
+impl TempDir {
+    fn new() -> Self {
+        static NEXT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        let index = NEXT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let path =
+            env::temp_dir().join(format!("colored_text_test_{}_{index}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    fn write(&self, name: &str, contents: &str) -> std::path::PathBuf {
+        let path = self.0.join(name);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn context(level: ColorLevel) -> RenderContext {
+    RenderContext::new(level, Background::Dark)
+}
+
 impl Drop for TestStateGuard {
     fn drop(&mut self) {
         ColorizeConfig::set_color_mode(self.previous_mode);
         ColorizeConfig::set_color_depth_mode(self.previous_depth_mode);
+        ColorizeConfig::set_background(self.previous_background);
+        ColorizeConfig::set_background_color(self.previous_background_color);
+        ColorizeConfig::set_color_transform(self.previous_color_transform);
+        ColorizeConfig::set_default_background(self.previous_default_background);
+        ColorizeConfig::set_extended_color_syntax(self.previous_extended_color_syntax);
+        ColorizeConfig::set_theme(self.previous_theme.take());
+        ColorizeConfig::set_process_theme(self.previous_process_theme.take());
+        ColorizeConfig::set_warn_unknown_roles(self.previous_warn_unknown_roles);
+        #[cfg(feature = "serde")]
+        ColorizeConfig::set_user_config(self.previous_user_config.take());
         set_terminal_override_for_tests(self.previous_terminal_override);
         set_stderr_terminal_override_for_tests(self.previous_stderr_terminal_override);
         for (key, value) in &self.previous_env {
@@ -512,6 +603,92 @@ fn test_hsl_edge_cases() {
     assert_hsl_rgb(120.0, 100.0, 75.0, 128, 255, 128);
 }
 
+#[test]
+fn test_hsl_rounds_to_nearest_rgb() {
+    assert_eq!(Hsl::new(0.0, 100.0, 50.0).to_color(), Color::rgb(255, 0, 0));
+    assert_eq!(
+        Hsl::new(0.0, 0.0, 50.0).to_color(),
+        Color::rgb(128, 128, 128)
+    );
+    assert_eq!(
+        Hsl::new(0.0, 0.0, 75.0).to_color(),
+        Color::rgb(191, 191, 191)
+    );
+    assert_eq!(
+        Hsl::new(120.0, 100.0, 25.0).to_color(),
+        Color::rgb(0, 128, 0)
+    );
+    assert_eq!(
+        Hsl::new(30.0, 100.0, 50.0).to_color(),
+        Color::rgb(255, 128, 0)
+    );
+}
+
+#[test]
+fn test_hsl_normalizes_components() {
+    assert_eq!(Hsl::new(-120.0, 100.0, 50.0), Hsl::new(240.0, 100.0, 50.0));
+    assert_eq!(Hsl::new(480.0, 100.0, 50.0), Hsl::new(120.0, 100.0, 50.0));
+    assert_eq!(Hsl::new(360.0, 100.0, 50.0).hue(), 0.0);
+    assert_eq!(Hsl::new(-1e-7, 100.0, 50.0).hue(), 0.0);
+    assert_eq!(Hsl::new(0.0, 150.0, -10.0), Hsl::new(0.0, 100.0, 0.0));
+    assert_eq!(
+        Hsl::new(-120.0, 100.0, 50.0).to_color(),
+        Color::rgb(0, 0, 255)
+    );
+
+    let nan = Hsl::new(f32::NAN, f32::NAN, f32::NAN);
+    assert_eq!(nan, Hsl::new(0.0, 0.0, 0.0));
+    assert_eq!(Hsl::new(f32::INFINITY, 100.0, 50.0).hue(), 0.0);
+    assert_eq!(Hsl::new(0.0, 100.0, f32::INFINITY).lightness(), 100.0);
+
+    assert_eq!(Hsla::new(0.0, 100.0, 50.0, 2.0).alpha(), 1.0);
+    assert_eq!(Hsla::new(0.0, 100.0, 50.0, -1.0).alpha(), 0.0);
+    assert_eq!(Hsla::new(0.0, 100.0, 50.0, f32::NAN).alpha(), 1.0);
+}
+
+#[test]
+fn test_hsl_round_trips_through_rgb() {
+    for color in [
+        Color::rgb(0, 0, 0),
+        Color::rgb(255, 255, 255),
+        Color::rgb(255, 128, 0),
+        Color::rgb(18, 52, 86),
+        Color::rgb(200, 10, 150),
+    ] {
+        assert_eq!(color.to_hsl().to_color(), color);
+    }
+
+    let hsl = Color::rgb(0, 128, 255).to_hsl();
+    assert_eq!(hsl.hue().round(), 210.0);
+    assert_eq!(hsl.saturation().round(), 100.0);
+    assert_eq!(hsl.lightness().round(), 50.0);
+
+    let rgba = Rgba::new(255, 0, 0, 128);
+    assert_eq!(Rgba::from(Hsla::from(rgba)), rgba);
+    assert_eq!(
+        Hsla::new(120.0, 100.0, 50.0, 0.5).to_rgba(),
+        Rgba::new(0, 255, 0, 128)
+    );
+}
+
+#[test]
+fn test_hsl_methods_use_normalized_hsl() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+
+    assert_eq!(
+        "test".hsl(-120.0, 100.0, 50.0).to_string(),
+        "test".rgb(0, 0, 255).to_string()
+    );
+    assert_eq!(
+        "test".on_hsl(0.0, 100.0, 50.0).to_string(),
+        "test".on_rgb(255, 0, 0).to_string()
+    );
+    assert_eq!(
+        "test".hsl(f32::NAN, 200.0, 50.0).to_string(),
+        "test".rgb(255, 0, 0).to_string()
+    );
+}
+
 #[test]
 fn test_hsl_background_colors() {
     let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
@@ -921,10 +1098,7 @@ fn test_color_depth_mode_accessors() {
 fn test_terminal_capabilities_for_known_target() {
     let _guard =
         TestStateGuard::with_depth(ColorMode::Auto, ColorDepthMode::Auto, None, Some(false));
-    let target = RenderTarget::Capabilities(TerminalCapabilities {
-        is_terminal: false,
-        color_level: ColorLevel::Ansi256,
-    });
+    let target = RenderTarget::Capabilities(TerminalCapabilities::new(false, ColorLevel::Ansi256));
 
     assert_eq!(ColorizeConfig::color_level(target), ColorLevel::Ansi256);
     assert_eq!(
@@ -937,10 +1111,7 @@ fn test_terminal_capabilities_for_known_target() {
 fn test_terminal_capabilities_accessor_returns_known_target_capabilities() {
     let _guard =
         TestStateGuard::with_depth(ColorMode::Auto, ColorDepthMode::Auto, None, Some(false));
-    let expected = TerminalCapabilities {
-        is_terminal: true,
-        color_level: ColorLevel::Ansi256,
-    };
+    let expected = TerminalCapabilities::new(true, ColorLevel::Ansi256);
 
     assert_eq!(
         ColorizeConfig::terminal_capabilities(RenderTarget::Capabilities(expected)),
@@ -951,10 +1122,7 @@ fn test_terminal_capabilities_accessor_returns_known_target_capabilities() {
 #[test]
 fn test_terminal_capabilities_respect_color_mode_never() {
     let _guard = TestStateGuard::colors_enabled(ColorMode::Never);
-    let target = RenderTarget::Capabilities(TerminalCapabilities {
-        is_terminal: true,
-        color_level: ColorLevel::TrueColor,
-    });
+    let target = RenderTarget::Capabilities(TerminalCapabilities::new(true, ColorLevel::TrueColor));
 
     assert_eq!(ColorizeConfig::color_level(target), ColorLevel::NoColor);
     assert_eq!("test".red().render(target), "test");
@@ -963,10 +1131,7 @@ fn test_terminal_capabilities_respect_color_mode_never() {
 #[test]
 fn test_terminal_capabilities_respect_no_color_env() {
     let _guard = TestStateGuard::no_color(ColorMode::Always);
-    let target = RenderTarget::Capabilities(TerminalCapabilities {
-        is_terminal: true,
-        color_level: ColorLevel::TrueColor,
-    });
+    let target = RenderTarget::Capabilities(TerminalCapabilities::new(true, ColorLevel::TrueColor));
 
     assert_eq!(ColorizeConfig::color_level(target), ColorLevel::NoColor);
     assert_eq!("test".red().render(target), "test");
@@ -976,10 +1141,7 @@ fn test_terminal_capabilities_respect_no_color_env() {
 fn test_terminal_capabilities_respect_color_depth_no_color() {
     let _guard =
         TestStateGuard::with_depth(ColorMode::Always, ColorDepthMode::NoColor, None, Some(true));
-    let target = RenderTarget::Capabilities(TerminalCapabilities {
-        is_terminal: true,
-        color_level: ColorLevel::TrueColor,
-    });
+    let target = RenderTarget::Capabilities(TerminalCapabilities::new(true, ColorLevel::TrueColor));
 
     assert_eq!(ColorizeConfig::color_level(target), ColorLevel::NoColor);
     assert_eq!("test".red().render(target), "test");
@@ -998,10 +1160,7 @@ fn test_terminal_capabilities_ignore_normal_target_env_overrides(
     let _guard =
         TestStateGuard::with_depth(ColorMode::Auto, ColorDepthMode::Auto, None, Some(false));
     env::set_var(key, value);
-    let target = RenderTarget::Capabilities(TerminalCapabilities {
-        is_terminal: true,
-        color_level: supplied,
-    });
+    let target = RenderTarget::Capabilities(TerminalCapabilities::new(true, supplied));
 
     assert_eq!(ColorizeConfig::color_level(target), expected);
 }
@@ -1025,10 +1184,7 @@ fn test_terminal_true_uses_env_depth_detection() {
 #[case(ColorLevel::NoColor, "test")]
 fn test_rgb_degrades_by_color_level(#[case] level: ColorLevel, #[case] expected: &str) {
     let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
-    let target = RenderTarget::Capabilities(TerminalCapabilities {
-        is_terminal: true,
-        color_level: level,
-    });
+    let target = RenderTarget::Capabilities(TerminalCapabilities::new(true, level));
 
     assert_eq!("test".rgb(255, 128, 0).render(target), expected);
 }
@@ -1040,10 +1196,7 @@ fn test_rgb_degrades_by_color_level(#[case] level: ColorLevel, #[case] expected:
 #[case(ColorLevel::NoColor, "test")]
 fn test_ansi256_degrades_by_color_level(#[case] level: ColorLevel, #[case] expected: &str) {
     let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
-    let target = RenderTarget::Capabilities(TerminalCapabilities {
-        is_terminal: true,
-        color_level: level,
-    });
+    let target = RenderTarget::Capabilities(TerminalCapabilities::new(true, level));
 
     assert_eq!("test".ansi256(208).render(target), expected);
 }
@@ -1055,10 +1208,7 @@ fn test_ansi256_degrades_by_color_level(#[case] level: ColorLevel, #[case] expec
 #[case(ColorLevel::NoColor, "test")]
 fn test_rgb_background_degrades_by_color_level(#[case] level: ColorLevel, #[case] expected: &str) {
     let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
-    let target = RenderTarget::Capabilities(TerminalCapabilities {
-        is_terminal: true,
-        color_level: level,
-    });
+    let target = RenderTarget::Capabilities(TerminalCapabilities::new(true, level));
 
     assert_eq!("test".on_rgb(255, 128, 0).render(target), expected);
 }
@@ -1073,10 +1223,7 @@ fn test_ansi256_background_degrades_by_color_level(
     #[case] expected: &str,
 ) {
     let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
-    let target = RenderTarget::Capabilities(TerminalCapabilities {
-        is_terminal: true,
-        color_level: level,
-    });
+    let target = RenderTarget::Capabilities(TerminalCapabilities::new(true, level));
 
     assert_eq!("test".on_ansi256(208).render(target), expected);
 }
@@ -1084,11 +1231,11 @@ fn test_ansi256_background_degrades_by_color_level(
 #[test]
 fn test_color_specs_return_none_without_color_support() {
     assert_eq!(
-        ColorSpec::Named(NamedColor::Red).foreground_code(ColorLevel::NoColor),
+        ColorSpec::Named(NamedColor::Red).foreground_code(context(ColorLevel::NoColor)),
         None
     );
     assert_eq!(
-        ColorSpec::Named(NamedColor::Red).background_code(ColorLevel::NoColor),
+        ColorSpec::Named(NamedColor::Red).background_code(context(ColorLevel::NoColor)),
         None
     );
 }
@@ -1128,10 +1275,7 @@ fn test_named_colors_remain_named_for_color_levels() {
         ColorLevel::Ansi256,
         ColorLevel::TrueColor,
     ] {
-        let target = RenderTarget::Capabilities(TerminalCapabilities {
-            is_terminal: true,
-            color_level: level,
-        });
+        let target = RenderTarget::Capabilities(TerminalCapabilities::new(true, level));
         assert_eq!("test".red().render(target), "\x1b[31mtest\x1b[0m");
     }
 }
@@ -1139,10 +1283,7 @@ fn test_named_colors_remain_named_for_color_levels() {
 #[test]
 fn test_no_color_suppresses_styles_and_raw_codes() {
     let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
-    let target = RenderTarget::Capabilities(TerminalCapabilities {
-        is_terminal: false,
-        color_level: ColorLevel::NoColor,
-    });
+    let target = RenderTarget::Capabilities(TerminalCapabilities::new(false, ColorLevel::NoColor));
 
     assert_eq!("test".bold().red().colorize("4").render(target), "test");
 }
@@ -1225,7 +1366,7 @@ fn test_raw_colorize_codes_still_render() {
 #[case(NamedColor::BrightWhite, "107")]
 fn test_bright_background_color_codes(#[case] color: NamedColor, #[case] expected: &str) {
     assert_eq!(
-        ColorSpec::Named(color).background_code(ColorLevel::Ansi16),
+        ColorSpec::Named(color).background_code(context(ColorLevel::Ansi16)),
         Some(expected.to_string())
     );
 }
@@ -1233,11 +1374,11 @@ fn test_bright_background_color_codes(#[case] color: NamedColor, #[case] expecte
 #[test]
 fn test_ansi256_color_codes() {
     assert_eq!(
-        ColorSpec::Ansi256(208).foreground_code(ColorLevel::Ansi256),
-        Some("38;5;208".to_string())
+        ColorSpec::Ansi256(208).foreground_code(context(ColorLevel::Ansi256)),
+        Some(("38;5;208".to_string(), false))
     );
     assert_eq!(
-        ColorSpec::Ansi256(236).background_code(ColorLevel::Ansi256),
+        ColorSpec::Ansi256(236).background_code(context(ColorLevel::Ansi256)),
         Some("48;5;236".to_string())
     );
 }
@@ -1263,3 +1404,2693 @@ fn test_assert_rgb_approx_eq_large_diff() {
     let color2 = "test".rgb(252, 0, 0).to_string();
     assert_rgb_approx_eq(&color1, &color2);
 }
+
+struct FakeTerminal {
+    replies: Vec<Vec<u8>>,
+}
+
+impl FakeTerminal {
+    fn replying(replies: &[&[u8]]) -> Self {
+        Self {
+            replies: replies.iter().rev().map(|reply| reply.to_vec()).collect(),
+        }
+    }
+}
+
+impl std::io::Read for FakeTerminal {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        match self.replies.pop() {
+            Some(reply) if reply.is_empty() => Err(std::io::ErrorKind::WouldBlock.into()),
+            Some(reply) => {
+                buffer[..reply.len()].copy_from_slice(&reply);
+                Ok(reply.len())
+            }
+            None => Err(std::io::ErrorKind::WouldBlock.into()),
+        }
+    }
+}
+
+#[test]
+fn test_terminal_query_request_bytes() {
+    let request = TerminalQuery::new().palette([1, 208]).request();
+    assert_eq!(
+        request,
+        b"\x1b]10;?\x1b\\\x1b]11;?\x1b\\\x1b]4;1;?\x1b\\\x1b]4;208;?\x1b\\\x1b[>0q\x1b[c"
+    );
+}
+
+#[test]
+fn test_terminal_query_writes_request_and_parses_replies() {
+    let mut terminal = FakeTerminal::replying(&[
+        b"\x1b]10;rgb:ffff/ffff/ffff\x1b\\",
+        b"",
+        b"\x1b]11;rgb:1e1e/1e1e/2e2e\x07\x1b]4;208;rgb:ff/87/00\x1b\\",
+        b"\x1bP>|XTerm(390)\x1b\\\x1b[?64;1;22c",
+    ]);
+    let mut written = Vec::new();
+    let query = TerminalQuery::new().palette([208]);
+
+    let report = query.run(&mut terminal, &mut written).unwrap();
+
+    assert_eq!(written, query.request());
+    assert_eq!(report.foreground, Some((255, 255, 255)));
+    assert_eq!(report.background, Some((30, 30, 46)));
+    assert_eq!(report.palette_color(208), Some((255, 135, 0)));
+    assert_eq!(report.palette_color(1), None);
+    assert_eq!(report.version.as_deref(), Some("XTerm(390)"));
+    assert_eq!(report.device_attributes, Some(vec![64, 1, 22]));
+}
+
+#[test]
+fn test_terminal_query_stops_at_a_split_da1_reply() {
+    let mut terminal = FakeTerminal::replying(&[
+        b"\x1b]11;rgb:0000/0000/0000\x07\x1b",
+        b"[?6",
+        b"2;22c",
+        b"\x1b]10;rgb:ffff/ffff/ffff\x07",
+    ]);
+
+    let report = TerminalQuery::new()
+        .run(&mut terminal, &mut Vec::new())
+        .unwrap();
+
+    assert_eq!(report.background, Some((0, 0, 0)));
+    assert_eq!(report.device_attributes, Some(vec![62, 22]));
+    assert_eq!(report.foreground, None);
+    assert_eq!(terminal.replies.len(), 1);
+}
+
+#[test]
+fn test_terminal_query_times_out_without_replies() {
+    let mut terminal = FakeTerminal::replying(&[]);
+    let report = TerminalQuery::new()
+        .timeout(std::time::Duration::from_millis(5))
+        .run(&mut terminal, &mut Vec::new())
+        .unwrap();
+
+    assert_eq!(report, TerminalReport::default());
+}
+
+#[test]
+fn test_terminal_query_keeps_partial_replies_after_timeout() {
+    let mut terminal = FakeTerminal::replying(&[b"\x1b]11;rgb:0000/0000/0000\x1b\\"]);
+    let report = TerminalQuery::new()
+        .timeout(std::time::Duration::from_millis(5))
+        .run(&mut terminal, &mut Vec::new())
+        .unwrap();
+
+    assert_eq!(report.background, Some((0, 0, 0)));
+    assert_eq!(report.device_attributes, None);
+}
+
+#[test]
+fn test_terminal_query_propagates_reader_errors() {
+    struct BrokenReader;
+
+    impl std::io::Read for BrokenReader {
+        fn read(&mut self, _buffer: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    let error = TerminalQuery::new()
+        .run(&mut BrokenReader, &mut Vec::new())
+        .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::BrokenPipe);
+}
+
+#[rstest]
+#[case(b"\x1b]11;rgb:f/8/0\x07", Some((255, 136, 0)))]
+#[case(b"\x1b]11;rgb:ff/80/00\x07", Some((255, 128, 0)))]
+#[case(b"\x1b]11;rgb:fff/800/000\x07", Some((255, 128, 0)))]
+#[case(b"\x1b]11;rgba:ffff/8080/0000/ffff\x1b\\", Some((255, 128, 0)))]
+#[case(b"\x1b]11;#ff8000\x07", Some((255, 128, 0)))]
+#[case(b"\x1b]11;rgb:zz/00/00\x07", None)]
+#[case(b"\x1b]11;rgb:ff/00\x07", None)]
+#[case(b"\x1b]11;#\xc3\xa9a\x07", None)]
+#[case(b"\x1b]11;rgb:+f/0/0\x07", None)]
+fn test_terminal_report_parses_color_specs(
+    #[case] reply: &[u8],
+    #[case] expected: Option<(u8, u8, u8)>,
+) {
+    assert_eq!(TerminalReport::parse(reply).background, expected);
+}
+
+#[test]
+fn test_terminal_report_ignores_noise_between_replies() {
+    let report =
+        TerminalReport::parse(b"abc\x1b[2J\x1b]4;1;rgb:80/00/00;2;rgb:00/80/00\x07x\x1b[?1;2c");
+
+    assert_eq!(report.palette, vec![(1, (128, 0, 0)), (2, (0, 128, 0))]);
+    assert_eq!(report.device_attributes, Some(vec![1, 2]));
+}
+
+#[rstest]
+#[case(TerminalReport { version: Some("kitty(0.35.2)".to_string()), ..Default::default() }, ColorLevel::Ansi16, ColorLevel::TrueColor)]
+#[case(TerminalReport { version: Some("WezTerm 20240203".to_string()), ..Default::default() }, ColorLevel::Ansi256, ColorLevel::TrueColor)]
+#[case(TerminalReport { palette: vec![(208, (255, 135, 0))], ..Default::default() }, ColorLevel::Ansi16, ColorLevel::Ansi256)]
+#[case(TerminalReport { palette: vec![(1, (128, 0, 0))], ..Default::default() }, ColorLevel::Ansi16, ColorLevel::Ansi16)]
+#[case(TerminalReport { device_attributes: Some(vec![62, 22]), ..Default::default() }, ColorLevel::Ansi16, ColorLevel::Ansi16)]
+#[case(TerminalReport { palette: vec![(208, (255, 135, 0))], ..Default::default() }, ColorLevel::TrueColor, ColorLevel::TrueColor)]
+#[case(TerminalReport { version: Some("kitty(0.35.2)".to_string()), ..Default::default() }, ColorLevel::NoColor, ColorLevel::NoColor)]
+fn test_terminal_report_raises_capabilities(
+    #[case] report: TerminalReport,
+    #[case] detected: ColorLevel,
+    #[case] expected: ColorLevel,
+) {
+    let _guard = TestStateGuard::auto_terminal(true);
+    let capabilities = TerminalCapabilities::new(true, detected);
+
+    assert_eq!(report.apply_to(capabilities).color_level, expected);
+}
+
+#[rstest]
+#[case(ColorDepthMode::Ansi256, None, ColorLevel::Ansi256)]
+#[case(ColorDepthMode::Ansi8, None, ColorLevel::Ansi16)]
+#[case(ColorDepthMode::TrueColor, Some("1"), ColorLevel::Ansi16)]
+#[case(ColorDepthMode::Auto, Some("2"), ColorLevel::Ansi256)]
+#[case(ColorDepthMode::Auto, Some("grayscale"), ColorLevel::TrueColor)]
+fn test_terminal_report_respects_the_depth_policy(
+    #[case] depth_mode: ColorDepthMode,
+    #[case] force_color: Option<&str>,
+    #[case] expected: ColorLevel,
+) {
+    let _guard = TestStateGuard::with_depth(ColorMode::Always, depth_mode, None, Some(true));
+    if let Some(value) = force_color {
+        env::set_var("FORCE_COLOR", value);
+    }
+    let report = TerminalReport {
+        version: Some("kitty(0.35.2)".to_string()),
+        ..Default::default()
+    };
+    let capabilities = TerminalCapabilities::new(true, ColorLevel::Ansi16);
+
+    assert_eq!(report.apply_to(capabilities).color_level, expected);
+}
+
+#[rstest]
+#[case(TestEnv::default().with("COLORFGBG", "15;0"), Some(Background::Dark))]
+#[case(TestEnv::default().with("COLORFGBG", "0;15"), Some(Background::Light))]
+#[case(TestEnv::default().with("COLORFGBG", "0;default;7"), Some(Background::Light))]
+#[case(TestEnv::default().with("COLORFGBG", "7;8"), Some(Background::Dark))]
+#[case(TestEnv::default().with("COLORFGBG", "15;default"), None)]
+#[case(TestEnv::default().with("COLORFGBG", "0;234"), None)]
+#[case(TestEnv::default().with("ITERM_PROFILE", "Solarized Light"), Some(Background::Light))]
+#[case(TestEnv::default().with("ITERM_PROFILE", "Dark Background"), Some(Background::Dark))]
+#[case(TestEnv::default().with("ITERM_PROFILE", "Default"), None)]
+#[case(TestEnv::default().with("ITERM_PROFILE", "Light on Dark"), None)]
+#[case(TestEnv::default().with("COLORFGBG", "0;15").with("ITERM_PROFILE", "Dark"), Some(Background::Light))]
+#[case(TestEnv::default().with("COLORFGBG", "bogus").with("ITERM_PROFILE", "Dark"), Some(Background::Dark))]
+#[case(TestEnv::default(), None)]
+fn test_background_detection(#[case] env: TestEnv, #[case] expected: Option<Background>) {
+    assert_eq!(detect_background(&env), expected);
+}
+
+#[test]
+fn test_background_is_detected_for_normal_targets() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    env::set_var("COLORFGBG", "0;15");
+
+    assert_eq!(
+        ColorizeConfig::terminal_capabilities(RenderTarget::Terminal(true)).background,
+        Some(Background::Light)
+    );
+}
+
+#[test]
+fn test_colorfgbg_palette_index_decides_background_brightness() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    env::set_var("COLORFGBG", "15;8");
+
+    let capabilities = ColorizeConfig::terminal_capabilities(RenderTarget::Terminal(true));
+    assert_eq!(capabilities.background, Some(Background::Dark));
+    assert_eq!(
+        capabilities.background_color,
+        Some(Color::rgb(128, 128, 128))
+    );
+}
+
+#[test]
+fn test_background_config_overrides_environment() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    env::set_var("COLORFGBG", "0;15");
+    ColorizeConfig::set_background(Some(Background::Dark));
+
+    assert_eq!(ColorizeConfig::background(), Some(Background::Dark));
+    assert_eq!(
+        ColorizeConfig::terminal_capabilities(RenderTarget::Stderr).background,
+        Some(Background::Dark)
+    );
+}
+
+#[test]
+fn test_background_is_kept_for_known_capabilities() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    ColorizeConfig::set_background(Some(Background::Dark));
+    let capabilities = TerminalCapabilities {
+        background: Some(Background::Light),
+        ..TerminalCapabilities::new(true, ColorLevel::TrueColor)
+    };
+
+    assert_eq!(
+        ColorizeConfig::terminal_capabilities(RenderTarget::Capabilities(capabilities)),
+        capabilities
+    );
+}
+
+#[rstest]
+#[case((255, 255, 255), Background::Light)]
+#[case((253, 246, 227), Background::Light)]
+#[case((128, 128, 128), Background::Light)]
+#[case((0, 0, 0), Background::Dark)]
+#[case((30, 30, 46), Background::Dark)]
+#[case((0, 43, 54), Background::Dark)]
+fn test_background_from_rgb(#[case] rgb: (u8, u8, u8), #[case] expected: Background) {
+    assert_eq!(Background::from_rgb(rgb.0, rgb.1, rgb.2), expected);
+}
+
+#[rstest]
+#[case(ColorLevel::Ansi16)]
+#[case(ColorLevel::NoColor)]
+fn test_terminal_report_sets_background(#[case] level: ColorLevel) {
+    let report = TerminalReport::parse(b"\x1b]11;rgb:fdfd/f6f6/e3e3\x1b\\");
+    let capabilities = TerminalCapabilities {
+        background: Some(Background::Dark),
+        ..TerminalCapabilities::new(true, level)
+    };
+
+    let applied = report.apply_to(capabilities);
+    assert_eq!(applied.background, Some(Background::Light));
+    assert_eq!(applied.background_color, Some(Color::rgb(253, 246, 227)));
+    assert_eq!(applied.color_level, level);
+}
+
+const ADAPTIVE: AdaptiveColor =
+    AdaptiveColor::new(Color::rgb(0, 95, 135), Color::rgb(95, 175, 255));
+
+fn capabilities_with_background(level: ColorLevel, background: Option<Background>) -> RenderTarget {
+    RenderTarget::Capabilities(TerminalCapabilities {
+        background,
+        ..TerminalCapabilities::new(true, level)
+    })
+}
+
+#[rstest]
+#[case(Some(Background::Light), "\x1b[38;2;0;95;135mtest\x1b[0m")]
+#[case(Some(Background::Dark), "\x1b[38;2;95;175;255mtest\x1b[0m")]
+#[case(None, "\x1b[38;2;95;175;255mtest\x1b[0m")]
+fn test_adaptive_color_resolves_by_background(
+    #[case] background: Option<Background>,
+    #[case] expected: &str,
+) {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let target = capabilities_with_background(ColorLevel::TrueColor, background);
+
+    assert_eq!("test".adaptive(ADAPTIVE).render(target), expected);
+}
+
+#[test]
+fn test_adaptive_color_uses_configured_default_background() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    ColorizeConfig::set_default_background(Background::Light);
+    let target = capabilities_with_background(ColorLevel::TrueColor, None);
+
+    assert_eq!(ColorizeConfig::default_background(), Background::Light);
+    assert_eq!(
+        "test".adaptive(ADAPTIVE).render(target),
+        "\x1b[38;2;0;95;135mtest\x1b[0m"
+    );
+}
+
+#[test]
+fn test_adaptive_color_uses_detected_background() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    env::set_var("COLORFGBG", "0;15");
+
+    assert_eq!(
+        "test".adaptive(ADAPTIVE).to_string(),
+        "\x1b[38;2;0;95;135mtest\x1b[0m"
+    );
+
+    ColorizeConfig::set_background(Some(Background::Dark));
+    assert_eq!(
+        "test".adaptive(ADAPTIVE).to_string(),
+        "\x1b[38;2;95;175;255mtest\x1b[0m"
+    );
+}
+
+#[rstest]
+#[case(ColorLevel::TrueColor, "\x1b[48;2;0;95;135mtest\x1b[0m")]
+#[case(ColorLevel::Ansi256, "\x1b[48;5;24mtest\x1b[0m")]
+#[case(ColorLevel::Ansi16, "\x1b[46mtest\x1b[0m")]
+#[case(ColorLevel::NoColor, "test")]
+fn test_adaptive_background_degrades_by_color_level(
+    #[case] level: ColorLevel,
+    #[case] expected: &str,
+) {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let target = capabilities_with_background(level, Some(Background::Light));
+
+    assert_eq!("test".on_adaptive(ADAPTIVE).render(target), expected);
+}
+
+#[test]
+fn test_adaptive_color_from_hex() {
+    assert_eq!(AdaptiveColor::from_hex("#005f87", "5fafff"), Some(ADAPTIVE));
+    assert_eq!(AdaptiveColor::from_hex("#005f87", "nope"), None);
+    assert_eq!(ADAPTIVE.resolve(Background::Light), Color::rgb(0, 95, 135));
+}
+
+const BRAND: FallbackColor = Color::rgb(255, 135, 0)
+    .or_ansi256(214)
+    .or_named(NamedColor::BrightYellow);
+
+#[rstest]
+#[case(ColorLevel::TrueColor, "\x1b[38;2;255;135;0mtest\x1b[0m")]
+#[case(ColorLevel::Ansi256, "\x1b[38;5;214mtest\x1b[0m")]
+#[case(ColorLevel::Ansi16, "\x1b[93mtest\x1b[0m")]
+#[case(ColorLevel::Ansi8, "\x1b[1;33mtest\x1b[0m")]
+#[case(ColorLevel::NoColor, "test")]
+fn test_fallback_color_uses_explicit_fallback_for_level(
+    #[case] level: ColorLevel,
+    #[case] expected: &str,
+) {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+
+    assert_eq!(render_at("test".fallback(BRAND), level), expected);
+}
+
+#[test]
+fn test_fallback_color_background_uses_explicit_fallback() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+
+    assert_eq!(
+        render_at("test".on_fallback(BRAND), ColorLevel::Ansi256),
+        "\x1b[48;5;214mtest\x1b[0m"
+    );
+    assert_eq!(
+        render_at("test".on_fallback(BRAND), ColorLevel::Ansi8),
+        "\x1b[43mtest\x1b[0m"
+    );
+}
+
+#[rstest]
+#[case(ColorLevel::Ansi256)]
+#[case(ColorLevel::Ansi16)]
+#[case(ColorLevel::Ansi8)]
+fn test_fallback_color_quantizes_levels_without_fallback(#[case] level: ColorLevel) {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let orange = Color::rgb(255, 135, 0);
+
+    assert_eq!(
+        render_at("test".fallback(FallbackColor::new(orange)), level),
+        render_at("test".rgb(255, 135, 0), level)
+    );
+    assert_eq!(
+        render_at(
+            "test".fallback(orange.or_named(NamedColor::Red)),
+            ColorLevel::Ansi256
+        ),
+        render_at("test".rgb(255, 135, 0), ColorLevel::Ansi256)
+    );
+}
+
+#[rstest]
+#[case(ColorLevel::Ansi16, "\x1b[91mtest\x1b[0m")]
+#[case(ColorLevel::Ansi8, "\x1b[31mtest\x1b[0m")]
+fn test_fallback_color_uses_ansi256_without_named_fallback(
+    #[case] level: ColorLevel,
+    #[case] expected: &str,
+) {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let orange = Color::rgb(255, 135, 0).or_ansi256(196);
+
+    assert_eq!(render_at("test".fallback(orange), level), expected);
+    assert_eq!(
+        render_at("test".fallback(orange), level),
+        render_at("test".ansi256(196), level)
+    );
+    assert_ne!(
+        render_at("test".fallback(orange), level),
+        render_at("test".rgb(255, 135, 0), level)
+    );
+}
+
+#[test]
+fn test_fallback_color_accessors() {
+    assert_eq!(BRAND.color(), Color::rgb(255, 135, 0));
+    assert_eq!(BRAND.ansi256(), Some(214));
+    assert_eq!(BRAND.named(), Some(NamedColor::BrightYellow));
+    assert_eq!(
+        FallbackColor::from(Color::rgb(1, 2, 3)),
+        FallbackColor::new(Color::rgb(1, 2, 3))
+    );
+}
+
+#[test]
+fn test_color_conversions() {
+    let color = Color::from_hex("#f80").unwrap();
+    assert_eq!(color, Color::rgb(255, 136, 0));
+    assert_eq!(color.to_rgb(), (255, 136, 0));
+    assert_eq!(Color::from((1, 2, 3)), Color::rgb(1, 2, 3));
+    assert_eq!(<(u8, u8, u8)>::from(Color::rgb(1, 2, 3)), (1, 2, 3));
+    assert_eq!(Color::from_hex("#12345"), None);
+}
+
+fn assert_close(actual: f32, expected: f32, tolerance: f32) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "{actual} is not within {tolerance} of {expected}"
+    );
+}
+
+#[rstest]
+#[case(Color::rgb(0, 0, 0), Color::rgb(255, 255, 255), 21.0)]
+#[case(Color::rgb(255, 255, 255), Color::rgb(0, 0, 0), 21.0)]
+#[case(Color::rgb(119, 119, 119), Color::rgb(255, 255, 255), 4.48)]
+#[case(Color::rgb(255, 0, 0), Color::rgb(255, 255, 255), 4.0)]
+#[case(Color::rgb(18, 52, 86), Color::rgb(18, 52, 86), 1.0)]
+fn test_contrast_ratio(#[case] a: Color, #[case] b: Color, #[case] expected: f32) {
+    assert_close(a.contrast_ratio(b), expected, 0.01);
+}
+
+#[rstest]
+#[case(Color::rgb(0, 0, 0), Color::rgb(255, 255, 255), 106.04)]
+#[case(Color::rgb(255, 255, 255), Color::rgb(0, 0, 0), -107.88)]
+#[case(Color::rgb(136, 136, 136), Color::rgb(255, 255, 255), 63.06)]
+#[case(Color::rgb(255, 255, 255), Color::rgb(136, 136, 136), -68.54)]
+#[case(Color::rgb(120, 120, 120), Color::rgb(120, 120, 120), 0.0)]
+fn test_apca_contrast(#[case] text: Color, #[case] background: Color, #[case] expected: f32) {
+    assert_close(text.apca_contrast(background), expected, 0.05);
+}
+
+#[test]
+fn test_meets_contrast_targets() {
+    let gray = Color::rgb(118, 118, 118);
+    let white = Color::rgb(255, 255, 255);
+
+    assert!(gray.meets_contrast(white, ContrastTarget::Aa));
+    assert!(!gray.meets_contrast(white, ContrastTarget::Aaa));
+    assert!(gray.meets_contrast(white, ContrastTarget::Ratio(3.0)));
+    assert!(gray.meets_contrast(white, ContrastTarget::Apca(60.0)));
+    assert!(!gray.meets_contrast(white, ContrastTarget::Apca(75.0)));
+}
+
+#[rstest]
+#[case(Color::rgb(255, 255, 255), Color::rgb(0, 0, 0))]
+#[case(Color::rgb(255, 255, 0), Color::rgb(0, 0, 0))]
+#[case(Color::rgb(0, 0, 128), Color::rgb(255, 255, 255))]
+#[case(Color::rgb(215, 58, 74), Color::rgb(0, 0, 0))]
+#[case(Color::rgb(0, 100, 0), Color::rgb(255, 255, 255))]
+fn test_black_or_white_on(#[case] background: Color, #[case] expected: Color) {
+    assert_eq!(Color::black_or_white_on(background), expected);
+}
+
+#[rstest]
+#[case(ContrastTarget::Aa)]
+#[case(ContrastTarget::Aaa)]
+#[case(ContrastTarget::Apca(75.0))]
+fn test_readable_on_adjusts_lightness(#[case] target: ContrastTarget) {
+    let brand = Color::rgb(255, 128, 0);
+    let background = Color::rgb(255, 255, 255);
+
+    let readable = brand.readable_on(background, target);
+
+    assert!(readable.meets_contrast(background, target));
+    assert!(readable.relative_luminance() < brand.relative_luminance());
+    assert_eq!(readable.r.max(readable.g).max(readable.b), readable.r);
+}
+
+#[test]
+fn test_readable_on_keeps_colors_that_already_pass() {
+    let navy = Color::rgb(0, 0, 128);
+    assert_eq!(
+        navy.readable_on(Color::rgb(255, 255, 255), ContrastTarget::Aaa),
+        navy
+    );
+}
+
+#[test]
+fn test_readable_on_lightens_on_dark_backgrounds() {
+    let background = Color::rgb(20, 20, 20);
+    let readable = Color::rgb(60, 60, 160).readable_on(background, ContrastTarget::Aa);
+
+    assert!(readable.meets_contrast(background, ContrastTarget::Aa));
+    assert!(readable.relative_luminance() > Color::rgb(60, 60, 160).relative_luminance());
+}
+
+#[test]
+fn test_readable_on_falls_back_to_black_or_white_when_unreachable() {
+    let background = Color::rgb(119, 119, 119);
+    assert_eq!(
+        Color::rgb(120, 0, 0).readable_on(background, ContrastTarget::Aaa),
+        Color::rgb(0, 0, 0)
+    );
+}
+
+#[test]
+fn test_styled_text_readable_on_uses_the_apca_measure() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let blue = Color::rgb(0, 128, 255);
+
+    // WCAG 2.x prefers black on this blue, while APCA prefers white.
+    assert_eq!(
+        "x".red().readable_on(blue, ContrastTarget::Aa).to_string(),
+        "\x1b[38;2;0;0;0;48;2;0;128;255mx\x1b[0m"
+    );
+    assert_eq!(
+        "x".red()
+            .readable_on(blue, ContrastTarget::Apca(60.0))
+            .to_string(),
+        "\x1b[38;2;255;255;255;48;2;0;128;255mx\x1b[0m"
+    );
+}
+
+#[test]
+fn test_styled_text_readable_on_picks_black_or_white() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+
+    assert_eq!(
+        "bug"
+            .bold()
+            .readable_on(Color::rgb(0, 100, 0), ContrastTarget::Aa)
+            .to_string(),
+        "\x1b[1;38;2;255;255;255;48;2;0;100;0mbug\x1b[0m"
+    );
+    assert_eq!(
+        "docs"
+            .red()
+            .readable_on(Color::rgb(255, 255, 0), ContrastTarget::Aa)
+            .to_string(),
+        "\x1b[38;2;0;0;0;48;2;255;255;0mdocs\x1b[0m"
+    );
+}
+
+#[test]
+fn test_styled_text_readable_on_adjusts_rgb_foreground() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let background = Color::rgb(255, 255, 255);
+    let styled = "warn"
+        .rgb(255, 128, 0)
+        .readable_on(background, ContrastTarget::Aa);
+    let expected = Color::rgb(255, 128, 0).readable_on(background, ContrastTarget::Aa);
+
+    assert_eq!(
+        styled.to_string(),
+        format!(
+            "\x1b[38;2;{};{};{};48;2;255;255;255mwarn\x1b[0m",
+            expected.r, expected.g, expected.b
+        )
+    );
+}
+
+fn assert_color_approx_eq(actual: Color, expected: Color) {
+    let close = |a: u8, b: u8| a.abs_diff(b) <= 1;
+    assert!(
+        close(actual.r, expected.r) && close(actual.g, expected.g) && close(actual.b, expected.b),
+        "{actual:?} is not within 1 of {expected:?}"
+    );
+}
+
+#[rstest]
+#[case(Color::rgb(128, 128, 128).lighten(25.0), Color::rgb(191, 191, 191))]
+#[case(Color::rgb(128, 128, 128).darken(25.0), Color::rgb(64, 64, 64))]
+#[case(Color::rgb(255, 0, 0).lighten(25.0), Color::rgb(255, 128, 128))]
+#[case(Color::rgb(255, 0, 0).darken(25.0), Color::rgb(128, 0, 0))]
+#[case(Color::rgb(255, 0, 0).lighten(200.0), Color::rgb(255, 255, 255))]
+#[case(Color::rgb(255, 0, 0).darken(200.0), Color::rgb(0, 0, 0))]
+#[case(Color::rgb(191, 64, 64).saturate(50.0), Color::rgb(255, 0, 0))]
+#[case(Color::rgb(255, 0, 0).desaturate(50.0), Color::rgb(191, 64, 64))]
+#[case(Color::rgb(255, 0, 0).desaturate(100.0), Color::rgb(128, 128, 128))]
+#[case(Color::rgb(255, 0, 0).rotate_hue(120.0), Color::rgb(0, 255, 0))]
+#[case(Color::rgb(255, 0, 0).rotate_hue(-120.0), Color::rgb(0, 0, 255))]
+#[case(Color::rgb(255, 0, 0).rotate_hue(480.0), Color::rgb(0, 255, 0))]
+fn test_hsl_color_adjustments(#[case] actual: Color, #[case] expected: Color) {
+    assert_color_approx_eq(actual, expected);
+}
+
+#[rstest]
+#[case(0.0, Color::rgb(0, 0, 0))]
+#[case(1.0, Color::rgb(255, 255, 255))]
+#[case(0.5, Color::rgb(99, 99, 99))]
+#[case(-1.0, Color::rgb(0, 0, 0))]
+#[case(2.0, Color::rgb(255, 255, 255))]
+fn test_mix_is_perceptual(#[case] t: f32, #[case] expected: Color) {
+    assert_color_approx_eq(
+        Color::rgb(0, 0, 0).mix(Color::rgb(255, 255, 255), t),
+        expected,
+    );
+}
+
+#[test]
+fn test_mix_keeps_endpoints() {
+    let orange = Color::rgb(255, 128, 0);
+    let blue = Color::rgb(0, 128, 255);
+
+    assert_color_approx_eq(orange.mix(blue, 0.0), orange);
+    assert_color_approx_eq(orange.mix(blue, 1.0), blue);
+}
+
+#[test]
+fn test_invert_and_grayscale() {
+    assert_eq!(Color::rgb(255, 128, 0).invert(), Color::rgb(0, 127, 255));
+    assert_eq!(Color::rgb(255, 0, 0).grayscale(), Color::rgb(127, 127, 127));
+    assert_eq!(
+        Color::rgb(255, 255, 255).grayscale(),
+        Color::rgb(255, 255, 255)
+    );
+    assert_eq!(Color::rgb(0, 0, 0).grayscale(), Color::rgb(0, 0, 0));
+}
+
+#[test]
+fn test_hsl_harmonies_rotate_hue() {
+    let red = Color::rgb(255, 0, 0);
+    let space = HarmonySpace::Hsl;
+
+    assert_eq!(red.complementary(space), [red, Color::rgb(0, 255, 255)]);
+    assert_eq!(
+        red.triadic(space),
+        [red, Color::rgb(0, 255, 0), Color::rgb(0, 0, 255)]
+    );
+    assert_eq!(
+        red.tetradic(space),
+        [
+            red,
+            Color::rgb(128, 255, 0),
+            Color::rgb(0, 255, 255),
+            Color::rgb(128, 0, 255)
+        ]
+    );
+    assert_eq!(
+        red.split_complementary(space),
+        [red, Color::rgb(0, 255, 128), Color::rgb(0, 128, 255)]
+    );
+    assert_eq!(
+        red.analogous(space),
+        [red, Color::rgb(255, 0, 128), Color::rgb(255, 128, 0)]
+    );
+}
+
+#[test]
+fn test_oklch_harmonies_keep_lightness() {
+    let brand = Color::rgb(70, 130, 180);
+    let (lightness, _, hue) = crate::color::rgb_to_oklch(brand);
+
+    let [base, complement] = brand.complementary(HarmonySpace::Oklch);
+    assert_eq!(base, brand);
+    let (complement_lightness, _, complement_hue) = crate::color::rgb_to_oklch(complement);
+    assert!((complement_lightness - lightness).abs() < 0.05);
+    assert!(((complement_hue - hue).rem_euclid(360.0) - 180.0).abs() < 10.0);
+
+    let gray = Color::rgb(128, 128, 128);
+    for color in gray.triadic(HarmonySpace::Oklch) {
+        assert_color_approx_eq(color, gray);
+    }
+}
+
+#[rstest]
+#[case(HarmonySpace::Hsl)]
+#[case(HarmonySpace::Oklch)]
+fn test_monochromatic_contains_base_and_runs_dark_to_light(#[case] space: HarmonySpace) {
+    let brand = Color::rgb(0, 128, 255);
+    let shades = brand.monochromatic(5, space);
+
+    assert_eq!(shades.len(), 5);
+    assert!(shades.contains(&brand));
+    let luminances: Vec<f32> = shades
+        .iter()
+        .map(|color| crate::color::relative_luminance(color.r, color.g, color.b))
+        .collect();
+    assert!(luminances.windows(2).all(|pair| pair[0] < pair[1]));
+
+    assert_eq!(brand.monochromatic(1, space), vec![brand]);
+    assert!(brand.monochromatic(0, space).is_empty());
+}
+
+#[rstest]
+#[case("abc", vec!["a", "b", "c"])]
+#[case("e\u{301}x", vec!["e\u{301}", "x"])]
+#[case("a\r\nb", vec!["a", "\r\n", "b"])]
+#[case("\u{1f1fa}\u{1f1f8}\u{1f1ec}\u{1f1e7}", vec!["\u{1f1fa}\u{1f1f8}", "\u{1f1ec}\u{1f1e7}"])]
+#[case("\u{1f44d}\u{1f3fd}!", vec!["\u{1f44d}\u{1f3fd}", "!"])]
+#[case("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}x", vec!["\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}", "x"])]
+#[case("\u{2764}\u{fe0f}", vec!["\u{2764}\u{fe0f}"])]
+#[case("", vec![])]
+fn test_graphemes(#[case] text: &str, #[case] expected: Vec<&str>) {
+    assert_eq!(graphemes(text), expected);
+}
+
+#[rstest]
+#[case(InterpolationSpace::Srgb, Color::rgb(128, 128, 128))]
+#[case(InterpolationSpace::Linear, Color::rgb(188, 188, 188))]
+#[case(InterpolationSpace::Oklch, Color::rgb(99, 99, 99))]
+fn test_gradient_interpolation_spaces(#[case] space: InterpolationSpace, #[case] expected: Color) {
+    let gradient = Gradient::new([Color::rgb(0, 0, 0), Color::rgb(255, 255, 255)]).space(space);
+
+    assert_color_approx_eq(gradient.sample(0.5), expected);
+    assert_eq!(gradient.sample(0.0), Color::rgb(0, 0, 0));
+    assert_eq!(gradient.sample(1.0), Color::rgb(255, 255, 255));
+}
+
+#[test]
+fn test_gradient_multi_stop_and_clamping() {
+    let gradient = Gradient::from([
+        Color::rgb(255, 0, 0),
+        Color::rgb(0, 255, 0),
+        Color::rgb(0, 0, 255),
+    ]);
+
+    assert_eq!(gradient.sample(0.5), Color::rgb(0, 255, 0));
+    assert_eq!(gradient.sample(0.25), Color::rgb(128, 128, 0));
+    assert_eq!(gradient.sample(-1.0), Color::rgb(255, 0, 0));
+    assert_eq!(gradient.sample(2.0), Color::rgb(0, 0, 255));
+    assert_eq!(gradient.sample(f32::NAN), Color::rgb(255, 0, 0));
+    assert_eq!(Gradient::new([]).sample(0.5), Color::rgb(0, 0, 0));
+    assert_eq!(
+        Gradient::new([Color::rgb(1, 2, 3)]).sample(0.5),
+        Color::rgb(1, 2, 3)
+    );
+}
+
+#[test]
+fn test_gradient_oklch_takes_shorter_hue_path() {
+    let gradient = Gradient::new([Color::rgb(255, 0, 0), Color::rgb(255, 0, 255)])
+        .space(InterpolationSpace::Oklch);
+    let middle = gradient.sample(0.5);
+
+    assert!(
+        middle.r > 200 && middle.g < 60 && middle.b > 60,
+        "{middle:?}"
+    );
+}
+
+#[test]
+fn test_rainbow_preset() {
+    let rainbow = Gradient::rainbow();
+    assert_eq!(rainbow.stops().len(), 6);
+    assert_eq!(rainbow.sample(0.0), Color::rgb(255, 0, 0));
+    assert_eq!(rainbow.sample(0.4), Color::rgb(0, 255, 0));
+    assert_eq!(rainbow.sample(1.0), Color::rgb(255, 0, 255));
+    assert_eq!(rainbow.interpolation_space(), InterpolationSpace::Srgb);
+
+    let cycle = Gradient::rainbow_cycle();
+    assert_eq!(cycle.stops().len(), 7);
+    assert_eq!(cycle.sample(0.0), cycle.sample(1.0));
+    assert_eq!(cycle.sample(0.5), Color::rgb(0, 255, 255));
+
+    let pastel = Gradient::pastel_rainbow();
+    assert_eq!(pastel.stops().len(), 6);
+    assert!(pastel
+        .stops()
+        .iter()
+        .all(|color| color.contrast_ratio(Color::rgb(0, 0, 0)) > 10.0));
+}
+
+#[test]
+fn test_gradient_renders_per_grapheme_in_truecolor() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+
+    assert_eq!(
+        "abc"
+            .gradient(&[Color::rgb(255, 0, 0), Color::rgb(0, 0, 255)])
+            .to_string(),
+        "\x1b[38;2;255;0;0ma\x1b[38;2;128;0;128mb\x1b[38;2;0;0;255mc\x1b[0m"
+    );
+}
+
+#[test]
+fn test_gradient_keeps_grapheme_clusters_together() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+
+    assert_eq!(
+        "e\u{301}x"
+            .gradient(&[Color::rgb(255, 0, 0), Color::rgb(0, 0, 255)])
+            .to_string(),
+        "\x1b[38;2;255;0;0me\u{301}\x1b[38;2;0;0;255mx\x1b[0m"
+    );
+}
+
+#[rstest]
+#[case(ColorLevel::Ansi16, "\x1b[1;91mabcd\x1b[0m")]
+#[case(ColorLevel::Ansi256, "\x1b[1;38;5;9mabcd\x1b[0m")]
+#[case(ColorLevel::NoColor, "abcd")]
+fn test_gradient_merges_equal_quantized_runs(#[case] level: ColorLevel, #[case] expected: &str) {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let target = RenderTarget::Capabilities(TerminalCapabilities::new(true, level));
+
+    assert_eq!(
+        "abcd"
+            .bold()
+            .gradient(&[Color::rgb(255, 0, 0), Color::rgb(245, 10, 10)])
+            .render(target),
+        expected
+    );
+}
+
+#[test]
+fn test_gradient_quantizes_each_grapheme_at_ansi256() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let target = RenderTarget::Capabilities(TerminalCapabilities::new(true, ColorLevel::Ansi256));
+    let colors = [Color::rgb(0, 0, 0), Color::rgb(255, 255, 255)];
+
+    let expected: String = [0.0, 0.5, 1.0]
+        .iter()
+        .zip(["a", "b", "c"])
+        .map(|(t, ch)| {
+            let Color { r, g, b } = Gradient::from(colors).sample(*t);
+            format!("\x1b[38;5;{}m{ch}", rgb_to_ansi256(r, g, b))
+        })
+        .collect::<String>()
+        + "\x1b[0m";
+
+    assert_eq!("abc".gradient(&colors).render(target), expected);
+}
+
+#[test]
+fn test_background_gradient_with_named_foreground() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let gradient = Gradient::new([Color::rgb(0, 0, 0), Color::rgb(0, 0, 255)]);
+
+    assert_eq!(
+        "ab".white().on_gradient_with(&gradient).to_string(),
+        "\x1b[37;48;2;0;0;0ma\x1b[37;48;2;0;0;255mb\x1b[0m"
+    );
+    assert_eq!(
+        "ab".on_gradient(&[Color::rgb(0, 0, 0)]).to_string(),
+        "\x1b[48;2;0;0;0mab\x1b[0m"
+    );
+}
+
+#[test]
+fn test_gradient_on_empty_text_and_overrides() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let colors = [Color::rgb(255, 0, 0), Color::rgb(0, 0, 255)];
+
+    assert_eq!(
+        "".gradient(&colors).to_string(),
+        "\x1b[38;2;255;0;0m\x1b[0m"
+    );
+    assert_eq!(
+        "ab".gradient(&colors).red().to_string(),
+        "\x1b[31mab\x1b[0m"
+    );
+    assert_eq!(
+        "ab".gradient_with(&Gradient::rainbow()).clear().to_string(),
+        "ab"
+    );
+}
+
+fn render_at(text: StyledText, level: ColorLevel) -> String {
+    text.render(RenderTarget::Capabilities(TerminalCapabilities::new(
+        true, level,
+    )))
+}
+
+#[rstest]
+#[case(Dither::None, "\x1b[38;5;232mabcdefgh\x1b[0m")]
+#[case(
+    Dither::Ordered,
+    "\x1b[38;5;233ma\x1b[38;5;232mbcd\x1b[38;5;233me\x1b[38;5;232mfgh\x1b[0m"
+)]
+#[case(
+    Dither::ErrorDiffusion,
+    "\x1b[38;5;232ma\x1b[38;5;233mb\x1b[38;5;232mcde\x1b[38;5;233mf\x1b[38;5;232mgh\x1b[0m"
+)]
+fn test_dither_modes_at_ansi256(#[case] dither: Dither, #[case] expected: &str) {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+
+    assert_eq!(
+        render_at(
+            "abcdefgh".rgb(11, 11, 11).dither(dither),
+            ColorLevel::Ansi256
+        ),
+        expected
+    );
+}
+
+#[test]
+fn test_dither_chooses_between_two_nearest_named_colors() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+
+    assert_eq!(
+        render_at(
+            "abcdefgh".on_rgb(32, 0, 0).dither(Dither::Ordered),
+            ColorLevel::Ansi16
+        ),
+        "\x1b[41ma\x1b[40mbcd\x1b[41me\x1b[40mfgh\x1b[0m"
+    );
+    assert_eq!(
+        render_at("abcdefgh".on_rgb(32, 0, 0), ColorLevel::Ansi16),
+        "\x1b[40mabcdefgh\x1b[0m"
+    );
+}
+
+#[test]
+fn test_error_diffusion_tracks_average_color() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let text = "x".repeat(64);
+    let output = render_at(
+        text.as_str()
+            .rgb(100, 100, 100)
+            .dither(Dither::ErrorDiffusion),
+        ColorLevel::Ansi256,
+    );
+
+    let mut total = 0.0;
+    let mut count = 0;
+    for run in output.split("\x1b[38;5;").skip(1) {
+        let (index, rest) = run.split_once('m').unwrap();
+        let (gray, _, _) = ansi256_to_rgb(index.parse().unwrap());
+        let length = rest.trim_end_matches("\x1b[0m").len();
+        total += f32::from(gray) * length as f32;
+        count += length;
+    }
+
+    assert_eq!(count, 64);
+    assert!((total / count as f32 - 100.0).abs() < 1.0);
+}
+
+#[test]
+fn test_dither_leaves_truecolor_and_palette_colors_unchanged() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+
+    assert_eq!(
+        render_at(
+            "ab".rgb(11, 11, 11).dither(Dither::Ordered),
+            ColorLevel::TrueColor
+        ),
+        "\x1b[38;2;11;11;11mab\x1b[0m"
+    );
+    assert_eq!(
+        render_at("ab".dither(Dither::Ordered).red(), ColorLevel::Ansi256),
+        "\x1b[31mab\x1b[0m"
+    );
+    assert_eq!(
+        render_at(
+            "ab".ansi256(208).dither(Dither::ErrorDiffusion),
+            ColorLevel::Ansi256
+        ),
+        "\x1b[38;5;208mab\x1b[0m"
+    );
+    assert_eq!(
+        render_at(
+            "ab".rgb(11, 11, 11).dither(Dither::Ordered),
+            ColorLevel::NoColor
+        ),
+        "ab"
+    );
+}
+
+#[test]
+fn test_dither_applies_to_gradients_and_adaptive_colors() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let gray = Color::rgb(11, 11, 11);
+
+    assert_eq!(
+        render_at(
+            "abcdefgh".gradient(&[gray, gray]).dither(Dither::Ordered),
+            ColorLevel::Ansi256
+        ),
+        render_at(
+            "abcdefgh".rgb(11, 11, 11).dither(Dither::Ordered),
+            ColorLevel::Ansi256
+        )
+    );
+    assert_eq!(
+        render_at(
+            "abcdefgh"
+                .adaptive(AdaptiveColor::new(Color::rgb(0, 0, 0), gray))
+                .dither(Dither::Ordered),
+            ColorLevel::Ansi256
+        ),
+        "\x1b[38;5;233ma\x1b[38;5;232mbcd\x1b[38;5;233me\x1b[38;5;232mfgh\x1b[0m"
+    );
+}
+
+#[rstest]
+#[case("#00000080", Some(Rgba::new(0, 0, 0, 128)))]
+#[case("ff000080", Some(Rgba::new(255, 0, 0, 128)))]
+#[case("#0008", Some(Rgba::new(0, 0, 0, 136)))]
+#[case("#fff", Some(Rgba::new(255, 255, 255, 255)))]
+#[case("#336699", Some(Rgba::new(51, 102, 153, 255)))]
+#[case("#12345", None)]
+#[case("#0000008g", None)]
+#[case("#000g", None)]
+#[case("éé", None)]
+#[case("#0000é", None)]
+#[case("+f+f+f+f", None)]
+fn test_rgba_from_hex(#[case] hex: &str, #[case] expected: Option<Rgba>) {
+    assert_eq!(Rgba::from_hex(hex), expected);
+}
+
+#[rstest]
+#[case(
+    Rgba::new(0, 0, 0, 128),
+    Color::rgb(255, 255, 255),
+    Color::rgb(127, 127, 127)
+)]
+#[case(Rgba::new(255, 0, 0, 0), Color::rgb(0, 0, 255), Color::rgb(0, 0, 255))]
+#[case(
+    Rgba::new(255, 0, 0, 255),
+    Color::rgb(0, 0, 255),
+    Color::rgb(255, 0, 0)
+)]
+#[case(
+    Rgba::new(255, 0, 0, 128),
+    Color::rgb(0, 0, 255),
+    Color::rgb(128, 0, 127)
+)]
+fn test_rgba_over(#[case] color: Rgba, #[case] background: Color, #[case] expected: Color) {
+    assert_eq!(color.over(background), expected);
+}
+
+#[test]
+fn test_rgba_conversions() {
+    assert_eq!(Rgba::new(1, 2, 3, 4).color(), Color::rgb(1, 2, 3));
+    assert_eq!(Rgba::from(Color::rgb(1, 2, 3)), Rgba::new(1, 2, 3, 255));
+}
+
+#[test]
+fn test_rgba_composites_against_terminal_background() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let half_white = Rgba::new(255, 255, 255, 128);
+    let half_black = Rgba::new(0, 0, 0, 128);
+
+    assert_eq!(
+        "x".rgba(half_white).to_string(),
+        "\x1b[38;2;128;128;128mx\x1b[0m"
+    );
+
+    ColorizeConfig::set_background(Some(Background::Light));
+    assert_eq!(
+        "x".on_rgba(half_black).to_string(),
+        "\x1b[48;2;127;127;127mx\x1b[0m"
+    );
+
+    ColorizeConfig::set_background_color(Some(Color::rgb(0, 0, 255)));
+    assert_eq!(
+        "x".rgba(Rgba::new(255, 0, 0, 128)).to_string(),
+        "\x1b[38;2;128;0;127mx\x1b[0m"
+    );
+}
+
+#[test]
+fn test_background_color_override_sets_brightness() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    ColorizeConfig::set_background_color(Some(Color::rgb(250, 250, 240)));
+
+    let capabilities = ColorizeConfig::terminal_capabilities(RenderTarget::Stdout);
+    assert_eq!(capabilities.background, Some(Background::Light));
+    assert_eq!(
+        capabilities.background_color,
+        Some(Color::rgb(250, 250, 240))
+    );
+
+    ColorizeConfig::set_background(Some(Background::Dark));
+    let capabilities = ColorizeConfig::terminal_capabilities(RenderTarget::Stdout);
+    assert_eq!(capabilities.background, Some(Background::Dark));
+}
+
+#[rstest]
+#[case(TestEnv::default().with("COLORFGBG", "0;15"), Some(Color::rgb(255, 255, 255)))]
+#[case(TestEnv::default().with("COLORFGBG", "15;4"), Some(Color::rgb(0, 0, 128)))]
+#[case(TestEnv::default().with("COLORFGBG", "0;default"), None)]
+#[case(TestEnv::default().with("COLORFGBG", "0;42"), None)]
+#[case(TestEnv::default(), None)]
+fn test_detect_background_color(#[case] env: TestEnv, #[case] expected: Option<Color>) {
+    assert_eq!(detect_background_color(&env), expected);
+}
+
+#[test]
+fn test_rgba_foreground_blends_with_span_background() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let half_white = Rgba::new(255, 255, 255, 128);
+
+    assert_eq!(
+        "x".rgba(half_white).on_rgb(0, 0, 200).to_string(),
+        "\x1b[38;2;128;128;228;48;2;0;0;200mx\x1b[0m"
+    );
+    assert_eq!(
+        "x".on_blue().rgba(half_white).to_string(),
+        "\x1b[38;2;128;128;192;44mx\x1b[0m"
+    );
+    assert_eq!(
+        "x".rgba(half_white)
+            .on_rgba(Rgba::new(255, 0, 0, 128))
+            .to_string(),
+        "\x1b[38;2;192;128;128;48;2;128;0;0mx\x1b[0m"
+    );
+    assert_eq!(
+        "ab".rgba(half_white)
+            .on_gradient(&[Color::rgb(0, 0, 0), Color::rgb(0, 0, 254)])
+            .to_string(),
+        "\x1b[38;2;128;128;128;48;2;0;0;0ma\x1b[38;2;128;128;255;48;2;0;0;254mb\x1b[0m"
+    );
+}
+
+#[test]
+fn test_rgba_uses_supplied_capabilities_and_degrades() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let capabilities = TerminalCapabilities {
+        background_color: Some(Color::rgb(255, 255, 255)),
+        ..TerminalCapabilities::new(true, ColorLevel::Ansi256)
+    };
+
+    assert_eq!(
+        "x".rgba(Rgba::new(0, 0, 0, 128))
+            .render(RenderTarget::Capabilities(capabilities)),
+        format!("\x1b[38;5;{}mx\x1b[0m", rgb_to_ansi256(127, 127, 127))
+    );
+    assert_eq!(
+        render_at("x".rgba(Rgba::new(0, 0, 0, 128)), ColorLevel::NoColor),
+        "x"
+    );
+}
+
+#[rstest]
+#[case(
+    ColorVisionDeficiency::Protanopia,
+    Color::rgb(255, 0, 0),
+    Color::rgb(109, 95, 0)
+)]
+#[case(
+    ColorVisionDeficiency::Deuteranopia,
+    Color::rgb(0, 255, 0),
+    Color::rgb(239, 214, 58)
+)]
+#[case(
+    ColorVisionDeficiency::Tritanopia,
+    Color::rgb(0, 0, 255),
+    Color::rgb(0, 107, 150)
+)]
+#[case(
+    ColorVisionDeficiency::Achromatopsia,
+    Color::rgb(0, 0, 255),
+    Color::rgb(76, 76, 76)
+)]
+fn test_simulate_color_vision_deficiency(
+    #[case] deficiency: ColorVisionDeficiency,
+    #[case] color: Color,
+    #[case] expected: Color,
+) {
+    assert_eq!(deficiency.simulate(color), expected);
+}
+
+#[rstest]
+#[case(ColorVisionDeficiency::Protanopia)]
+#[case(ColorVisionDeficiency::Deuteranopia)]
+#[case(ColorVisionDeficiency::Tritanopia)]
+#[case(ColorVisionDeficiency::Achromatopsia)]
+fn test_color_vision_transforms_keep_grays(#[case] deficiency: ColorVisionDeficiency) {
+    for gray in [0, 64, 128, 255] {
+        let color = Color::rgb(gray, gray, gray);
+        assert_eq!(deficiency.simulate(color), color);
+        assert_eq!(deficiency.daltonize(color), color);
+    }
+}
+
+#[test]
+fn test_achromatopsia_matches_grayscale() {
+    for color in [Color::rgb(255, 0, 0), Color::rgb(12, 200, 99)] {
+        assert_eq!(
+            ColorVisionDeficiency::Achromatopsia.simulate(color),
+            color.grayscale()
+        );
+        assert_eq!(ColorVisionDeficiency::Achromatopsia.daltonize(color), color);
+    }
+}
+
+#[rstest]
+#[case(
+    ColorVisionDeficiency::Protanopia,
+    Color::rgb(255, 0, 0),
+    Color::rgb(255, 184, 203)
+)]
+#[case(
+    ColorVisionDeficiency::Deuteranopia,
+    Color::rgb(255, 0, 0),
+    Color::rgb(255, 112, 180)
+)]
+#[case(
+    ColorVisionDeficiency::Tritanopia,
+    Color::rgb(0, 0, 255),
+    Color::rgb(213, 158, 255)
+)]
+fn test_daltonize(
+    #[case] deficiency: ColorVisionDeficiency,
+    #[case] color: Color,
+    #[case] expected: Color,
+) {
+    assert_eq!(deficiency.daltonize(color), expected);
+    assert_eq!(ColorTransform::Daltonize(deficiency).apply(color), expected);
+    assert_eq!(ColorTransform::None.apply(color), color);
+}
+
+#[test]
+fn test_daltonize_moves_lost_red_difference_into_blue() {
+    let red = Color::rgb(200, 40, 40);
+    let corrected = ColorVisionDeficiency::Protanopia.daltonize(red);
+
+    assert_eq!(corrected.r, red.r);
+    assert!(corrected.b > red.b + 40, "{corrected:?}");
+    assert!(
+        ColorVisionDeficiency::Protanopia.simulate(corrected).b
+            > ColorVisionDeficiency::Protanopia.simulate(red).b
+    );
+}
+
+#[test]
+fn test_color_transform_applies_to_all_color_kinds() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    assert_eq!(ColorizeConfig::color_transform(), ColorTransform::None);
+    assert_eq!("x".red().to_string(), "\x1b[31mx\x1b[0m");
+
+    let transform = ColorTransform::Simulate(ColorVisionDeficiency::Achromatopsia);
+    ColorizeConfig::set_color_transform(transform);
+    let gray = |color: Color| {
+        let Color { r, g, b } = color.grayscale();
+        format!("{r};{g};{b}")
+    };
+
+    assert_eq!(
+        "x".red().to_string(),
+        format!("\x1b[38;2;{}mx\x1b[0m", gray(Color::rgb(128, 0, 0)))
+    );
+    assert_eq!(
+        "x".ansi256(196).to_string(),
+        format!("\x1b[38;2;{}mx\x1b[0m", gray(Color::rgb(255, 0, 0)))
+    );
+    assert_eq!(
+        "x".rgb(0, 0, 255).on_green().to_string(),
+        format!(
+            "\x1b[38;2;{};48;2;{}mx\x1b[0m",
+            gray(Color::rgb(0, 0, 255)),
+            gray(Color::rgb(0, 128, 0))
+        )
+    );
+    assert_eq!(
+        "ab".gradient(&[Color::rgb(255, 0, 0), Color::rgb(0, 0, 255)])
+            .to_string(),
+        format!(
+            "\x1b[38;2;{}ma\x1b[38;2;{}mb\x1b[0m",
+            gray(Color::rgb(255, 0, 0)),
+            gray(Color::rgb(0, 0, 255))
+        )
+    );
+}
+
+#[test]
+fn test_color_transform_happens_before_quantization() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    ColorizeConfig::set_color_transform(ColorTransform::Simulate(
+        ColorVisionDeficiency::Achromatopsia,
+    ));
+    let Color { r, g, b } = Color::rgb(255, 0, 0).grayscale();
+
+    assert_eq!(
+        render_at("x".rgb(255, 0, 0), ColorLevel::Ansi256),
+        format!("\x1b[38;5;{}mx\x1b[0m", rgb_to_ansi256(r, g, b))
+    );
+    assert_eq!(
+        render_at("x".bright_red().bold(), ColorLevel::Ansi16),
+        format!(
+            "\x1b[1;{}mx\x1b[0m",
+            rgb_to_named_color(r, g, b).foreground_code()
+        )
+    );
+    assert_eq!(render_at("x".red(), ColorLevel::NoColor), "x");
+}
+
+#[test]
+fn test_color_transform_composites_alpha_before_transforming() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    ColorizeConfig::set_color_transform(ColorTransform::Simulate(
+        ColorVisionDeficiency::Protanopia,
+    ));
+    let composited = Rgba::new(255, 0, 0, 128).over(Color::rgb(0, 0, 255));
+    let Color { r, g, b } = ColorVisionDeficiency::Protanopia.simulate(composited);
+
+    assert!("x"
+        .rgba(Rgba::new(255, 0, 0, 128))
+        .on_rgb(0, 0, 255)
+        .to_string()
+        .starts_with(&format!("\x1b[38;2;{r};{g};{b};")));
+}
+
+fn grayscale_guard() -> TestStateGuard {
+    TestStateGuard::with_depth(
+        ColorMode::Always,
+        ColorDepthMode::Grayscale,
+        None,
+        Some(true),
+    )
+}
+
+#[test]
+fn test_grayscale_depth_mode_detects_level_and_keeps_styles() {
+    let _guard = grayscale_guard();
+    let capabilities = ColorizeConfig::terminal_capabilities(RenderTarget::Stdout);
+    let Color { r: gray, .. } = Color::rgb(255, 0, 0).grayscale();
+
+    assert_eq!(capabilities.color_level, ColorLevel::Ansi256);
+    assert!(capabilities.grayscale);
+    assert_eq!(
+        "x".rgb(255, 0, 0).bold().underline().to_string(),
+        format!(
+            "\x1b[1;4;38;5;{}mx\x1b[0m",
+            rgb_to_ansi256_gray(gray, gray, gray)
+        )
+    );
+}
+
+#[rstest]
+#[case(ColorLevel::TrueColor, "x".red(), "\x1b[38;2;60;60;60mx\x1b[0m")]
+#[case(ColorLevel::TrueColor, "x".on_rgb(0, 0, 255), "\x1b[48;2;76;76;76mx\x1b[0m")]
+#[case(ColorLevel::Ansi256, "x".rgb(0, 0, 0), "\x1b[38;5;232mx\x1b[0m")]
+#[case(ColorLevel::Ansi256, "x".rgb(255, 255, 255), "\x1b[38;5;255mx\x1b[0m")]
+#[case(ColorLevel::Ansi256, "x".ansi256(196), "\x1b[38;5;244mx\x1b[0m")]
+#[case(ColorLevel::Ansi256, "x".white(), "\x1b[38;5;250mx\x1b[0m")]
+#[case(ColorLevel::Ansi16, "x".rgb(0, 0, 0), "\x1b[30mx\x1b[0m")]
+#[case(ColorLevel::Ansi16, "x".bright_red(), "\x1b[90mx\x1b[0m")]
+#[case(ColorLevel::Ansi16, "x".green(), "\x1b[90mx\x1b[0m")]
+#[case(ColorLevel::Ansi16, "x".blue(), "\x1b[30mx\x1b[0m")]
+#[case(ColorLevel::Ansi16, "x".rgb(200, 200, 200), "\x1b[37mx\x1b[0m")]
+#[case(ColorLevel::Ansi16, "x".bold().on_rgb(255, 255, 0), "\x1b[1;107mx\x1b[0m")]
+#[case(ColorLevel::NoColor, "x".red(), "x")]
+fn test_grayscale_maps_colors_to_luminance(
+    #[case] level: ColorLevel,
+    #[case] text: StyledText,
+    #[case] expected: &str,
+) {
+    let _guard = grayscale_guard();
+    assert_eq!(render_at(text, level), expected);
+}
+
+#[test]
+fn test_ansi8_gradient_clears_bright_bold_between_runs() {
+    let _guard = grayscale_guard();
+    let output = render_at(
+        "abcdefgh".gradient(&[Color::rgb(0, 0, 0), Color::rgb(255, 255, 255)]),
+        ColorLevel::Ansi8,
+    );
+
+    assert_eq!(
+        output,
+        "\x1b[30mab\x1b[1;30mcde\x1b[22;37mfg\x1b[1;37mh\x1b[0m"
+    );
+}
+
+#[rstest]
+#[case(ColorLevel::Ansi8)]
+#[case(ColorLevel::Ansi16)]
+#[case(ColorLevel::Ansi256)]
+fn test_grayscale_palette_matches_plain_quantization(#[case] level: ColorLevel) {
+    let context = RenderContext {
+        grayscale: true,
+        ..context(level)
+    };
+    let palette: Vec<ColorSpec> = quantization_palette(context)
+        .iter()
+        .map(|(spec, _)| spec.clone())
+        .collect();
+
+    for gray in 0..=255 {
+        let quantized = ColorSpec::Rgb(gray, gray, gray).quantized(context);
+        assert!(
+            palette.contains(&quantized),
+            "{level:?} {gray}: {quantized:?}"
+        );
+    }
+}
+
+#[test]
+fn test_grayscale_capabilities_and_dithering() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let capabilities = TerminalCapabilities {
+        grayscale: true,
+        ..TerminalCapabilities::new(true, ColorLevel::Ansi256)
+    };
+    let output = "abcdefgh"
+        .rgb(200, 30, 30)
+        .dither(Dither::Ordered)
+        .render(RenderTarget::Capabilities(capabilities));
+
+    for run in output.split("\x1b[38;5;").skip(1) {
+        let (index, _) = run.split_once('m').unwrap();
+        assert!(
+            (232..=255).contains(&index.parse::<u8>().unwrap()),
+            "{output:?}"
+        );
+    }
+    assert_eq!(
+        "x".red()
+            .render(RenderTarget::Capabilities(TerminalCapabilities::new(
+                true,
+                ColorLevel::Ansi256
+            ))),
+        "\x1b[31mx\x1b[0m"
+    );
+}
+
+#[test]
+fn test_supplied_capabilities_ignore_force_color_grayscale() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    env::set_var("FORCE_COLOR", "grayscale");
+    let capabilities = TerminalCapabilities::new(true, ColorLevel::Ansi256);
+
+    assert!(
+        !ColorizeConfig::terminal_capabilities(RenderTarget::Capabilities(capabilities)).grayscale
+    );
+
+    ColorizeConfig::set_color_depth_mode(ColorDepthMode::Grayscale);
+    assert!(
+        ColorizeConfig::terminal_capabilities(RenderTarget::Capabilities(capabilities)).grayscale
+    );
+}
+
+#[rstest]
+#[case("grayscale", ColorLevel::Ansi256)]
+#[case("GREY", ColorLevel::Ansi256)]
+#[case("mono", ColorLevel::Ansi256)]
+fn test_force_color_grayscale(#[case] value: &str, #[case] expected: ColorLevel) {
+    let env = TestEnv::default()
+        .with("FORCE_COLOR", value)
+        .with("TERM", "xterm-256color");
+
+    assert_eq!(
+        detect_color_level(false, ColorMode::Auto, ColorDepthMode::Auto, &env),
+        expected
+    );
+    assert!(grayscale_requested(ColorDepthMode::Auto, &env));
+    assert_eq!(
+        detect_color_level(
+            false,
+            ColorMode::Auto,
+            ColorDepthMode::Auto,
+            &TestEnv::default().with("FORCE_COLOR", value)
+        ),
+        ColorLevel::Ansi16
+    );
+}
+
+#[test]
+fn test_grayscale_not_requested_by_default() {
+    let env = TestEnv::default().with("FORCE_COLOR", "3");
+    assert!(!grayscale_requested(ColorDepthMode::Auto, &env));
+    assert!(!grayscale_requested(ColorDepthMode::TrueColor, &env));
+    assert!(grayscale_requested(ColorDepthMode::Grayscale, &env));
+}
+
+#[test]
+fn test_grayscale_respects_no_color() {
+    let _guard = TestStateGuard::with_depth(
+        ColorMode::Always,
+        ColorDepthMode::Grayscale,
+        Some("1"),
+        Some(true),
+    );
+    assert_eq!("x".red().bold().to_string(), "x");
+}
+
+#[rstest]
+#[case("x".bright_red(), "\x1b[1;31mx\x1b[0m")]
+#[case("x".bright_red().bold(), "\x1b[1;31mx\x1b[0m")]
+#[case("x".bright_black().underline(), "\x1b[1;4;30mx\x1b[0m")]
+#[case("x".red(), "\x1b[31mx\x1b[0m")]
+#[case("x".on_bright_blue(), "\x1b[44mx\x1b[0m")]
+#[case("x".bright_white().on_bright_green(), "\x1b[1;37;42mx\x1b[0m")]
+#[case("x".rgb(255, 128, 0), "\x1b[33mx\x1b[0m")]
+#[case("x".ansi256(208), "\x1b[33mx\x1b[0m")]
+#[case("x".ansi256(9), "\x1b[31mx\x1b[0m")]
+#[case("x".on_rgb(250, 250, 250), "\x1b[47mx\x1b[0m")]
+#[case("x".on_hex("#000080"), "\x1b[44mx\x1b[0m")]
+fn test_ansi8_rendering(#[case] text: StyledText, #[case] expected: &str) {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    assert_eq!(render_at(text, ColorLevel::Ansi8), expected);
+}
+
+#[test]
+fn test_ansi8_level_ordering_and_depth_mode() {
+    assert!(ColorLevel::NoColor < ColorLevel::Ansi8);
+    assert!(ColorLevel::Ansi8 < ColorLevel::Ansi16);
+
+    let env = TestEnv::default().with("TERM", "xterm-256color");
+    assert_eq!(
+        detect_color_level(true, ColorMode::Auto, ColorDepthMode::Ansi8, &env),
+        ColorLevel::Ansi8
+    );
+}
+
+#[rstest]
+#[case(TestEnv::default().with("TERM", "linux"), ColorLevel::Ansi8)]
+#[case(TestEnv::default().with("TERM", "vt100"), ColorLevel::Ansi8)]
+#[case(TestEnv::default().with("TERM", "VT220"), ColorLevel::Ansi8)]
+#[case(TestEnv::default().with("TERM", "ansi"), ColorLevel::Ansi8)]
+#[case(TestEnv::default().with("TERM", "xterm"), ColorLevel::Ansi16)]
+#[case(
+    TestEnv::default().with("TERM", "linux").with("COLORTERM", "truecolor"),
+    ColorLevel::TrueColor
+)]
+#[case(TestEnv::default().with("TERM", "linux").with("CLICOLOR_FORCE", "1"), ColorLevel::Ansi8)]
+#[case(TestEnv::default().with("FORCE_COLOR", "ansi8"), ColorLevel::Ansi8)]
+#[case(
+    TestEnv::default().with("TERM", "linux").with("FORCE_COLOR", "grayscale"),
+    ColorLevel::Ansi8
+)]
+fn test_detect_ansi8_level(#[case] env: TestEnv, #[case] expected: ColorLevel) {
+    assert_eq!(
+        detect_color_level(true, ColorMode::Auto, ColorDepthMode::Auto, &env),
+        expected
+    );
+}
+
+#[test]
+fn test_ansi8_grayscale_and_dithering() {
+    let _guard = grayscale_guard();
+    assert_eq!(
+        render_at("x".rgb(255, 255, 255), ColorLevel::Ansi8),
+        "\x1b[1;37mx\x1b[0m"
+    );
+    assert_eq!(
+        render_at("x".on_rgb(255, 255, 255), ColorLevel::Ansi8),
+        "\x1b[47mx\x1b[0m"
+    );
+    assert_eq!(
+        render_at("x".rgb(0, 0, 0), ColorLevel::Ansi8),
+        "\x1b[30mx\x1b[0m"
+    );
+
+    ColorizeConfig::set_color_depth_mode(ColorDepthMode::Auto);
+    let output = render_at(
+        "abcdefgh".rgb(64, 0, 0).dither(Dither::Ordered),
+        ColorLevel::Ansi8,
+    );
+    assert_eq!(
+        output,
+        "\x1b[31ma\x1b[30mb\x1b[31mc\x1b[30md\x1b[31me\x1b[30mf\x1b[31mg\x1b[30mh\x1b[0m"
+    );
+}
+
+#[rstest]
+#[case(ColorLevel::Ansi16, "\x1b[91;41mtest\x1b[0m")]
+#[case(ColorLevel::Ansi8, "\x1b[33;41mtest\x1b[0m")]
+#[case(
+    ColorLevel::TrueColor,
+    "\x1b[38;2;150;20;20;48;2;120;10;10mtest\x1b[0m"
+)]
+fn test_downgraded_foreground_avoids_background_collision(
+    #[case] level: ColorLevel,
+    #[case] expected: &str,
+) {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+
+    assert_eq!(
+        render_at("test".rgb(150, 20, 20).on_rgb(120, 10, 10), level),
+        expected
+    );
+}
+
+#[test]
+fn test_dithered_and_fallback_foregrounds_avoid_background_collision() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let dithered = render_at(
+        "abcdefgh"
+            .rgb(150, 20, 20)
+            .on_rgb(120, 10, 10)
+            .dither(Dither::Ordered),
+        ColorLevel::Ansi16,
+    );
+
+    assert!(!dithered.contains("\x1b[31;41m"), "{dithered:?}");
+    assert_eq!(
+        render_at(
+            "test"
+                .fallback(FallbackColor::new(Color::rgb(150, 20, 20)))
+                .on_rgb(120, 10, 10),
+            ColorLevel::Ansi16
+        ),
+        "\x1b[91;41mtest\x1b[0m"
+    );
+}
+
+#[test]
+fn test_downgraded_gray_foreground_stays_gray() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+
+    assert_eq!(
+        render_at(
+            "test".rgb(60, 60, 60).on_rgb(30, 30, 30),
+            ColorLevel::Ansi16
+        ),
+        "\x1b[90;40mtest\x1b[0m"
+    );
+}
+
+#[test]
+fn test_collision_avoidance_keeps_explicit_and_readable_pairs() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+
+    assert_eq!(
+        render_at("test".red().on_red(), ColorLevel::Ansi16),
+        "\x1b[31;41mtest\x1b[0m"
+    );
+    assert_eq!(
+        render_at(
+            "test".rgb(250, 10, 10).on_rgb(10, 10, 250),
+            ColorLevel::Ansi16
+        ),
+        "\x1b[91;104mtest\x1b[0m"
+    );
+    // A pair that was already indistinguishable is left as designed.
+    assert_eq!(
+        render_at(
+            "test".rgb(120, 10, 10).on_rgb(120, 10, 10),
+            ColorLevel::Ansi16
+        ),
+        "\x1b[31;41mtest\x1b[0m"
+    );
+}
+
+#[rstest]
+#[case(palettes::nord::FROST_1, "#8fbcbb")]
+#[case(palettes::solarized::BASE03, "#002b36")]
+#[case(palettes::dracula::PURPLE, "#bd93f9")]
+#[case(palettes::gruvbox::BRIGHT_ORANGE, "#fe8019")]
+#[case(palettes::catppuccin::mocha::BASE, "#1e1e2e")]
+#[case(palettes::tokyo_night::STORM_BG, "#24283b")]
+#[case(palettes::tailwind::BLUE_500, "#3b82f6")]
+#[case(palettes::material::DEEP_PURPLE_A200, "#7c4dff")]
+fn test_palette_constants_match_published_values(#[case] color: Color, #[case] hex: &str) {
+    assert_eq!(Color::from_hex(hex), Some(color));
+}
+
+#[test]
+fn test_palette_metadata_and_entries() {
+    use palettes::{catppuccin, material, nord, tailwind};
+
+    assert_eq!(nord::PALETTE.name(), "Nord");
+    assert_eq!(nord::PALETTE.background(), Some(Background::Dark));
+    assert_eq!(
+        catppuccin::latte::PALETTE.background(),
+        Some(Background::Light)
+    );
+    assert_eq!(tailwind::PALETTE.background(), None);
+
+    assert_eq!(nord::PALETTE.len(), 16);
+    assert_eq!(catppuccin::mocha::PALETTE.len(), 26);
+    assert_eq!(tailwind::PALETTE.len(), 22 * 11);
+    assert_eq!(material::PALETTE.len(), 16 * 14 + 3 * 10);
+
+    assert_eq!(
+        nord::PALETTE.iter().nth(7),
+        Some(("frost_1", nord::FROST_1))
+    );
+    assert_eq!(tailwind::PALETTE.get("BLUE_500"), Some(tailwind::BLUE_500));
+    assert_eq!(tailwind::PALETTE.get("blue_1000"), None);
+    assert_eq!(
+        nord::PALETTE.colors().take(2).collect::<Vec<_>>(),
+        [nord::POLAR_NIGHT_1, nord::POLAR_NIGHT_2]
+    );
+}
+
+#[test]
+fn test_palette_entry_names_are_unique() {
+    for palette in palettes::all() {
+        let mut names: Vec<_> = palette.into_iter().map(|(name, _)| name).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), palette.len(), "{}", palette.name());
+    }
+}
+
+#[test]
+fn test_palettes_by_name() {
+    assert_eq!(palettes::all().count(), 14);
+    assert_eq!(
+        palettes::by_name("tokyo-night"),
+        Some(palettes::tokyo_night::NIGHT)
+    );
+    assert_eq!(
+        palettes::by_name("CATPPUCCIN_MOCHA"),
+        Some(palettes::catppuccin::mocha::PALETTE)
+    );
+    assert_eq!(palettes::by_name("nord light"), None);
+}
+
+#[test]
+fn test_palette_colors_work_with_rgb_methods() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let (r, g, b) = palettes::nord::FROST_2.to_rgb();
+
+    assert_eq!(
+        render_at("test".rgb(r, g, b), ColorLevel::TrueColor),
+        "\x1b[38;2;136;192;208mtest\x1b[0m"
+    );
+}
+
+#[rstest]
+#[case(8, 8)]
+#[case(10, 10)]
+#[case(48, 0)]
+fn test_categorical_colors_stay_distinct_when_quantized(
+    #[case] n: usize,
+    #[case] distinct_named: usize,
+) {
+    let colors = palettes::Palette::categorical_colors(n);
+    let mut ansi256: Vec<_> = colors
+        .iter()
+        .map(|color| rgb_to_ansi256(color.r, color.g, color.b))
+        .collect();
+    ansi256.sort_unstable();
+    ansi256.dedup();
+
+    assert_eq!(colors.len(), n);
+    assert_eq!(ansi256.len(), n);
+    if distinct_named > 0 {
+        let mut named = Vec::new();
+        for color in &colors {
+            let color = rgb_to_named_color(color.r, color.g, color.b);
+            if !named.contains(&color) {
+                named.push(color);
+            }
+        }
+        assert_eq!(named.len(), distinct_named);
+    }
+}
+
+#[test]
+fn test_categorical_spaces_hues_evenly() {
+    assert!(palettes::Palette::categorical_colors(0).is_empty());
+    assert_eq!(palettes::Palette::categorical_colors(1).len(), 1);
+
+    for n in 3..=10 {
+        let spacing = 360.0 / n as f32;
+        let hues: Vec<f32> = palettes::Palette::categorical_colors(n)
+            .into_iter()
+            .map(|color| crate::color::rgb_to_oklch(color).2)
+            .collect();
+        for pair in hues.windows(2) {
+            let gap = (pair[1] - pair[0]).rem_euclid(360.0);
+            assert!((gap - spacing).abs() < 2.0, "n = {n}, hues {hues:?}");
+        }
+    }
+
+    // Without collisions to resolve, every color keeps the base lightness.
+    for color in palettes::Palette::categorical_colors(4) {
+        assert!((crate::color::rgb_to_oklch(color).0 - 0.72).abs() < 0.01);
+    }
+    assert_ne!(
+        palettes::Palette::categorical_colors(6)[0],
+        palettes::Palette::categorical_colors(6)[1]
+    );
+}
+
+#[test]
+fn test_color_from_hash_is_stable_and_readable() {
+    assert_eq!(
+        Color::from_hash("api-gateway"),
+        Color::from_hash("api-gateway")
+    );
+    assert_eq!(Color::from_hash(42_u64), Color::from_hash(42_u64));
+    // The color for a key must not change between releases.
+    assert_eq!(Color::from_hash("api-gateway"), Color::rgb(96, 167, 255));
+
+    let set = palettes::Palette::categorical_colors(8);
+    let mut seen = Vec::new();
+    for key in 0..64_u32 {
+        let color = Color::from_hash(key);
+        assert!(set.contains(&color));
+        assert!(color.contrast_ratio(Color::rgb(0, 0, 0)) >= 4.5);
+        if !seen.contains(&color) {
+            seen.push(color);
+        }
+    }
+    assert_eq!(seen.len(), set.len());
+}
+
+#[rstest]
+#[case(Colormap::viridis(), "#440154", "#21918c", "#fde725")]
+#[case(Colormap::magma(), "#000004", "#b73779", "#fcfdbf")]
+#[case(Colormap::plasma(), "#0d0887", "#cc4778", "#f0f921")]
+#[case(Colormap::inferno(), "#000004", "#bc3754", "#fcffa4")]
+#[case(Colormap::cividis(), "#00204c", "#7c7b78", "#ffe945")]
+#[case(Colormap::red_blue(), "#67001f", "#f7f7f7", "#053061")]
+fn test_builtin_colormaps_match_reference_values(
+    #[case] map: Colormap,
+    #[case] start: &str,
+    #[case] middle: &str,
+    #[case] end: &str,
+) {
+    for (t, hex) in [(0.0, start), (0.5, middle), (1.0, end)] {
+        let (actual, expected) = (map.sample(t), Color::from_hex(hex).unwrap());
+        let close = |a: u8, b: u8| a.abs_diff(b) <= 6;
+        assert!(
+            close(actual.r, expected.r)
+                && close(actual.g, expected.g)
+                && close(actual.b, expected.b),
+            "{actual:?} is not close to {expected:?} at {t}"
+        );
+    }
+}
+
+#[test]
+fn test_turbo_colormap_runs_blue_to_red() {
+    let turbo = Colormap::turbo();
+    let (start, middle, end) = (turbo.sample(0.0), turbo.sample(0.5), turbo.sample(1.0));
+
+    assert!(turbo.sample(0.1).b > turbo.sample(0.1).r);
+    assert!(middle.g > middle.r && middle.g > middle.b);
+    assert!(end.r > end.g && end.r > end.b);
+    assert_ne!(start, end);
+}
+
+#[test]
+fn test_colormap_clamps_out_of_range_values() {
+    let map = Colormap::viridis();
+
+    assert_eq!(map.sample(-1.0), map.sample(0.0));
+    assert_eq!(map.sample(2.0), map.sample(1.0));
+    assert_eq!(map.sample(f32::NAN), map.sample(0.0));
+    assert_eq!(map.sample_range(150.0, 0.0, 100.0), map.sample(1.0));
+    assert_eq!(map.sample_range(-5.0, 0.0, 100.0), map.sample(0.0));
+    assert_eq!(map.sample_range(25.0, 0.0, 100.0), map.sample(0.25));
+    assert_eq!(map.sample_range(25.0, 100.0, 0.0), map.sample(0.75));
+    assert_eq!(map.sample_range(7.0, 5.0, 5.0), map.sample(0.0));
+}
+
+#[test]
+fn test_custom_colormap_from_stops() {
+    let black = Color::rgb(0, 0, 0);
+    let red = Color::rgb(255, 0, 0);
+    let white = Color::rgb(255, 255, 255);
+    let map = Colormap::new([(1.0, white), (0.0, black), (0.8, red), (f32::NAN, white)]);
+
+    assert_eq!(map.sample(0.0), black);
+    assert_eq!(map.sample(0.4), Color::rgb(128, 0, 0));
+    assert_eq!(map.sample(0.8), red);
+    assert_eq!(map.sample(1.0), white);
+    assert_eq!(
+        Colormap::evenly_spaced([black, white]).sample(0.5),
+        Color::rgb(128, 128, 128)
+    );
+    assert_eq!(Colormap::new([]).sample(0.5), black);
+    assert_eq!(
+        Colormap::new([(0.5, red)]).sample(0.0),
+        Colormap::new([(0.5, red)]).sample(1.0)
+    );
+    assert_ne!(
+        Colormap::evenly_spaced([black, white])
+            .space(InterpolationSpace::Linear)
+            .sample(0.5),
+        Color::rgb(128, 128, 128)
+    );
+}
+
+#[test]
+fn test_colormap_styles_text_by_value() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let map = Colormap::evenly_spaced([Color::rgb(0, 0, 0), Color::rgb(255, 255, 255)]);
+
+    assert_eq!(
+        render_at(
+            "42ms".colormap(50.0, 0.0, 100.0, &map),
+            ColorLevel::TrueColor
+        ),
+        "\x1b[38;2;128;128;128m42ms\x1b[0m"
+    );
+    assert_eq!(
+        render_at(
+            "cpu".on_colormap(250.0, 0.0, 100.0, &map),
+            ColorLevel::TrueColor
+        ),
+        "\x1b[48;2;255;255;255mcpu\x1b[0m"
+    );
+}
+
+#[test]
+fn test_style_paints_and_overrides_styled_text() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let warning = Style::new().fg_named(NamedColor::Yellow).bold();
+
+    assert!(Style::new().is_plain());
+    assert!(!warning.is_plain());
+    assert_eq!(
+        warning.paint("careful").to_string(),
+        "\x1b[1;33mcareful\x1b[0m"
+    );
+    assert_eq!(
+        "careful".red().italic().style(&warning).to_string(),
+        "\x1b[1;3;33mcareful\x1b[0m"
+    );
+    assert_eq!(
+        "careful".on_blue().style(&warning).to_string(),
+        "\x1b[1;33;44mcareful\x1b[0m"
+    );
+    assert_eq!(
+        Style::new()
+            .fg(Color::rgb(255, 128, 0))
+            .bg_ansi256(236)
+            .paint("x")
+            .to_string(),
+        "\x1b[38;2;255;128;0;48;5;236mx\x1b[0m"
+    );
+    assert_eq!(
+        Style::new().raw("31").paint("x").to_string(),
+        "\x1b[31mx\x1b[0m"
+    );
+    assert_eq!(Style::new().paint("plain").to_string(), "plain");
+}
+
+#[test]
+fn test_scale_thresholds_pick_first_matching_style() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let green = Style::new().fg_named(NamedColor::Green);
+    let yellow = Style::new().fg_named(NamedColor::Yellow);
+    let red = Style::new().fg_named(NamedColor::Red).bold();
+    let scale = Scale::new()
+        .below(90.0, yellow.clone())
+        .below(70.0, green.clone())
+        .otherwise(red.clone());
+
+    assert_eq!(scale.style_for(12.0), &green);
+    assert_eq!(scale.style_for(70.0), &yellow);
+    assert_eq!(scale.style_for(89.9), &yellow);
+    assert_eq!(scale.style_for(90.0), &red);
+    assert_eq!(scale.style_for(f64::NAN), &red);
+    assert_eq!(scale.paint(95).to_string(), "\x1b[1;31m95\x1b[0m");
+    assert_eq!(Scale::new().paint(5).to_string(), "5");
+}
+
+#[test]
+fn test_scale_sign_mode() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let scale = Scale::sign();
+
+    assert_eq!(scale.paint(-1.5).to_string(), "\x1b[31m-1.5\x1b[0m");
+    assert_eq!(scale.paint(0).to_string(), "\x1b[2m0\x1b[0m");
+    assert_eq!(scale.paint(2.25).to_string(), "\x1b[32m2.25\x1b[0m");
+    assert_eq!(scale.style_for(f64::NAN), &Style::new().dim());
+
+    let custom = Scale::sign_with(
+        Style::new().fg_named(NamedColor::Magenta),
+        Style::new(),
+        Style::new().fg_named(NamedColor::Cyan),
+    );
+    assert_eq!(custom.paint(-3).to_string(), "\x1b[35m-3\x1b[0m");
+    assert_eq!(custom.paint(0).to_string(), "0");
+}
+
+#[test]
+fn test_scale_precision_rounds_before_choosing_style() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let sign = Scale::sign().precision(2);
+
+    assert_eq!(sign.paint(1.0).to_string(), "\x1b[32m1.00\x1b[0m");
+    assert_eq!(sign.paint(-0.001).to_string(), "\x1b[2m0.00\x1b[0m");
+    assert_eq!(sign.paint(0.004_f32).to_string(), "\x1b[2m0.00\x1b[0m");
+
+    let usage = Scale::new()
+        .below(70.0, Style::new().fg_named(NamedColor::Green))
+        .otherwise(Style::new().fg_named(NamedColor::Red))
+        .precision(0);
+    assert_eq!(usage.paint(69.6).to_string(), "\x1b[31m70\x1b[0m");
+    assert_eq!(usage.paint(69.4).to_string(), "\x1b[32m69\x1b[0m");
+}
+
+#[rstest]
+#[case(Color::rgb(0xff, 0x80, 0x01), "darkorange")]
+#[case(Color::rgb(0x66, 0x33, 0x99), "rebeccapurple")]
+#[case(Color::rgb(0x80, 0x80, 0x80), "gray")]
+#[case(Color::rgb(0x00, 0xff, 0xff), "aqua")]
+#[case(Color::rgb(0x01, 0x02, 0x03), "black")]
+fn test_nearest_css_name(#[case] color: Color, #[case] expected: &str) {
+    assert_eq!(color.nearest_css_name().0, expected);
+}
+
+#[test]
+fn test_nearest_lookups_report_distances() {
+    assert_eq!(
+        Color::rgb(255, 140, 0).nearest_css_name(),
+        ("darkorange", 0.0)
+    );
+    let (name, distance) = Color::rgb(0xff, 0x80, 0x01).nearest_css_name();
+    assert_eq!(name, "darkorange");
+    assert!((distance - 145_f32.sqrt()).abs() < 1e-4);
+
+    assert_eq!(Color::rgb(255, 135, 0).nearest_ansi256(), (208, 0.0));
+    let (index, distance) = Color::rgb(0xff, 0x80, 0x01).nearest_ansi256();
+    assert_eq!(index, 208);
+    assert!((distance - 50_f32.sqrt()).abs() < 1e-4);
+
+    assert_eq!(
+        Color::rgb(255, 0, 0).nearest_named(),
+        (NamedColor::BrightRed, 0.0)
+    );
+    let (named, distance) = Color::rgb(120, 10, 0).nearest_named();
+    assert_eq!(named, NamedColor::Red);
+    assert!((distance - 164_f32.sqrt()).abs() < 1e-4);
+}
+
+#[rstest]
+#[case(
+    ExtendedColorSyntax::Semicolon,
+    "\x1b[1;38;2;255;128;0;48;5;236mtest\x1b[0m"
+)]
+#[case(
+    ExtendedColorSyntax::ColonWithColorSpace,
+    "\x1b[1;38:2::255:128:0;48:5:236mtest\x1b[0m"
+)]
+#[case(
+    ExtendedColorSyntax::Colon,
+    "\x1b[1;38:2:255:128:0;48:5:236mtest\x1b[0m"
+)]
+fn test_extended_color_syntax_from_config(
+    #[case] syntax: ExtendedColorSyntax,
+    #[case] expected: &str,
+) {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    ColorizeConfig::set_extended_color_syntax(syntax);
+
+    assert_eq!(ColorizeConfig::extended_color_syntax(), syntax);
+    assert_eq!(
+        "test".rgb(255, 128, 0).on_ansi256(236).bold().to_string(),
+        expected
+    );
+    assert_eq!("test".red().to_string(), "\x1b[31mtest\x1b[0m");
+}
+
+#[test]
+fn test_extended_color_syntax_from_capabilities() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    ColorizeConfig::set_extended_color_syntax(ExtendedColorSyntax::Colon);
+    let capabilities = TerminalCapabilities {
+        extended_color_syntax: ExtendedColorSyntax::ColonWithColorSpace,
+        ..TerminalCapabilities::new(true, ColorLevel::TrueColor)
+    };
+
+    assert_eq!(
+        "test"
+            .rgb(255, 128, 0)
+            .render(RenderTarget::Capabilities(capabilities)),
+        "\x1b[38:2::255:128:0mtest\x1b[0m"
+    );
+    assert_eq!(
+        render_at("test".ansi256(208), ColorLevel::Ansi256),
+        "\x1b[38;5;208mtest\x1b[0m"
+    );
+    assert_eq!(
+        ColorizeConfig::terminal_capabilities(RenderTarget::Stdout).extended_color_syntax,
+        ExtendedColorSyntax::Colon
+    );
+}
+
+#[rstest]
+#[case("error", "\x1b[1;31merror\x1b[0m")]
+#[case("warning", "\x1b[33mwarning\x1b[0m")]
+#[case("success", "\x1b[32msuccess\x1b[0m")]
+#[case("hint", "\x1b[36mhint\x1b[0m")]
+#[case("path", "\x1b[4;34mpath\x1b[0m")]
+#[case("code", "\x1b[35mcode\x1b[0m")]
+#[case("muted", "\x1b[2mmuted\x1b[0m")]
+fn test_default_theme_roles(#[case] role: &str, #[case] expected: &str) {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    assert_eq!(role.role(role).to_string(), expected);
+}
+
+#[test]
+fn test_builtin_themes_define_every_role() {
+    for theme in [
+        Theme::default_theme(),
+        Theme::high_contrast(),
+        Theme::monochrome(),
+    ] {
+        for role in Theme::ROLES {
+            assert!(theme.style(role).is_some(), "{} lacks {role}", theme.name());
+        }
+        assert_eq!(Theme::builtin(theme.name()), Some(theme.clone()));
+    }
+    assert_eq!(
+        Theme::builtin("High Contrast"),
+        Some(Theme::high_contrast())
+    );
+    assert_eq!(Theme::builtin("solarized"), None);
+}
+
+#[test]
+fn test_thread_theme_overrides_process_theme() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    ColorizeConfig::set_process_theme(Some(Theme::monochrome()));
+    assert_eq!(ColorizeConfig::active_theme(), Theme::monochrome());
+    assert_eq!("x".role("error").to_string(), "\x1b[1;4mx\x1b[0m");
+
+    let custom =
+        Theme::new("custom").with_role("error", Style::new().fg_named(NamedColor::Magenta));
+    ColorizeConfig::set_theme(Some(custom.clone()));
+    assert_eq!(ColorizeConfig::theme(), Some(custom));
+    assert_eq!("x".role("error").to_string(), "\x1b[35mx\x1b[0m");
+    assert_eq!("x".role("warning").to_string(), "x");
+
+    let other_thread = std::thread::spawn(|| "x".role("error").to_string())
+        .join()
+        .unwrap();
+    assert_eq!(other_thread, "x");
+
+    ColorizeConfig::set_theme(None);
+    ColorizeConfig::set_process_theme(None);
+    assert_eq!(ColorizeConfig::active_theme(), Theme::default_theme());
+}
+
+#[test]
+fn test_role_composes_with_chained_styles() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    assert_eq!(
+        "x".italic().role("error").to_string(),
+        "\x1b[1;3;31mx\x1b[0m"
+    );
+    assert_eq!("x".role("path").red().to_string(), "\x1b[4;31mx\x1b[0m");
+}
+
+#[test]
+fn test_unknown_roles_render_plain_and_can_warn() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    assert_eq!("x".role("unknown-quiet").to_string(), "x");
+    assert!(!warned_about_role_for_tests("unknown-quiet"));
+
+    ColorizeConfig::set_warn_unknown_roles(true);
+    assert_eq!(
+        "x".bold().role("unknown-loud").to_string(),
+        "\x1b[1mx\x1b[0m"
+    );
+    assert!(warned_about_role_for_tests("unknown-loud"));
+    assert!(!warned_about_role_for_tests("error"));
+    assert_ne!("x".role("error").to_string(), "x");
+    assert!(!warned_about_role_for_tests("error"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_theme_from_toml_with_variables_and_extends() {
+    let theme = Theme::from_toml_str(
+        r##"
+        name = "ocean"
+        extends = "monochrome"
+        "$accent" = "#0080ff"
+        "$loud" = "bold bright_red"
+
+        [roles]
+        path = "underline $accent"
+        error = "$loud on black"
+        code = { fg = "$accent", bg = "ansi256(236)", italic = true, bold = false }
+        "##,
+    )
+    .unwrap();
+
+    assert_eq!(theme.name(), "ocean");
+    assert_eq!(
+        theme.style("path"),
+        Some(&Style::new().underline().fg(Color::rgb(0, 128, 255)))
+    );
+    assert_eq!(
+        theme.style("error"),
+        Some(
+            &Style::new()
+                .bold()
+                .fg_named(NamedColor::BrightRed)
+                .bg_named(NamedColor::Black)
+        )
+    );
+    assert_eq!(
+        theme.style("code"),
+        Some(
+            &Style::new()
+                .fg(Color::rgb(0, 128, 255))
+                .bg_ansi256(236)
+                .italic()
+        )
+    );
+    assert_eq!(theme.style("muted"), Theme::monochrome().style("muted"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_theme_from_json_matches_toml() {
+    let json = Theme::from_json_str(
+        r##"{
+            "$accent": "#0080ff",
+            "roles": { "hint": "italic $accent", "error": { "fg": "red", "bold": true } }
+        }"##,
+    )
+    .unwrap();
+    let toml = Theme::from_toml_str(
+        r##"
+        "$accent" = "#0080ff"
+        [roles]
+        hint = "italic $accent"
+        error = { fg = "red", bold = true }
+        "##,
+    )
+    .unwrap();
+
+    assert_eq!(json, toml);
+    assert_eq!(json.name(), "custom");
+    assert_eq!(json.style("warning"), None);
+}
+
+#[cfg(feature = "serde")]
+#[rstest]
+#[case("colour = \"red\"", "colour", "unknown key")]
+#[case(
+    "[roles]\nerror = \"bold reddish\"",
+    "roles.error",
+    "unknown color or style \"reddish\""
+)]
+#[case(
+    "[roles]\nerror = \"$nope\"",
+    "roles.error",
+    "unknown variable \"$nope\""
+)]
+#[case(
+    "[roles]\nerror = \"red on\"",
+    "roles.error",
+    "expected a color after \"on\""
+)]
+#[case(
+    "[roles]\nerror = 3",
+    "roles.error",
+    "expected a style string or table, found a number"
+)]
+#[case(
+    "[roles.error]\nfg = \"#12\"",
+    "roles.error.fg",
+    "invalid hex color \"#12\""
+)]
+#[case(
+    "[roles]\nerror = \"#aééb\"",
+    "roles.error",
+    "invalid hex color \"#aééb\""
+)]
+#[case(
+    "[roles.error]\nfg = \"#aééb\"",
+    "roles.error.fg",
+    "invalid hex color \"#aééb\""
+)]
+#[case(
+    "[roles.error]\nfg = \"red blue\"",
+    "roles.error.fg",
+    "expected one color, found \"red blue\""
+)]
+#[case(
+    "[roles.error]\nblink = true",
+    "roles.error.blink",
+    "unknown style field"
+)]
+#[case(
+    "[roles.error]\nbold = \"yes\"",
+    "roles.error.bold",
+    "expected a boolean, found a string"
+)]
+#[case(
+    "extends = \"no-such-theme\"",
+    "extends",
+    "\"no-such-theme\" is neither a built-in theme nor a theme file"
+)]
+#[case(
+    "\"$a\" = \"$b\"\n[roles]\nerror = \"$a\"",
+    "roles.error",
+    "variable \"$a\" refers to another variable"
+)]
+fn test_theme_validation_errors_name_the_key(
+    #[case] source: &str,
+    #[case] key: &str,
+    #[case] message: &str,
+) {
+    let error = Theme::from_toml_str(source).unwrap_err();
+    assert_eq!(error.key(), Some(key));
+    assert_eq!(error.message(), message);
+    assert_eq!(error.path(), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_theme_file_errors_include_path() {
+    let dir = TempDir::new();
+    let path = dir.write("broken.toml", "[roles]\nerror = \"bold nope\"\n");
+
+    let error = Theme::from_file(&path).unwrap_err();
+    assert_eq!(error.path(), Some(path.as_path()));
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "{}: roles.error: unknown color or style \"nope\"",
+            path.display()
+        )
+    );
+
+    let syntax = dir.write("syntax.json", "{ roles: }");
+    let error = Theme::from_file(&syntax).unwrap_err();
+    assert_eq!(error.path(), Some(syntax.as_path()));
+    assert_eq!(error.key(), None);
+    assert!(error.message().starts_with("invalid JSON"));
+
+    let missing = dir.0.join("missing.toml");
+    assert_eq!(
+        Theme::from_file(&missing).unwrap_err().path(),
+        Some(missing.as_path())
+    );
+    assert_eq!(
+        Theme::from_file(dir.write("theme.yaml", ""))
+            .unwrap_err()
+            .message(),
+        "expected a .toml or .json file"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_theme_files_extend_other_files() {
+    let dir = TempDir::new();
+    dir.write(
+        "base/brand.json",
+        r##"{ "extends": "high-contrast", "$accent": "#ff8000", "roles": { "hint": "$accent" } }"##,
+    );
+    let child = dir.write(
+        "child.toml",
+        "extends = \"base/brand.json\"\n[roles]\npath = \"underline $accent\"\n",
+    );
+
+    let theme = Theme::from_file(&child).unwrap();
+    assert_eq!(theme.name(), "child");
+    assert_eq!(
+        theme.style("hint"),
+        Some(&Style::new().fg(Color::rgb(255, 128, 0)))
+    );
+    assert_eq!(
+        theme.style("path"),
+        Some(&Style::new().underline().fg(Color::rgb(255, 128, 0)))
+    );
+    assert_eq!(theme.style("error"), Theme::high_contrast().style("error"));
+
+    let looped = dir.write("loop.toml", "extends = \"loop.toml\"\n");
+    let error = Theme::from_file(&looped).unwrap_err();
+    assert_eq!(error.key(), Some("extends"));
+    assert_eq!(error.message(), "theme extends itself");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_loaded_theme_can_be_activated() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let theme =
+        Theme::from_toml_str("extends = \"default\"\n[roles]\nerror = \"magenta\"\n").unwrap();
+    ColorizeConfig::set_theme(Some(theme));
+
+    assert_eq!("x".role("error").to_string(), "\x1b[35mx\x1b[0m");
+    assert_eq!("x".role("warning").to_string(), "\x1b[33mx\x1b[0m");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_user_config_path_follows_xdg_config_home() {
+    use crate::user_config::config_path;
+    use std::path::PathBuf;
+
+    let env = TestEnv::default()
+        .with("XDG_CONFIG_HOME", "/xdg")
+        .with("HOME", "/home/me");
+    assert_eq!(
+        config_path(&env),
+        Some(PathBuf::from("/xdg/colored_text/config.toml"))
+    );
+
+    let env = TestEnv::default()
+        .with("XDG_CONFIG_HOME", "relative")
+        .with("HOME", "/home/me");
+    assert_eq!(
+        config_path(&env),
+        Some(PathBuf::from("/home/me/.config/colored_text/config.toml"))
+    );
+    assert_eq!(config_path(&TestEnv::default()), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_user_config_parses_every_key() {
+    let dir = TempDir::new();
+    dir.write("mine.toml", "[roles]\nerror = \"magenta\"\n");
+    let path = dir.write(
+        "config.toml",
+        "color_mode = \"always\"\ncolor_depth = \"no-color\"\npalette = \"tokyo night\"\ntheme = \"mine.toml\"\n",
+    );
+
+    let config = UserConfig::from_file(&path).unwrap();
+    assert_eq!(config.color_mode(), Some(ColorMode::Always));
+    assert_eq!(config.color_depth_mode(), Some(ColorDepthMode::NoColor));
+    assert_eq!(config.palette(), palettes::by_name("Tokyo Night"));
+    assert_eq!(config.theme().map(Theme::name), Some("mine"));
+
+    let config = UserConfig::from_toml_str("theme = \"High Contrast\"").unwrap();
+    assert_eq!(config.theme(), Some(&Theme::high_contrast()));
+    assert_eq!(config.color_mode(), None);
+    assert_eq!(
+        UserConfig::from_toml_str("").unwrap(),
+        UserConfig::default()
+    );
+}
+
+#[cfg(feature = "serde")]
+#[rstest]
+#[case("color_mode = \"sometimes\"", "color_mode")]
+#[case("color_depth = 256", "color_depth")]
+#[case("palette = \"no such palette\"", "palette")]
+#[case("theme = \"missing.toml\"", "theme")]
+#[case("colour_mode = \"always\"", "colour_mode")]
+fn test_user_config_rejects_invalid_values(#[case] source: &str, #[case] key: &str) {
+    let error = UserConfig::from_toml_str(source).unwrap_err();
+    assert_eq!(error.key(), Some(key));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_load_user_config_reads_xdg_config_home() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Auto);
+    let dir = TempDir::new();
+    env::set_var("XDG_CONFIG_HOME", &dir.0);
+
+    assert!(!ColorizeConfig::load_user_config().unwrap());
+    assert_eq!(ColorizeConfig::user_config(), None);
+
+    let path = dir.write("colored_text/config.toml", "color_mode = \"never\"\n");
+    assert!(ColorizeConfig::load_user_config().unwrap());
+    assert_eq!(
+        ColorizeConfig::user_config().and_then(|config| config.color_mode()),
+        Some(ColorMode::Never)
+    );
+
+    std::fs::write(&path, "color_mode = 1\n").unwrap();
+    let error = ColorizeConfig::load_user_config().unwrap_err();
+    assert_eq!(error.path(), Some(path.as_path()));
+    assert_eq!(error.key(), Some("color_mode"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_user_config_overrides_in_process_settings() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    ColorizeConfig::set_theme(Some(
+        Theme::default_theme().with_role("error", Style::new().fg_named(NamedColor::Blue)),
+    ));
+    let user_theme = "[roles]\nerror = \"magenta\"\n";
+    let dir = TempDir::new();
+    dir.write("theme.toml", user_theme);
+    let path = dir.write(
+        "config.toml",
+        "color_depth = \"ansi16\"\ntheme = \"theme.toml\"\n",
+    );
+    ColorizeConfig::set_user_config(Some(UserConfig::from_file(&path).unwrap()));
+
+    assert_eq!("x".role("error").to_string(), "\x1b[35mx\x1b[0m");
+    assert_eq!("x".role("warning").to_string(), "\x1b[33mx\x1b[0m");
+    let active = ColorizeConfig::active_theme();
+    assert_eq!(
+        active.style("error"),
+        Some(&Style::new().fg_named(NamedColor::Magenta))
+    );
+    assert_eq!(
+        active.style("warning"),
+        Some(&Style::new().fg_named(NamedColor::Yellow))
+    );
+    assert_eq!("x".rgb(255, 0, 0).to_string(), "\x1b[91mx\x1b[0m");
+    assert_eq!(
+        ColorizeConfig::color_depth_mode(),
+        ColorDepthMode::TrueColor
+    );
+
+    ColorizeConfig::set_user_config(Some(
+        UserConfig::from_toml_str("color_depth = \"auto\"").unwrap(),
+    ));
+    assert_eq!("x".rgb(255, 0, 0).to_string(), "\x1b[38;5;9mx\x1b[0m");
+
+    ColorizeConfig::set_user_config(Some(
+        UserConfig::from_toml_str("color_mode = \"never\"").unwrap(),
+    ));
+    assert_eq!("x".red().to_string(), "x");
+
+    env::set_var("NO_COLOR", "1");
+    ColorizeConfig::set_user_config(Some(
+        UserConfig::from_toml_str("color_mode = \"always\"").unwrap(),
+    ));
+    assert_eq!("x".red().to_string(), "x");
+}
+
+#[test]
+fn test_ls_colors_parses_types_patterns_and_raw_codes() {
+    let ls_colors = LsColors::parse(
+        "di=01;34:ln=target:*.rs=38;5;208:*.tar=01;05;31:*.png=38;2;255;128;0;48;5;236:or=bogus:\
+         *.rs=33:pi=40;93",
+    );
+
+    assert_eq!(
+        ls_colors.style("di"),
+        Some(&Style::new().bold().fg_named(NamedColor::Blue))
+    );
+    assert_eq!(
+        ls_colors.style("*.rs"),
+        Some(&Style::new().fg_named(NamedColor::Yellow))
+    );
+    assert_eq!(
+        ls_colors.style("*.tar"),
+        Some(&Style::new().bold().fg_named(NamedColor::Red).raw("05"))
+    );
+    assert_eq!(
+        ls_colors.style("*.png"),
+        Some(&Style::new().fg(Color::rgb(255, 128, 0)).bg_ansi256(236))
+    );
+    assert_eq!(
+        ls_colors.style("pi"),
+        Some(
+            &Style::new()
+                .bg_named(NamedColor::Black)
+                .fg_named(NamedColor::BrightYellow)
+        )
+    );
+    assert_eq!(ls_colors.style("ln"), None);
+    assert_eq!(ls_colors.style("or"), None);
+}
+
+#[test]
+fn test_ls_colors_keeps_unknown_sgr_codes_when_rendered() {
+    let _guard = TestStateGuard::colors_enabled(ColorMode::Always);
+    let ls_colors = LsColors::parse("*.tar=01;05;31");
+    let style = ls_colors.style("*.tar").unwrap();
+
+    assert_eq!(
+        style.paint("a.tar").to_string(),
+        "\x1b[05;1;31ma.tar\x1b[0m"
+    );
+}
+
+#[test]
+fn test_ls_colors_from_dircolors_database() {
+    let ls_colors = LsColors::from_dircolors(
+        "# Configuration file for dircolors\n\
+         TERM xterm*\n\
+         COLORTERM ?*\n\
+         DIR 01;34 # directories\n\
+         link target\n\
+         EXEC 01;32\n\
+         .rs 33\n\
+         *Makefile 04;36\n\
+         NOT_A_KEYWORD 31\n",
+    );
+
+    assert_eq!(ls_colors.style("di"), LsColors::gnu_defaults().style("di"));
+    assert_eq!(
+        ls_colors.style("*.rs"),
+        Some(&Style::new().fg_named(NamedColor::Yellow))
+    );
+    assert_eq!(
+        ls_colors.style("*Makefile"),
+        Some(&Style::new().underline().fg_named(NamedColor::Cyan))
+    );
+    assert_eq!(ls_colors.style("ln"), None);
+    assert_eq!(ls_colors.style("ex"), LsColors::gnu_defaults().style("ex"));
+}
+
+#[test]
+fn test_ls_colors_from_env_falls_back_to_gnu_defaults() {
+    use crate::ls_colors::from_env;
+
+    assert_eq!(from_env(&TestEnv::default()), LsColors::gnu_defaults());
+    assert_eq!(
+        from_env(&TestEnv::default().with("LS_COLORS", "")),
+        LsColors::default()
+    );
+    assert_eq!(
+        from_env(&TestEnv::default().with("LS_COLORS", "di=31")).style("di"),
+        Some(&Style::new().fg_named(NamedColor::Red))
+    );
+}
+
+#[test]
+fn test_style_path_by_file_type_and_pattern() {
+    let dir = TempDir::new();
+    let source = dir.write("main.RS", "");
+    let notes = dir.write("notes.txt", "");
+    let ls_colors = LsColors::parse("di=01;34:fi=37:*.rs=33:*.RS=35");
+    let style = |path: &std::path::Path| {
+        ls_colors.style_path(path, &std::fs::symlink_metadata(path).unwrap())
+    };
+
+    assert_eq!(
+        style(&dir.0),
+        Style::new().bold().fg_named(NamedColor::Blue)
+    );
+    assert_eq!(style(&source), Style::new().fg_named(NamedColor::Magenta));
+    assert_eq!(style(&notes), Style::new().fg_named(NamedColor::White));
+    assert!(LsColors::new()
+        .style_path(&notes, &std::fs::metadata(&notes).unwrap())
+        .is_plain());
+
+    let upper_only = LsColors::parse("*.TXT=32");
+    assert_eq!(
+        upper_only.style_path(&notes, &std::fs::metadata(&notes).unwrap()),
+        Style::new().fg_named(NamedColor::Green)
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_style_path_uses_permissions_and_links() {
+    use std::os::unix::fs::{symlink, PermissionsExt};
+
+    let dir = TempDir::new();
+    let script = dir.write("build.rs", "");
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+    let link = dir.0.join("link");
+    symlink(&script, &link).unwrap();
+    let broken = dir.0.join("broken");
+    symlink(dir.0.join("missing"), &broken).unwrap();
+    let style = |colors: &LsColors, path: &std::path::Path| {
+        colors.style_path(path, &std::fs::symlink_metadata(path).unwrap())
+    };
+
+    let ls_colors = LsColors::parse("ex=01;32:ln=01;36:or=40;31:*.rs=33");
+    assert_eq!(
+        style(&ls_colors, &script),
+        Style::new().bold().fg_named(NamedColor::Green)
+    );
+    assert_eq!(
+        style(&ls_colors, &link),
+        Style::new().bold().fg_named(NamedColor::Cyan)
+    );
+    assert_eq!(
+        style(&ls_colors, &broken),
+        Style::new()
+            .bg_named(NamedColor::Black)
+            .fg_named(NamedColor::Red)
+    );
+
+    let follow = LsColors::parse("ex=01;32:ln=target");
+    assert_eq!(style(&follow, &link), style(&follow, &script));
+    assert!(style(&follow, &broken).is_plain());
+}
@@ -0,0 +1,207 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Mutex, OnceLock};
+
+use crate::color::NamedColor;
+use crate::config::{with_active_theme, ColorizeConfig};
+use crate::palettes::same_name;
+use crate::style::Style;
+
+/// Roles whose unknown-role warning has already been printed.
+static WARNED_ROLES: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+/// A set of semantic roles, such as `"error"` or `"path"`, and the [`Style`]
+/// each one is drawn with.
+///
+/// Text takes a role's style with `.role(name)`, which looks the role up in
+/// the active theme. The thread theme set with [`ColorizeConfig::set_theme`]
+/// wins over the process theme set with [`ColorizeConfig::set_process_theme`],
+/// and [`Theme::default_theme`] is used when neither is set. Roles the active
+/// theme does not define leave the text plain.
+///
+/// ```rust
+/// use colored_text::{Colorize, ColorizeConfig, NamedColor, Style, Theme};
+///
+/// println!("{} file not found", "error:".role("error"));
+///
+/// let theme = Theme::default_theme()
+///     .with_role("error", Style::new().fg_named(NamedColor::Magenta).bold())
+///     .with_role("metric", Style::new().fg_named(NamedColor::Cyan));
+/// ColorizeConfig::set_theme(Some(theme));
+/// println!("{} {}", "error:".role("error"), "42ms".role("metric"));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Theme {
+    name: String,
+    roles: BTreeMap<String, Style>,
+}
+
+impl Theme {
+    /// The roles every built-in theme defines.
+    pub const ROLES: [&'static str; 7] = [
+        "error", "warning", "success", "hint", "path", "code", "muted",
+    ];
+
+    /// Create a theme without roles.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            roles: BTreeMap::new(),
+        }
+    }
+
+    /// The built-in theme, which draws roles with the named ANSI colors so
+    /// they follow the terminal's palette.
+    pub fn default_theme() -> Self {
+        Self::new("default")
+            .with_role("error", Style::new().fg_named(NamedColor::Red).bold())
+            .with_role("warning", Style::new().fg_named(NamedColor::Yellow))
+            .with_role("success", Style::new().fg_named(NamedColor::Green))
+            .with_role("hint", Style::new().fg_named(NamedColor::Cyan))
+            .with_role("path", Style::new().fg_named(NamedColor::Blue).underline())
+            .with_role("code", Style::new().fg_named(NamedColor::Magenta))
+            .with_role("muted", Style::new().dim())
+    }
+
+    /// A built-in theme with bright, bold colors and no dim text, for low
+    /// vision and washed-out displays.
+    pub fn high_contrast() -> Self {
+        Self::new("high-contrast")
+            .with_role(
+                "error",
+                Style::new()
+                    .fg_named(NamedColor::BrightWhite)
+                    .bg_named(NamedColor::Red)
+                    .bold(),
+            )
+            .with_role(
+                "warning",
+                Style::new().fg_named(NamedColor::BrightYellow).bold(),
+            )
+            .with_role(
+                "success",
+                Style::new().fg_named(NamedColor::BrightGreen).bold(),
+            )
+            .with_role("hint", Style::new().fg_named(NamedColor::BrightCyan))
+            .with_role(
+                "path",
+                Style::new().fg_named(NamedColor::BrightBlue).underline(),
+            )
+            .with_role("code", Style::new().fg_named(NamedColor::BrightMagenta))
+            .with_role("muted", Style::new().fg_named(NamedColor::White))
+    }
+
+    /// A built-in theme that uses text styles only, for terminals and
+    /// readers without color.
+    pub fn monochrome() -> Self {
+        Self::new("monochrome")
+            .with_role("error", Style::new().bold().underline())
+            .with_role("warning", Style::new().bold())
+            .with_role("success", Style::new())
+            .with_role("hint", Style::new().italic())
+            .with_role("path", Style::new().underline())
+            .with_role("code", Style::new().inverse())
+            .with_role("muted", Style::new().dim())
+    }
+
+    /// Find a built-in theme by name: `"default"`, `"high-contrast"`, or
+    /// `"monochrome"`.
+    ///
+    /// Matching ignores ASCII case and treats spaces, hyphens, and underscores
+    /// alike.
+    pub fn builtin(name: &str) -> Option<Self> {
+        [
+            Self::default_theme(),
+            Self::high_contrast(),
+            Self::monochrome(),
+        ]
+        .into_iter()
+        .find(|theme| same_name(&theme.name, name))
+    }
+
+    /// Return the theme's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return the theme with `role` drawn in `style`, replacing any existing
+    /// style for it.
+    pub fn with_role(mut self, role: impl Into<String>, style: Style) -> Self {
+        self.set_role(role, style);
+        self
+    }
+
+    /// Draw `role` in `style`, replacing any existing style for it.
+    pub fn set_role(&mut self, role: impl Into<String>, style: Style) {
+        self.roles.insert(role.into(), style);
+    }
+
+    /// Return the style for `role`, if the theme defines it.
+    pub fn style(&self, role: &str) -> Option<&Style> {
+        self.roles.get(role)
+    }
+
+    /// Iterate over `(role, style)` pairs in role name order.
+    pub fn roles(&self) -> impl Iterator<Item = (&str, &Style)> {
+        self.roles
+            .iter()
+            .map(|(role, style)| (role.as_str(), style))
+    }
+
+    /// Return this theme with the roles `other` defines replaced by its
+    /// styles, named after `other`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn overlaid_with(&self, other: &Theme) -> Theme {
+        let mut theme = Theme {
+            name: other.name.clone(),
+            roles: self.roles.clone(),
+        };
+        for (role, style) in other.roles() {
+            theme.set_role(role, style.clone());
+        }
+        theme
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::default_theme()
+    }
+}
+
+/// Return the built-in default theme, built once.
+pub(crate) fn default_theme() -> &'static Theme {
+    static DEFAULT: OnceLock<Theme> = OnceLock::new();
+    DEFAULT.get_or_init(Theme::default_theme)
+}
+
+/// Return the active theme's style for `role`, warning about unknown roles
+/// when [`ColorizeConfig::set_warn_unknown_roles`] is on.
+pub(crate) fn role_style(role: &str) -> Option<Style> {
+    with_active_theme(|theme| {
+        let style = theme.style(role).cloned();
+        if style.is_none() && ColorizeConfig::warn_unknown_roles() {
+            warn_unknown_role(role, &theme.name);
+        }
+        style
+    })
+}
+
+/// Print a warning for an unknown role, once per role and process.
+fn warn_unknown_role(role: &str, theme_name: &str) {
+    let mut warned = WARNED_ROLES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if warned.insert(role.to_string()) {
+        eprintln!(
+            "colored_text: theme \"{theme_name}\" has no role \"{role}\"; rendering it plain"
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn warned_about_role_for_tests(role: &str) -> bool {
+    WARNED_ROLES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .contains(role)
+}
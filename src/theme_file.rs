@@ -0,0 +1,558 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+
+use crate::color::{Color, NamedColor};
+use crate::style::Style;
+use crate::theme::Theme;
+
+/// Name given to themes loaded without a `name` key or file name.
+const DEFAULT_NAME: &str = "custom";
+
+/// Error returned when a theme or user config file cannot be read or is
+/// invalid.
+///
+/// It names the file, when there is one, and the key that failed, such as
+/// `roles.error.fg`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigError {
+    path: Option<PathBuf>,
+    key: Option<String>,
+    message: String,
+}
+
+impl ConfigError {
+    pub(crate) fn new(key: Option<&str>, message: impl Into<String>) -> Self {
+        Self {
+            path: None,
+            key: key.map(str::to_string),
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn at(key: &str, message: impl Into<String>) -> Self {
+        Self::new(Some(key), message)
+    }
+
+    pub(crate) fn in_file(mut self, path: &Path) -> Self {
+        self.path.get_or_insert_with(|| path.to_path_buf());
+        self
+    }
+
+    /// Return the file the error was found in, if the theme came from a file.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Return the key the error was found at, if it belongs to one.
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    /// Return the description of the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}: ", path.display())?;
+        }
+        if let Some(key) = &self.key {
+            write!(f, "{key}: ")?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// File formats a theme can be written in.
+#[derive(Clone, Copy)]
+pub(crate) enum Format {
+    Toml,
+    Json,
+}
+
+impl Theme {
+    /// Load a theme from TOML source.
+    ///
+    /// Roles are listed in a `roles` table, either as style strings such as
+    /// `"bold #ff8000 on black"` or as tables with `fg`, `bg`, and boolean
+    /// `bold`, `dim`, `italic`, `underline`, `inverse`, and `strikethrough`
+    /// keys. Top-level keys starting with `$` define variables that style
+    /// strings and colors can use. `extends` names a built-in theme, or a
+    /// theme file relative to the current directory, whose roles and
+    /// variables this theme starts from. `name` sets the theme's name.
+    ///
+    /// ```rust
+    /// use colored_text::Theme;
+    ///
+    /// let theme = Theme::from_toml_str(
+    ///     r##"
+    ///     name = "ocean"
+    ///     extends = "default"
+    ///     "$accent" = "#0080ff"
+    ///
+    ///     [roles]
+    ///     path = "underline $accent"
+    ///     error = { fg = "bright_red", bold = true }
+    ///     "##,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(theme.name(), "ocean");
+    /// assert!(theme.style("warning").is_some());
+    /// ```
+    pub fn from_toml_str(source: &str) -> Result<Self, ConfigError> {
+        load_source(source, Format::Toml)
+    }
+
+    /// Load a theme from JSON source, with the same structure as
+    /// [`Theme::from_toml_str`].
+    pub fn from_json_str(source: &str) -> Result<Self, ConfigError> {
+        load_source(source, Format::Json)
+    }
+
+    /// Load a theme from a `.toml` or `.json` file.
+    ///
+    /// A relative `extends` path is resolved from the file's directory. The
+    /// theme is named after the file when it has no `name` key.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        load_file(path.as_ref(), &mut Vec::new()).map(|(theme, _)| theme)
+    }
+}
+
+fn load_file(
+    path: &Path,
+    visiting: &mut Vec<PathBuf>,
+) -> Result<(Theme, BTreeMap<String, String>), ConfigError> {
+    let format = match path.extension().and_then(|extension| extension.to_str()) {
+        Some(extension) if extension.eq_ignore_ascii_case("toml") => Format::Toml,
+        Some(extension) if extension.eq_ignore_ascii_case("json") => Format::Json,
+        _ => {
+            return Err(ConfigError::new(None, "expected a .toml or .json file").in_file(path));
+        }
+    };
+    let canonical = fs::canonicalize(path)
+        .map_err(|error| ConfigError::new(None, error.to_string()).in_file(path))?;
+    if visiting.contains(&canonical) {
+        return Err(ConfigError::at("extends", "theme extends itself").in_file(path));
+    }
+    let source = fs::read_to_string(path)
+        .map_err(|error| ConfigError::new(None, error.to_string()).in_file(path))?;
+
+    visiting.push(canonical);
+    let result = parse(&source, format)
+        .and_then(|document| build(document, Some(path), visiting))
+        .map_err(|error| error.in_file(path));
+    visiting.pop();
+    result
+}
+
+fn load_source(source: &str, format: Format) -> Result<Theme, ConfigError> {
+    let document = parse(source, format)?;
+    build(document, None, &mut Vec::new()).map(|(theme, _)| theme)
+}
+
+pub(crate) fn parse(source: &str, format: Format) -> Result<BTreeMap<String, Value>, ConfigError> {
+    let value = match format {
+        Format::Toml => toml::from_str(source).map_err(|error| {
+            ConfigError::new(None, format!("invalid TOML: {}", error.message()))
+        })?,
+        Format::Json => serde_json::from_str(source)
+            .map_err(|error| ConfigError::new(None, format!("invalid JSON: {error}")))?,
+    };
+    match value {
+        Value::Table(table) => Ok(table),
+        other => Err(ConfigError::new(
+            None,
+            format!("expected a table, found {}", other.kind()),
+        )),
+    }
+}
+
+/// Build a theme and its variables from a parsed document.
+fn build(
+    document: BTreeMap<String, Value>,
+    path: Option<&Path>,
+    visiting: &mut Vec<PathBuf>,
+) -> Result<(Theme, BTreeMap<String, String>), ConfigError> {
+    let mut name = None;
+    let mut extends = None;
+    let mut roles = BTreeMap::new();
+    let mut own_variables = BTreeMap::new();
+
+    for (key, value) in document {
+        match key.as_str() {
+            "name" => name = Some(expect_string(&key, value)?),
+            "extends" => extends = Some(expect_string(&key, value)?),
+            "roles" => match value {
+                Value::Table(table) => roles = table,
+                other => return Err(ConfigError::at(&key, expected("a table", &other))),
+            },
+            _ if key.starts_with('$') && key.len() > 1 => {
+                let value = expect_string(&key, value)?;
+                own_variables.insert(key[1..].to_string(), value);
+            }
+            _ => return Err(ConfigError::at(&key, "unknown key")),
+        }
+    }
+
+    let (parent, mut variables) = match extends {
+        Some(parent) => load_parent(&parent, path, visiting)?,
+        None => (Theme::new(DEFAULT_NAME), BTreeMap::new()),
+    };
+    variables.extend(own_variables);
+
+    let name = name.unwrap_or_else(|| {
+        path.and_then(Path::file_stem).map_or_else(
+            || DEFAULT_NAME.to_string(),
+            |stem| stem.to_string_lossy().into_owned(),
+        )
+    });
+    let mut theme = parent
+        .roles()
+        .fold(Theme::new(name), |theme, (role, style)| {
+            theme.with_role(role, style.clone())
+        });
+    for (role, value) in roles {
+        let key = format!("roles.{role}");
+        let style = role_style(&key, value, &variables)?;
+        theme.set_role(role, style);
+    }
+
+    Ok((theme, variables))
+}
+
+fn load_parent(
+    parent: &str,
+    path: Option<&Path>,
+    visiting: &mut Vec<PathBuf>,
+) -> Result<(Theme, BTreeMap<String, String>), ConfigError> {
+    if let Some(theme) = Theme::builtin(parent) {
+        return Ok((theme, BTreeMap::new()));
+    }
+
+    let parent_path = match path.and_then(Path::parent) {
+        Some(directory) => directory.join(parent),
+        None => PathBuf::from(parent),
+    };
+    if !parent_path.is_file() {
+        return Err(ConfigError::at(
+            "extends",
+            format!("\"{parent}\" is neither a built-in theme nor a theme file"),
+        ));
+    }
+    load_file(&parent_path, visiting)
+}
+
+fn role_style(
+    key: &str,
+    value: Value,
+    variables: &BTreeMap<String, String>,
+) -> Result<Style, ConfigError> {
+    match value {
+        Value::String(text) => parse_style(key, &text, variables),
+        Value::Table(fields) => {
+            let mut style = Style::new();
+            for (field, value) in fields {
+                let key = format!("{key}.{field}");
+                style = match field.as_str() {
+                    "fg" | "bg" => {
+                        let text = expect_string(&key, value)?;
+                        let color = parse_color_value(&key, &text, variables)?;
+                        if field == "fg" {
+                            color.apply_fg(style)
+                        } else {
+                            color.apply_bg(style)
+                        }
+                    }
+                    _ => {
+                        let enabled = match value {
+                            Value::Bool(enabled) => enabled,
+                            other => {
+                                return Err(ConfigError::at(&key, expected("a boolean", &other)))
+                            }
+                        };
+                        let Some(apply) = attribute(&field) else {
+                            return Err(ConfigError::at(&key, "unknown style field"));
+                        };
+                        if enabled {
+                            apply(style)
+                        } else {
+                            style
+                        }
+                    }
+                };
+            }
+            Ok(style)
+        }
+        other => Err(ConfigError::at(
+            key,
+            expected("a style string or table", &other),
+        )),
+    }
+}
+
+/// Parse a style string such as `"bold bright_red on #202020"`.
+///
+/// Words are text attributes or colors; a color after `on` sets the
+/// background. `$name` words are replaced by the variable's words.
+fn parse_style(
+    key: &str,
+    text: &str,
+    variables: &BTreeMap<String, String>,
+) -> Result<Style, ConfigError> {
+    let words = expand(key, text, variables)?;
+    let mut style = Style::new();
+    let mut words = words.iter();
+
+    while let Some(word) = words.next() {
+        if word.eq_ignore_ascii_case("on") {
+            let Some(color) = words.next() else {
+                return Err(ConfigError::at(key, "expected a color after \"on\""));
+            };
+            style = parse_color(key, color)?.apply_bg(style);
+        } else if let Some(apply) = attribute(word) {
+            style = apply(style);
+        } else {
+            style = parse_color(key, word)?.apply_fg(style);
+        }
+    }
+
+    Ok(style)
+}
+
+/// Parse a single color, which may be a variable.
+fn parse_color_value(
+    key: &str,
+    text: &str,
+    variables: &BTreeMap<String, String>,
+) -> Result<ThemeColor, ConfigError> {
+    match expand(key, text, variables)?.as_slice() {
+        [word] => parse_color(key, word),
+        _ => Err(ConfigError::at(
+            key,
+            format!("expected one color, found \"{text}\""),
+        )),
+    }
+}
+
+/// Split a style string into words, replacing variables with their words.
+fn expand(
+    key: &str,
+    text: &str,
+    variables: &BTreeMap<String, String>,
+) -> Result<Vec<String>, ConfigError> {
+    let mut words = Vec::new();
+    for word in text.split_whitespace() {
+        match word.strip_prefix('$') {
+            Some(name) => {
+                let Some(value) = variables.get(name) else {
+                    return Err(ConfigError::at(
+                        key,
+                        format!("unknown variable \"${name}\""),
+                    ));
+                };
+                if value.split_whitespace().any(|word| word.starts_with('$')) {
+                    return Err(ConfigError::at(
+                        key,
+                        format!("variable \"${name}\" refers to another variable"),
+                    ));
+                }
+                words.extend(value.split_whitespace().map(str::to_string));
+            }
+            None => words.push(word.to_string()),
+        }
+    }
+    Ok(words)
+}
+
+/// A color parsed from a theme file.
+enum ThemeColor {
+    Named(NamedColor),
+    Ansi256(u8),
+    Rgb(Color),
+}
+
+impl ThemeColor {
+    fn apply_fg(self, style: Style) -> Style {
+        match self {
+            Self::Named(color) => style.fg_named(color),
+            Self::Ansi256(index) => style.fg_ansi256(index),
+            Self::Rgb(color) => style.fg(color),
+        }
+    }
+
+    fn apply_bg(self, style: Style) -> Style {
+        match self {
+            Self::Named(color) => style.bg_named(color),
+            Self::Ansi256(index) => style.bg_ansi256(index),
+            Self::Rgb(color) => style.bg(color),
+        }
+    }
+}
+
+/// Parse a named color such as `bright_red`, a hex color such as `#ff8000`,
+/// or an ANSI 256 index such as `ansi256(208)`.
+fn parse_color(key: &str, word: &str) -> Result<ThemeColor, ConfigError> {
+    if word.starts_with('#') {
+        return Color::from_hex(word)
+            .map(ThemeColor::Rgb)
+            .ok_or_else(|| ConfigError::at(key, format!("invalid hex color \"{word}\"")));
+    }
+    let lower = word.to_ascii_lowercase();
+    if let Some(index) = lower
+        .strip_prefix("ansi256(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        return index
+            .trim()
+            .parse()
+            .map(ThemeColor::Ansi256)
+            .map_err(|_| ConfigError::at(key, format!("invalid ANSI 256 index in \"{word}\"")));
+    }
+    named_color(&lower)
+        .map(ThemeColor::Named)
+        .ok_or_else(|| ConfigError::at(key, format!("unknown color or style \"{word}\"")))
+}
+
+fn named_color(name: &str) -> Option<NamedColor> {
+    let (base, bright) = match name.strip_prefix("bright") {
+        Some(rest) => (rest.trim_start_matches(['_', '-']), true),
+        None => (name, false),
+    };
+    let color = match (base, bright) {
+        ("black", false) => NamedColor::Black,
+        ("red", false) => NamedColor::Red,
+        ("green", false) => NamedColor::Green,
+        ("yellow", false) => NamedColor::Yellow,
+        ("blue", false) => NamedColor::Blue,
+        ("magenta", false) => NamedColor::Magenta,
+        ("cyan", false) => NamedColor::Cyan,
+        ("white", false) => NamedColor::White,
+        ("black", true) => NamedColor::BrightBlack,
+        ("red", true) => NamedColor::BrightRed,
+        ("green", true) => NamedColor::BrightGreen,
+        ("yellow", true) => NamedColor::BrightYellow,
+        ("blue", true) => NamedColor::BrightBlue,
+        ("magenta", true) => NamedColor::BrightMagenta,
+        ("cyan", true) => NamedColor::BrightCyan,
+        ("white", true) => NamedColor::BrightWhite,
+        _ => return None,
+    };
+    Some(color)
+}
+
+fn attribute(name: &str) -> Option<fn(Style) -> Style> {
+    let apply: fn(Style) -> Style = match name.to_ascii_lowercase().as_str() {
+        "bold" => Style::bold,
+        "dim" => Style::dim,
+        "italic" => Style::italic,
+        "underline" => Style::underline,
+        "inverse" | "reverse" => Style::inverse,
+        "strikethrough" => Style::strikethrough,
+        _ => return None,
+    };
+    Some(apply)
+}
+
+pub(crate) fn expect_string(key: &str, value: Value) -> Result<String, ConfigError> {
+    match value {
+        Value::String(text) => Ok(text),
+        other => Err(ConfigError::at(key, expected("a string", &other))),
+    }
+}
+
+pub(crate) fn expected(what: &str, found: &Value) -> String {
+    format!("expected {what}, found {}", found.kind())
+}
+
+/// A format-independent view of a parsed TOML or JSON document.
+pub(crate) enum Value {
+    String(String),
+    Bool(bool),
+    Number,
+    Array,
+    Table(BTreeMap<String, Value>),
+    Null,
+}
+
+impl Value {
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            Self::String(_) => "a string",
+            Self::Bool(_) => "a boolean",
+            Self::Number => "a number",
+            Self::Array => "an array",
+            Self::Table(_) => "a table",
+            Self::Null => "null",
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a theme value")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Value, E> {
+        Ok(Value::Number)
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Value, E> {
+        Ok(Value::Number)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Value, E> {
+        Ok(Value::Number)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Value, E> {
+        Ok(Value::String(value.to_string()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Value, E> {
+        Ok(Value::String(value))
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        while seq.next_element::<Value>()?.is_some() {}
+        Ok(Value::Array)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut table = BTreeMap::new();
+        while let Some((key, value)) = map.next_entry::<String, Value>()? {
+            table.insert(key, value);
+        }
+        Ok(Value::Table(table))
+    }
+}
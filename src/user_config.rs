@@ -0,0 +1,234 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use crate::config::{ColorDepthMode, ColorMode, ColorizeConfig};
+use crate::palettes::{self, Palette};
+use crate::terminal::{EnvProvider, ProcessEnv};
+use crate::theme::Theme;
+use crate::theme_file::{expect_string, parse, ConfigError, Format, Value};
+
+/// Preferences installed with [`ColorizeConfig::set_user_config`].
+static USER_CONFIG: RwLock<Option<Arc<UserConfig>>> = RwLock::new(None);
+
+/// Color preferences an end user sets once for every program using this
+/// crate.
+///
+/// Programs opt in by calling [`ColorizeConfig::load_user_config`], which reads
+/// `$XDG_CONFIG_HOME/colored_text/config.toml`, or
+/// `$HOME/.config/colored_text/config.toml` when `XDG_CONFIG_HOME` is unset:
+///
+/// ```toml
+/// color_mode = "always"      # auto, always, or never
+/// color_depth = "ansi256"    # auto, no-color, ansi8, ansi16, ansi256,
+///                            # truecolor, or grayscale
+/// palette = "nord"           # any built-in palette
+/// theme = "high-contrast"    # a built-in theme, or a theme file path
+///                            # relative to the config file
+/// ```
+///
+/// Every key is optional. Loaded preferences rank below environment
+/// variables and above in-process [`ColorizeConfig`] settings; even
+/// `color_depth = "auto"` replaces a depth the program set explicitly. The
+/// crate does not apply `palette` itself: it only records the user's choice
+/// for programs to read with [`UserConfig::palette`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserConfig {
+    color_mode: Option<ColorMode>,
+    color_depth_mode: Option<ColorDepthMode>,
+    palette: Option<Palette>,
+    theme: Option<Theme>,
+}
+
+impl UserConfig {
+    /// Return where the user config file is looked for, or `None` when
+    /// neither `XDG_CONFIG_HOME` nor `HOME` is set to an absolute path.
+    pub fn path() -> Option<PathBuf> {
+        config_path(&ProcessEnv)
+    }
+
+    /// Load the user config file, or return `None` when it does not exist.
+    pub fn load() -> Result<Option<Self>, ConfigError> {
+        let Some(path) = Self::path() else {
+            return Ok(None);
+        };
+        match fs::read_to_string(&path) {
+            Ok(source) => Self::from_source(&source, Some(&path))
+                .map(Some)
+                .map_err(|error| error.in_file(&path)),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(ConfigError::new(None, error.to_string()).in_file(&path)),
+        }
+    }
+
+    /// Load preferences from a TOML file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path)
+            .map_err(|error| ConfigError::new(None, error.to_string()).in_file(path))?;
+        Self::from_source(&source, Some(path)).map_err(|error| error.in_file(path))
+    }
+
+    /// Load preferences from TOML source. A theme file path is resolved from
+    /// the current directory.
+    pub fn from_toml_str(source: &str) -> Result<Self, ConfigError> {
+        Self::from_source(source, None)
+    }
+
+    fn from_source(source: &str, path: Option<&Path>) -> Result<Self, ConfigError> {
+        let mut config = Self::default();
+        for (key, value) in parse(source, Format::Toml)? {
+            match key.as_str() {
+                "color_mode" => {
+                    config.color_mode = Some(parse_color_mode(&expect_string(&key, value)?)?);
+                }
+                "color_depth" => {
+                    config.color_depth_mode =
+                        Some(parse_color_depth_mode(&expect_string(&key, value)?)?);
+                }
+                "palette" => {
+                    let name = expect_string(&key, value)?;
+                    config.palette = Some(palettes::by_name(&name).ok_or_else(|| {
+                        ConfigError::at(&key, format!("unknown palette \"{name}\""))
+                    })?);
+                }
+                "theme" => config.theme = Some(load_theme(&key, value, path)?),
+                _ => return Err(ConfigError::at(&key, "unknown key")),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Return the preferred color mode, if set.
+    pub fn color_mode(&self) -> Option<ColorMode> {
+        self.color_mode
+    }
+
+    /// Return the preferred color depth mode, if set.
+    pub fn color_depth_mode(&self) -> Option<ColorDepthMode> {
+        self.color_depth_mode
+    }
+
+    /// Return the preferred palette, if set, for programs that pick their
+    /// colors from one.
+    pub fn palette(&self) -> Option<Palette> {
+        self.palette
+    }
+
+    /// Return the preferred theme, if set.
+    pub fn theme(&self) -> Option<&Theme> {
+        self.theme.as_ref()
+    }
+}
+
+impl ColorizeConfig {
+    /// Load the user config file and install it for the whole process.
+    ///
+    /// Returns whether a config file was found. When it is missing, any
+    /// installed preferences are left as they are.
+    pub fn load_user_config() -> Result<bool, ConfigError> {
+        let config = UserConfig::load()?;
+        let found = config.is_some();
+        if found {
+            Self::set_user_config(config);
+        }
+        Ok(found)
+    }
+
+    /// Install user preferences for the whole process, or remove them with
+    /// `None`.
+    ///
+    /// Installed preferences replace the color mode, color depth mode, and
+    /// theme set in-process on every thread. Roles the user's theme does not
+    /// define still come from the in-process theme.
+    pub fn set_user_config(config: Option<UserConfig>) {
+        *USER_CONFIG
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = config.map(Arc::new);
+    }
+
+    /// Get the installed user preferences, if any.
+    pub fn user_config() -> Option<UserConfig> {
+        user_config().as_deref().cloned()
+    }
+}
+
+/// Return the installed user preferences.
+pub(crate) fn user_config() -> Option<Arc<UserConfig>> {
+    USER_CONFIG
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+pub(crate) fn config_path(env: &impl EnvProvider) -> Option<PathBuf> {
+    // The XDG base directory spec says relative paths are invalid and
+    // should be ignored.
+    let absolute = |key: &str| {
+        env.get(key)
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+    };
+    let directory = absolute("XDG_CONFIG_HOME")
+        .or_else(|| absolute("HOME").map(|home| home.join(".config")))?;
+    Some(directory.join("colored_text").join("config.toml"))
+}
+
+fn load_theme(key: &str, value: Value, path: Option<&Path>) -> Result<Theme, ConfigError> {
+    let name = expect_string(key, value)?;
+    if let Some(theme) = Theme::builtin(&name) {
+        return Ok(theme);
+    }
+
+    let theme_path = match path.and_then(Path::parent) {
+        Some(directory) => directory.join(&name),
+        None => PathBuf::from(&name),
+    };
+    if !theme_path.is_file() {
+        return Err(ConfigError::at(
+            key,
+            format!("\"{name}\" is neither a built-in theme nor a theme file"),
+        ));
+    }
+    Theme::from_file(theme_path)
+}
+
+fn parse_color_mode(value: &str) -> Result<ColorMode, ConfigError> {
+    match normalize(value).as_str() {
+        "auto" => Ok(ColorMode::Auto),
+        "always" => Ok(ColorMode::Always),
+        "never" => Ok(ColorMode::Never),
+        _ => Err(ConfigError::at(
+            "color_mode",
+            format!("expected auto, always, or never, found \"{value}\""),
+        )),
+    }
+}
+
+fn parse_color_depth_mode(value: &str) -> Result<ColorDepthMode, ConfigError> {
+    match normalize(value).as_str() {
+        "auto" => Ok(ColorDepthMode::Auto),
+        "nocolor" => Ok(ColorDepthMode::NoColor),
+        "ansi8" => Ok(ColorDepthMode::Ansi8),
+        "ansi16" => Ok(ColorDepthMode::Ansi16),
+        "ansi256" => Ok(ColorDepthMode::Ansi256),
+        "truecolor" => Ok(ColorDepthMode::TrueColor),
+        "grayscale" | "greyscale" => Ok(ColorDepthMode::Grayscale),
+        _ => Err(ConfigError::at(
+            "color_depth",
+            format!(
+                "expected auto, no-color, ansi8, ansi16, ansi256, truecolor, or grayscale, found \"{value}\""
+            ),
+        )),
+    }
+}
+
+/// Lowercase a value and drop separators, so `"no-color"` matches `"NoColor"`.
+fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
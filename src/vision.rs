@@ -0,0 +1,138 @@
+use crate::color::{linear_to_srgb, srgb_to_linear, Color};
+
+type Matrix = [[f32; 3]; 3];
+
+// Machado, Oliveira, and Fernandes (2009) matrices at full severity, applied
+// to linear RGB.
+const PROTANOPIA: Matrix = [
+    [0.152_286, 1.052_583, -0.204_868],
+    [0.114_503, 0.786_281, 0.099_216],
+    [-0.003_882, -0.048_116, 1.051_998],
+];
+const DEUTERANOPIA: Matrix = [
+    [0.367_322, 0.860_646, -0.227_968],
+    [0.280_085, 0.672_501, 0.047_413],
+    [-0.011_820, 0.042_940, 0.968_881],
+];
+const TRITANOPIA: Matrix = [
+    [1.255_528, -0.076_749, -0.178_779],
+    [-0.078_411, 0.930_809, 0.147_602],
+    [0.004_733, 0.691_367, 0.303_900],
+];
+const ACHROMATOPSIA: Matrix = [
+    [0.212_6, 0.715_2, 0.072_2],
+    [0.212_6, 0.715_2, 0.072_2],
+    [0.212_6, 0.715_2, 0.072_2],
+];
+
+// Shift the color information a viewer cannot see into channels they can,
+// following Fidaner, Lin, and Ozguven's daltonization. Red-green deficiencies
+// move the lost red difference into green and blue; tritanopia moves the lost
+// blue difference into red and green.
+const RED_GREEN_SHIFT: Matrix = [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]];
+const BLUE_YELLOW_SHIFT: Matrix = [[1.0, 0.0, 0.7], [0.0, 1.0, 0.7], [0.0, 0.0, 0.0]];
+
+/// A color vision deficiency.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ColorVisionDeficiency {
+    /// No functioning long-wavelength (red) cones.
+    Protanopia,
+    /// No functioning medium-wavelength (green) cones.
+    Deuteranopia,
+    /// No functioning short-wavelength (blue) cones.
+    Tritanopia,
+    /// No color vision, only luminance.
+    Achromatopsia,
+}
+
+impl ColorVisionDeficiency {
+    /// Return how a color appears to a viewer with this deficiency.
+    pub fn simulate(self, color: Color) -> Color {
+        to_srgb(multiply(self.matrix(), to_linear(color)))
+    }
+
+    /// Return a color adjusted so that a viewer with this deficiency can tell
+    /// it apart from colors that would otherwise look the same.
+    ///
+    /// The difference between the original and simulated colors is shifted
+    /// into channels the viewer can still distinguish. Achromatopsia leaves no
+    /// such channels, so colors are returned unchanged for it.
+    pub fn daltonize(self, color: Color) -> Color {
+        let shift = match self {
+            Self::Protanopia | Self::Deuteranopia => RED_GREEN_SHIFT,
+            Self::Tritanopia => BLUE_YELLOW_SHIFT,
+            Self::Achromatopsia => return color,
+        };
+        let original = to_linear(color);
+        let simulated = multiply(self.matrix(), original);
+        let error = [
+            original[0] - simulated[0],
+            original[1] - simulated[1],
+            original[2] - simulated[2],
+        ];
+        let shift = multiply(shift, error);
+
+        to_srgb([
+            original[0] + shift[0],
+            original[1] + shift[1],
+            original[2] + shift[2],
+        ])
+    }
+
+    fn matrix(self) -> Matrix {
+        match self {
+            Self::Protanopia => PROTANOPIA,
+            Self::Deuteranopia => DEUTERANOPIA,
+            Self::Tritanopia => TRITANOPIA,
+            Self::Achromatopsia => ACHROMATOPSIA,
+        }
+    }
+}
+
+/// A transform applied to every color at render time, before it is quantized
+/// to the output color level.
+///
+/// Set it with [`crate::ColorizeConfig::set_color_transform`]. Named and ANSI
+/// 256 colors are transformed through their standard palette RGB values, so a
+/// transform turns them into RGB colors that then degrade like any other.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ColorTransform {
+    /// Render colors unchanged.
+    #[default]
+    None,
+    /// Show colors as they appear with a color vision deficiency.
+    Simulate(ColorVisionDeficiency),
+    /// Correct colors for a color vision deficiency.
+    Daltonize(ColorVisionDeficiency),
+}
+
+impl ColorTransform {
+    /// Apply the transform to a color.
+    pub fn apply(self, color: Color) -> Color {
+        match self {
+            Self::None => color,
+            Self::Simulate(deficiency) => deficiency.simulate(color),
+            Self::Daltonize(deficiency) => deficiency.daltonize(color),
+        }
+    }
+}
+
+fn multiply(matrix: Matrix, vector: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+fn to_linear(color: Color) -> [f32; 3] {
+    [
+        srgb_to_linear(color.r),
+        srgb_to_linear(color.g),
+        srgb_to_linear(color.b),
+    ]
+}
+
+fn to_srgb(linear: [f32; 3]) -> Color {
+    Color::rgb(
+        linear_to_srgb(linear[0]),
+        linear_to_srgb(linear[1]),
+        linear_to_srgb(linear[2]),
+    )
+}